@@ -0,0 +1,46 @@
+//! Timing of share parsing, dominated by word lookups
+//!
+//! Run with `just bench`. Compare against another revision by running it there too;
+//! numbers are only meaningful relative to each other on the same machine.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use shameless::codec;
+use shameless::commands::split_mnemonic;
+use shameless::domain::{ShareCount, SplitConfig, Threshold};
+
+const MNEMONIC_24: &str = "void come effort suffer camp survey warrior heavy shoot primary \
+                           clutch crush open amazing screen patrol group space point ten \
+                           exist slush involve unfold";
+
+/// Parses `share` repeatedly for about a second and returns the mean time per parse
+fn time_parse(share: &str) -> Duration {
+    // Warm up caches and the allocator
+    for _ in 0..1_000 {
+        black_box(codec::parse_share(black_box(share)).unwrap());
+    }
+
+    let mut iterations = 0u32;
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(1) {
+        for _ in 0..1_000 {
+            black_box(codec::parse_share(black_box(share)).unwrap());
+        }
+        iterations += 1_000;
+    }
+    start.elapsed() / iterations
+}
+
+fn main() {
+    let config = SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+    let lowercase = split_mnemonic(MNEMONIC_24, config).unwrap().remove(0);
+    let uppercase = lowercase.to_uppercase();
+
+    for (name, share) in [("lowercase", &lowercase), ("uppercase", &uppercase)] {
+        println!(
+            "parse_share, 24-word secret, {name} words: {:?}",
+            time_parse(share)
+        );
+    }
+}
@@ -0,0 +1,16 @@
+//! Feeds arbitrary input to the share parser, which must reject it without panicking
+//!
+//! Shares are typed or pasted by users, so every encoding's decoder sees untrusted
+//! input: truncated payloads, stray words, bogus length fields and the like.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use shameless::codec;
+
+fuzz_target!(|data: &[u8]| {
+    // Invalid UTF-8 cannot reach the parser through its `&str` API, but lossy
+    // conversion still lets mutations of the surrounding bytes through
+    let input = String::from_utf8_lossy(data);
+    let _ = codec::parse_share(&input);
+});
@@ -0,0 +1,78 @@
+#ifndef SHAMELESS_H
+#define SHAMELESS_H
+
+/* Generated by cbindgen from src/ffi.rs; run `just ffi-header` to update. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The call succeeded
+ */
+#define SHAMELESS_OK 0
+
+/**
+ * A required pointer argument was null
+ */
+#define SHAMELESS_ERR_NULL -1
+
+/**
+ * A string argument was not valid UTF-8
+ */
+#define SHAMELESS_ERR_UTF8 -2
+
+/**
+ * The split parameters were invalid
+ */
+#define SHAMELESS_ERR_PARAMS -3
+
+/**
+ * Splitting failed, e.g. the mnemonic is invalid
+ */
+#define SHAMELESS_ERR_SPLIT -4
+
+/**
+ * Combining failed, e.g. too few or corrupted shares
+ */
+#define SHAMELESS_ERR_COMBINE -5
+
+/**
+ * Split a BIP39 mnemonic into Shamir Secret Shares
+ *
+ * On success `*out` receives the split result JSON,
+ * `{"shares": [...], "share_count": N, "threshold": M}`.
+ *
+ * # Safety
+ * `mnemonic` must be a valid null-terminated string and `out` must be valid for
+ * writes. The string written to `*out` must be released with [`shameless_free`].
+ */
+int32_t shameless_split(const char *mnemonic, uint8_t shares, uint8_t threshold, char **out);
+
+/**
+ * Combine Shamir Secret Shares to reconstruct the original mnemonic
+ *
+ * On success `*out` receives the reconstructed mnemonic.
+ *
+ * # Safety
+ * `shares` must point to `len` valid null-terminated strings (it may be null when `len`
+ * is 0) and `out` must be valid for writes. The string written to `*out` must be
+ * released with [`shameless_free`].
+ */
+int32_t shameless_combine(const char *const *shares, size_t len, char **out);
+
+/**
+ * Release a string returned by a `shameless_*` function
+ *
+ * The string is zeroed before it is freed, since it may hold a mnemonic. Passing null
+ * is a no-op.
+ *
+ * # Safety
+ * `ptr` must be null or a string returned through `out` by this library that has not
+ * been freed yet.
+ */
+void shameless_free(char *ptr);
+
+#endif  /* SHAMELESS_H */
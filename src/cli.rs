@@ -1,6 +1,12 @@
-use clap::{Parser, Subcommand};
+use std::path::{Component, Path, PathBuf};
 
-use crate::shamir39::Threshold;
+use bip39::Language;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
+
+use crate::codec::{CodecOptions, ShareEncoding};
+use crate::commands::DedupPolicy;
+use crate::shamir39::{ShareCount, ShareIndex, SplitConfig, Threshold};
 
 /// Validates that threshold is at least 2
 /// A threshold of 1 defeats the purpose of Shamir Secret Sharing
@@ -13,18 +19,296 @@ fn validate_threshold(s: &str) -> Result<Threshold, String> {
     Threshold::new(value).map_err(|e| e.to_string())
 }
 
+/// Threshold and share count given together as `M-of-N`, e.g. `3-of-5`
+#[derive(Debug, Clone, Copy)]
+pub struct SchemeSpec(SplitConfig);
+
+impl SchemeSpec {
+    /// The split configuration: threshold `M`, share count `N`
+    #[must_use]
+    pub fn config(self) -> SplitConfig {
+        self.0
+    }
+}
+
+impl std::str::FromStr for SchemeSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((m, n)) = s.split_once("-of-") else {
+            return Err(format!(
+                "'{s}' is not of the form M-of-N (e.g. 3-of-5 for 3 of 5 shares)"
+            ));
+        };
+
+        let parse = |part: &str, what: &str| {
+            part.parse::<u8>()
+                .map_err(|_| format!("'{part}' in '{s}' is not a valid {what}"))
+        };
+        let threshold = Threshold::new(parse(m, "threshold")?).map_err(|e| e.to_string())?;
+        let share_count = ShareCount::new(parse(n, "share count")?).map_err(|e| e.to_string())?;
+
+        SplitConfig::new(threshold, share_count)
+            .map(Self)
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// File name template for shares written to `--output-dir`
+///
+/// `{index}` (the 0-based share index embedded in the share), `{threshold}` and
+/// `{count}` are substituted when rendering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputTemplate(String);
+
+impl OutputTemplate {
+    /// Template used when `--output-dir` is given without `--output-template`
+    pub const DEFAULT: &str = "share-{index}.txt";
+
+    /// Renders the file path of one share, relative to the output directory
+    #[must_use]
+    pub fn render(&self, index: ShareIndex, threshold: Threshold, count: ShareCount) -> PathBuf {
+        PathBuf::from(
+            self.0
+                .replace("{index}", &index.to_string())
+                .replace("{threshold}", &threshold.to_string())
+                .replace("{count}", &count.to_string()),
+        )
+    }
+}
+
+impl Default for OutputTemplate {
+    fn default() -> Self {
+        Self(Self::DEFAULT.to_string())
+    }
+}
+
+/// Validates that a share file name template names distinct files inside the output directory
+fn validate_output_template(s: &str) -> Result<OutputTemplate, String> {
+    if !s.contains("{index}") {
+        return Err(format!(
+            "'{s}' must contain {{index}}, otherwise every share would be written to the same file"
+        ));
+    }
+
+    let stays_inside = Path::new(s)
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if !stays_inside {
+        return Err(format!(
+            "'{s}' must be a relative path inside the output directory (no '..' or absolute paths)"
+        ));
+    }
+
+    Ok(OutputTemplate(s.to_string()))
+}
+
+/// BIP39 wordlist of the mnemonic and the share words
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum WordlistLanguage {
+    #[default]
+    English,
+    Japanese,
+    Spanish,
+    French,
+    Italian,
+    Korean,
+    ChineseSimplified,
+    ChineseTraditional,
+    Czech,
+    Portuguese,
+}
+
+impl From<WordlistLanguage> for Language {
+    fn from(language: WordlistLanguage) -> Self {
+        match language {
+            WordlistLanguage::English => Self::English,
+            WordlistLanguage::Japanese => Self::Japanese,
+            WordlistLanguage::Spanish => Self::Spanish,
+            WordlistLanguage::French => Self::French,
+            WordlistLanguage::Italian => Self::Italian,
+            WordlistLanguage::Korean => Self::Korean,
+            WordlistLanguage::ChineseSimplified => Self::SimplifiedChinese,
+            WordlistLanguage::ChineseTraditional => Self::TraditionalChinese,
+            WordlistLanguage::Czech => Self::Czech,
+            WordlistLanguage::Portuguese => Self::Portuguese,
+        }
+    }
+}
+
+/// Output format of a diagnostic report
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    /// Human-readable summary
+    #[default]
+    Text,
+    /// Machine-readable JSON object
+    Json,
+}
+
+/// How `shameless` reports an error that ends the command
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ErrorFormat {
+    /// Human-readable message with its causes
+    #[default]
+    Text,
+    /// One JSON object, `{"error": ..., "chain": [...]}`, for programs wrapping the binary
+    Json,
+}
+
 #[derive(Parser)]
 #[command(name = "shameless")]
 #[command(about = "Split Ethereum mnemonics into Shamir Secret Shares using shameless encoding")]
 pub struct Cli {
+    /// BIP39 wordlist of the mnemonic, also used for the words of the shares
+    #[arg(long, global = true, value_enum, default_value_t = WordlistLanguage::English)]
+    pub language: WordlistLanguage,
+
+    /// Log diagnostics to stderr (`-v` for progress, `-vv` for per-share details);
+    /// never logs secrets or share data. `RUST_LOG` is honoured when not given
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Don't print advisory warnings, such as about a scheme with no spare shares
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Format of the error printed to stderr when a command fails
+    #[arg(long, global = true, value_enum, default_value_t = ErrorFormat::Text)]
+    pub error_format: ErrorFormat,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+/// Arguments of the `split` subcommand
+#[derive(Args)]
+#[allow(
+    clippy::struct_excessive_bools,
+    reason = "each bool is an independent command-line switch"
+)]
+pub struct SplitArgs {
+    /// Number of shares to create
+    #[arg(short, long, required_unless_present = "scheme")]
+    pub shares: Option<u8>,
+
+    /// Threshold: minimum number of shares needed to reconstruct (must be >= 2)
+    #[arg(short, long, value_parser = validate_threshold, required_unless_present = "scheme")]
+    pub threshold: Option<Threshold>,
+
+    /// Threshold and share count as `M-of-N`, e.g. `3-of-5`, instead of
+    /// `--threshold` and `--shares`
+    #[arg(long, value_name = "M-of-N", conflicts_with_all = ["shares", "threshold"])]
+    pub scheme: Option<SchemeSpec>,
+
+    /// Reject the split if the threshold is below this policy minimum
+    #[arg(long, value_name = "N")]
+    pub min_threshold: Option<u8>,
+
+    /// Text encoding of the printed shares
+    #[arg(long, value_enum, default_value_t = ShareEncoding::Words)]
+    pub encoding: ShareEncoding,
+
+    /// Emit shares in the original shamir39 layout (`shamir39-p1`) for other
+    /// implementations; such shares carry no checksum
+    #[arg(long, conflicts_with = "encoding")]
+    pub strict_spec: bool,
+
+    /// Zero-pad every share to this many bytes of share data, so shares don't reveal
+    /// the secret's size (33 makes 12- and 24-word mnemonics indistinguishable)
+    #[arg(
+        long,
+        value_name = "BYTES",
+        conflicts_with = "strict_spec",
+        value_parser = clap::value_parser!(u16).range(1..=CodecOptions::DEFAULT_MAX_DATA_LEN as i64)
+    )]
+    pub pad_to: Option<u16>,
+
+    /// Mark every share as belonging to a wallet that also uses a BIP39 passphrase, so
+    /// `combine` reminds whoever recovers it; the passphrase itself is never stored
+    #[arg(long, conflicts_with = "strict_spec")]
+    pub with_passphrase_reminder: bool,
+
+    /// Leave the number of shares dealt out of the shares, so no custodian learns how
+    /// many others exist; `combine` then cannot tell how many shares are missing
+    #[arg(long)]
+    pub no_share_count: bool,
+
+    /// Read the mnemonic from this file instead of `SHAMELESS_MNEMONIC` or stdin
+    #[arg(long, value_name = "PATH")]
+    pub mnemonic_file: Option<PathBuf>,
+
+    /// Split these BIP39 entropy bytes (16, 20, 24, 28 or 32, as hex) instead of a
+    /// mnemonic; meant for restore drills with throwaway secrets, as command-line
+    /// arguments are visible to other local users
+    #[arg(
+        long,
+        value_name = "HEX",
+        hide = true,
+        conflicts_with = "mnemonic_file"
+    )]
+    pub entropy_hex: Option<String>,
+
+    /// Split `--entropy-hex` even if it looks too regular to be random, e.g. all zero
+    /// bytes
+    #[arg(long, requires = "entropy_hex", hide = true)]
+    pub force_weak: bool,
+
+    /// Write each share to its own file in this directory instead of printing it
+    #[arg(long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+
+    /// File name for each share in `--output-dir`; `{index}`, `{threshold}` and
+    /// `{count}` are substituted [default: share-{index}.txt]
+    #[arg(long, value_name = "TEMPLATE", requires = "output_dir", value_parser = validate_output_template)]
+    pub output_template: Option<OutputTemplate>,
+
+    /// Also write a JSON manifest of the scheme and a fingerprint of the secret to
+    /// this file; it holds no share data and no secret
+    #[arg(long, value_name = "PATH")]
+    pub manifest: Option<PathBuf>,
+
+    /// Also write every share, compressed into one line, to this file; anyone who
+    /// reads it can recover the secret, so it needs the same care as the mnemonic
+    #[arg(long, value_name = "PATH")]
+    pub bundle: Option<PathBuf>,
+
+    /// Also write each share as a printable HTML page (words, QR code and "Share N of M"
+    /// label) to its own file in this directory, e.g. for paper backups
+    #[arg(long, value_name = "DIR")]
+    pub paper: Option<PathBuf>,
+
+    /// Print a short fingerprint next to each share, which `shameless fingerprint` shows
+    /// again so a custodian can confirm over the phone that they hold the right share
+    #[arg(long)]
+    pub show_fingerprints: bool,
+
+    /// Skip the confirmation prompt before printing shares to a terminal
+    #[arg(short, long)]
+    pub yes: bool,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Split a mnemonic into shares
-    Split {
+    Split(SplitArgs),
+    /// Re-split the secret behind existing shares with new parameters, without revealing it
+    Rotate {
+        /// Number of new shares to create
+        #[arg(short, long)]
+        shares: u8,
+
+        /// New threshold: minimum number of new shares needed to reconstruct (must be >= 2)
+        #[arg(short, long, value_parser = validate_threshold)]
+        threshold: Threshold,
+
+        /// Skip the confirmation prompt before printing shares to a terminal
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Split a mnemonic into an offline recovery kit: a printable page per share, a
+    /// manifest and a README explaining how to recover
+    Kit {
         /// Number of shares to create
         #[arg(short, long)]
         shares: u8,
@@ -32,7 +316,104 @@ pub enum Commands {
         /// Threshold: minimum number of shares needed to reconstruct (must be >= 2)
         #[arg(short, long, value_parser = validate_threshold)]
         threshold: Threshold,
+
+        /// Directory to write the kit to; it must not exist yet or be empty
+        #[arg(long, value_name = "DIR")]
+        output_dir: PathBuf,
     },
     /// Combine shares to reconstruct the original mnemonic
-    Combine,
+    Combine {
+        /// Drop shares whose threshold disagrees with a clear majority instead of failing
+        #[arg(long)]
+        lenient: bool,
+
+        /// When more shares than the threshold are given, skip corrupt ones and recover
+        /// from a subset that a spare share confirms
+        #[arg(long)]
+        robust: bool,
+
+        /// Fail unless the shares were dealt with this threshold, guarding against
+        /// shares from a different scheme
+        #[arg(long, value_name = "N", value_parser = validate_threshold)]
+        expect_threshold: Option<Threshold>,
+
+        /// How to handle several copies of the same share: fail, or keep the first or
+        /// last copy (copies with different contents always fail)
+        #[arg(long, value_enum, default_value_t = DedupPolicy::Error)]
+        dedup: DedupPolicy,
+
+        /// Fail unless the shares match the scheme and secret fingerprint recorded in
+        /// this manifest (written by `split --manifest`)
+        #[arg(long, value_name = "PATH", conflicts_with = "interactive")]
+        manifest: Option<PathBuf>,
+
+        /// Verify the shares reconstruct a valid mnemonic without printing it
+        #[arg(long)]
+        check: bool,
+
+        /// Print the recovered secret as hex instead of forming a mnemonic, for secrets
+        /// that are not BIP39 mnemonics
+        #[arg(long, conflicts_with_all = ["check", "interactive", "manifest"])]
+        raw: bool,
+
+        /// Fail unless the recovered secret equals these bytes (as hex), printing
+        /// neither; meant for restore drills against a secret split with
+        /// `split --entropy-hex`
+        #[arg(
+            long,
+            value_name = "HEX",
+            hide = true,
+            conflicts_with_all = ["check", "raw", "interactive", "manifest", "output_file"]
+        )]
+        assert_entropy_hex: Option<String>,
+
+        /// Enter shares in a guided terminal UI that validates each one as it is pasted
+        /// (requires the `tui` feature)
+        #[arg(short, long, conflicts_with = "check")]
+        interactive: bool,
+
+        /// Write the recovered mnemonic to this file (readable only by the owner)
+        /// instead of printing it
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["check", "interactive"])]
+        output_file: Option<PathBuf>,
+
+        /// Overwrite `--output-file` if it already exists
+        #[arg(long, requires = "output_file")]
+        force: bool,
+
+        /// Fail unless every share originally dealt was given, rather than only warning
+        /// about missing ones
+        #[arg(long, conflicts_with_all = ["raw", "assert_entropy_hex", "interactive"])]
+        require_all_shares: bool,
+
+        /// After recovering the secret, recompute every share from it with independent
+        /// GF(256) arithmetic and fail unless they match the shares given
+        #[arg(long)]
+        paranoid_verify: bool,
+
+        /// Read a JSON split result, `{"shares": [...], ...}` as returned by the WASM
+        /// `wasm_split`, from stdin instead of one share per line
+        #[arg(long, conflicts_with = "interactive")]
+        json: bool,
+    },
+    /// Diagnose a set of shares (checksums, indices, thresholds) without reconstructing
+    /// the secret
+    Health {
+        /// Report format
+        #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+        format: ReportFormat,
+    },
+    /// List each word of the shares with its position, role and BIP39 index, to help
+    /// track down transcription errors; a single share reveals nothing about the secret
+    Words,
+    /// Print the fingerprint of each share, as `split --show-fingerprints` did, to
+    /// confirm a copy matches without reading the share out
+    Fingerprint,
+    /// Print a shell completion script to stdout
+    #[command(hide = true)]
+    Completion {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
 }
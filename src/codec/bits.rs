@@ -0,0 +1,470 @@
+//! Wordlist-independent bit packing of share data and parameters
+//!
+//! Everything here works on BIP39 word indices (0-2047, 11 bits each) rather than
+//! words, and depends only on `core` and `alloc`: no wordlist, no `std`. It is the part
+//! of the codec a bare-metal signer needs, and can be built into a `#![no_std]` crate
+//! as is; mapping indices to the words of a wordlist is left to the caller, which
+//! [`super`] does for the std build.
+//!
+//! Share data is padded with 0-10 zero bits to a multiple of 11 bits, so `n` bytes
+//! always take exactly [`word_count`]`(n)` indices. shameless left-pads (see
+//! [`Padding`]). Parameters use the layout described
+//! in [`super::params`].
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use zeroize::Zeroizing;
+
+/// Number of words in a BIP39 wordlist; valid indices are below this
+pub const WORDLIST_LEN: u16 = 2048;
+
+/// Largest threshold or share index two parameter words can express (10 bits)
+pub const MAX_PARAMETER: u16 = 1023;
+
+/// Error raised while packing or unpacking word indices
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitsError {
+    /// A share data index is not below [`WORDLIST_LEN`]
+    IndexOutOfRange(u16),
+    /// Too few indices for the expected number of bytes
+    NotEnoughBits { got: usize, expected: usize },
+    /// No parameter indices were given
+    NoParameterWords,
+    /// A parameter index is not below [`WORDLIST_LEN`]
+    ParameterOutOfRange(u16),
+    /// The first parameter index announces a second one that is missing
+    MissingContinuation,
+    /// The second parameter index announces a third, which the layout does not allow
+    DoubleContinuation,
+    /// A threshold or share index above [`MAX_PARAMETER`] cannot be encoded
+    ParameterTooLarge(u16),
+}
+
+impl fmt::Display for BitsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IndexOutOfRange(index) => {
+                write!(f, "Word index {index} out of range (must be 0-2047)")
+            }
+            Self::NotEnoughBits { got, expected } => {
+                write!(
+                    f,
+                    "Not enough bits: got {got}, expected at least {expected}"
+                )
+            }
+            Self::NoParameterWords => write!(f, "No parameter words provided"),
+            Self::ParameterOutOfRange(index) => write!(
+                f,
+                "Parameter word index {index} is outside the 2048-word BIP39 wordlist"
+            ),
+            Self::MissingContinuation => write!(
+                f,
+                "Continuation bit set but only one parameter word provided"
+            ),
+            Self::DoubleContinuation => {
+                write!(f, "Second parameter word has continuation bit set")
+            }
+            Self::ParameterTooLarge(value) => write!(
+                f,
+                "Parameter {value} cannot be encoded: two parameter words hold at most {MAX_PARAMETER}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for BitsError {}
+
+/// Where the 0-10 zero bits that align share data to whole words go
+///
+/// shameless puts them in front of the data. Some other shamir39-style tools put them
+/// after it; the same bytes then give different words, so both sides must agree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Padding {
+    /// Zero bits before the data, at the top of the first index (the default)
+    #[default]
+    Left,
+    /// Zero bits after the data, at the bottom of the last index
+    Right,
+}
+
+impl Padding {
+    /// The other alignment
+    #[must_use]
+    pub const fn other(self) -> Self {
+        match self {
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+impl fmt::Display for Padding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        })
+    }
+}
+
+/// Number of word indices needed to encode `byte_len` bytes (11 bits per word)
+#[must_use]
+pub fn word_count(byte_len: usize) -> usize {
+    (byte_len * 8).div_ceil(11)
+}
+
+/// Passes each word index encoding `data` to `emit`, in order
+///
+/// Nothing is allocated, so shares can be streamed out word by word.
+///
+/// # Errors
+/// Returns the first error returned by `emit`
+pub fn for_each_index<E>(data: &[u8], emit: impl FnMut(u16) -> Result<(), E>) -> Result<(), E> {
+    for_each_index_padded(data, Padding::Left, emit)
+}
+
+/// Passes each word index encoding `data` to `emit`, padded as `padding` says
+///
+/// # Errors
+/// Returns the first error returned by `emit`
+pub fn for_each_index_padded<E>(
+    data: &[u8],
+    padding: Padding,
+    mut emit: impl FnMut(u16) -> Result<(), E>,
+) -> Result<(), E> {
+    if data.is_empty() {
+        return Ok(());
+    }
+
+    let pad_bits = (11 - (data.len() * 8) % 11) % 11;
+
+    // Left-pad with zero bits: the buffer starts out holding `pad_bits` zeros
+    let mut bit_buffer: u16 = 0;
+    let mut bits_in_buffer = match padding {
+        Padding::Left => pad_bits,
+        Padding::Right => 0,
+    };
+
+    for &byte in data {
+        for bit_pos in (0..8).rev() {
+            bit_buffer = (bit_buffer << 1) | u16::from((byte >> bit_pos) & 1);
+            bits_in_buffer += 1;
+
+            if bits_in_buffer == 11 {
+                emit(bit_buffer)?;
+                bit_buffer = 0;
+                bits_in_buffer = 0;
+            }
+        }
+    }
+
+    // Right-pad with zero bits: shift what is left to the top of the last index
+    if bits_in_buffer > 0 {
+        emit(bit_buffer << (11 - bits_in_buffer))?;
+    }
+
+    Ok(())
+}
+
+/// Encodes `data` as word indices, left-padded to a multiple of 11 bits
+///
+/// The indices are wrapped in `Zeroizing`, as they carry the data itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::codec::bits::{decode_indices, encode_indices};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let indices = encode_indices(&[0xDE, 0xAD, 0xBE, 0xEF]);
+/// assert_eq!(*indices, [890, 1463, 1775]);
+///
+/// let data = decode_indices(&indices, 4)?;
+/// assert_eq!(*data, [0xDE, 0xAD, 0xBE, 0xEF]);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn encode_indices(data: &[u8]) -> Zeroizing<Vec<u16>> {
+    encode_indices_padded(data, Padding::Left)
+}
+
+/// Encodes `data` as word indices, padded to a multiple of 11 bits as `padding` says
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::codec::bits::{Padding, decode_indices_padded, encode_indices_padded};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let indices = encode_indices_padded(&[0xDE, 0xAD, 0xBE, 0xEF], Padding::Right);
+/// assert_eq!(*indices, [1781, 879, 1502]);
+///
+/// let data = decode_indices_padded(&indices, 4, Padding::Right)?;
+/// assert_eq!(*data, [0xDE, 0xAD, 0xBE, 0xEF]);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn encode_indices_padded(data: &[u8], padding: Padding) -> Zeroizing<Vec<u16>> {
+    let mut indices = Zeroizing::new(Vec::with_capacity(word_count(data.len())));
+    let _ = for_each_index_padded(data, padding, |index| {
+        indices.push(index);
+        Ok::<(), core::convert::Infallible>(())
+    });
+    indices
+}
+
+/// Decodes `byte_len` bytes from word indices, skipping the left padding
+///
+/// Indices beyond those `byte_len` needs are read as padding, matching the words of a
+/// share whose length is known from elsewhere: a short `byte_len` keeps the last
+/// `byte_len` bytes the indices hold. `byte_len` must not exceed
+/// `indices.len() * 11 / 8`, the most the indices can hold.
+///
+/// # Errors
+/// Returns an error if an index is not below [`WORDLIST_LEN`] or the indices hold
+/// fewer than `byte_len` bytes
+pub fn decode_indices(indices: &[u16], byte_len: usize) -> Result<Zeroizing<Vec<u8>>, BitsError> {
+    decode_indices_padded(indices, byte_len, Padding::Left)
+}
+
+/// Decodes `byte_len` bytes from word indices padded as `padding` says
+///
+/// Bits beyond those `byte_len` needs are read as padding: leading bits for
+/// [`Padding::Left`], trailing ones for [`Padding::Right`].
+///
+/// # Errors
+/// Returns an error under the same conditions as [`decode_indices`]
+pub fn decode_indices_padded(
+    indices: &[u16],
+    byte_len: usize,
+    padding: Padding,
+) -> Result<Zeroizing<Vec<u8>>, BitsError> {
+    let expected_bits = byte_len * 8;
+    let total_bits = indices.len() * 11;
+    if total_bits < expected_bits {
+        return Err(BitsError::NotEnoughBits {
+            got: total_bits,
+            expected: expected_bits,
+        });
+    }
+
+    let mut leading = match padding {
+        Padding::Left => total_bits - expected_bits,
+        Padding::Right => 0,
+    };
+    let mut result = Zeroizing::new(Vec::with_capacity(byte_len));
+    let mut bit_buffer: u16 = 0;
+    let mut bits_in_buffer = 0;
+
+    for &index in indices {
+        if index >= WORDLIST_LEN {
+            return Err(BitsError::IndexOutOfRange(index));
+        }
+
+        for bit_pos in (0..11).rev() {
+            if leading > 0 {
+                leading -= 1;
+                continue;
+            }
+            if result.len() == byte_len {
+                break;
+            }
+
+            bit_buffer = (bit_buffer << 1) | ((index >> bit_pos) & 1);
+            bits_in_buffer += 1;
+
+            if bits_in_buffer == 8 {
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    reason = "bit_buffer only contains 8 bits at this point"
+                )]
+                result.push(bit_buffer as u8);
+                bit_buffer = 0;
+                bits_in_buffer = 0;
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Whether a parameter index has its continuation bit set, meaning a second parameter
+/// index follows
+#[must_use]
+pub fn has_continuation(index: u16) -> bool {
+    (index >> 10) & 1 == 1
+}
+
+/// Encodes a threshold (M) and share index (O) as one or two parameter indices
+///
+/// Values below 32 fit in one index; larger ones, up to [`MAX_PARAMETER`], take two.
+///
+/// # Errors
+/// Returns an error if `m` or `o` exceeds [`MAX_PARAMETER`]
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::codec::bits::{decode_parameter_indices, encode_parameter_indices};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// assert_eq!(encode_parameter_indices(2, 1)?, [65]);
+/// assert_eq!(encode_parameter_indices(35, 10)?, [1056, 106]);
+///
+/// // M, O and the number of indices used
+/// assert_eq!(decode_parameter_indices(&[1056, 106, 7])?, (35, 10, 2));
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_parameter_indices(m: u16, o: u16) -> Result<Vec<u16>, BitsError> {
+    if let Some(value) = [m, o].into_iter().find(|&value| value > MAX_PARAMETER) {
+        return Err(BitsError::ParameterTooLarge(value));
+    }
+
+    if m < 32 && o < 32 {
+        return Ok(alloc::vec![(m << 5) | o]);
+    }
+
+    // Continuation word with bits 5-9 of each value, then bits 0-4
+    Ok(alloc::vec![
+        (1 << 10) | ((m >> 5) << 5) | (o >> 5),
+        ((m & 0b11111) << 5) | (o & 0b11111),
+    ])
+}
+
+/// Decodes a threshold (M) and share index (O) from the parameter indices at the start
+/// of `indices`
+///
+/// Returns M, O and the number of indices consumed (1 or 2); any further indices are
+/// ignored.
+///
+/// # Errors
+/// Returns an error if `indices` is empty, a consumed index is not below
+/// [`WORDLIST_LEN`], or the continuation bits are inconsistent
+pub fn decode_parameter_indices(indices: &[u16]) -> Result<(u16, u16, usize), BitsError> {
+    let Some(&first) = indices.first() else {
+        return Err(BitsError::NoParameterWords);
+    };
+    let used = if has_continuation(first) { 2 } else { 1 };
+    if let Some(&index) = indices
+        .iter()
+        .take(used)
+        .find(|&&index| index >= WORDLIST_LEN)
+    {
+        return Err(BitsError::ParameterOutOfRange(index));
+    }
+
+    if !has_continuation(first) {
+        return Ok(((first >> 5) & 0b11111, first & 0b11111, 1));
+    }
+
+    let Some(&second) = indices.get(1) else {
+        return Err(BitsError::MissingContinuation);
+    };
+    if has_continuation(second) {
+        return Err(BitsError::DoubleContinuation);
+    }
+
+    let m = (((first >> 5) & 0b11111) << 5) | ((second >> 5) & 0b11111);
+    let o = ((first & 0b11111) << 5) | (second & 0b11111);
+    Ok((m, o, 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indices_round_trip_every_padding() {
+        for len in 1..=23u8 {
+            let data: Vec<u8> = (0..len).map(|i| i.wrapping_mul(37) ^ 0xA5).collect();
+            let indices = encode_indices(&data);
+            assert_eq!(indices.len(), word_count(data.len()));
+            assert!(indices.iter().all(|&index| index < WORDLIST_LEN));
+            assert_eq!(*decode_indices(&indices, data.len()).unwrap(), data);
+
+            let right = encode_indices_padded(&data, Padding::Right);
+            assert_eq!(right.len(), indices.len());
+            assert_eq!(
+                *decode_indices_padded(&right, data.len(), Padding::Right).unwrap(),
+                data
+            );
+        }
+
+        assert_eq!(
+            decode_indices(&[0], 2),
+            Err(BitsError::NotEnoughBits {
+                got: 11,
+                expected: 16
+            })
+        );
+        assert_eq!(
+            decode_indices(&[2048], 1),
+            Err(BitsError::IndexOutOfRange(2048))
+        );
+    }
+
+    #[test]
+    fn test_decode_indices_byte_len_bounds() {
+        let data = [0x01, 0x23, 0x45, 0x67, 0x89];
+        let indices = encode_indices(&data);
+        let capacity = indices.len() * 11 / 8;
+        assert_eq!(capacity, 5);
+
+        // One byte more than the indices can hold
+        assert_eq!(
+            decode_indices(&indices, capacity + 1),
+            Err(BitsError::NotEnoughBits {
+                got: 44,
+                expected: 48
+            })
+        );
+
+        // Fewer bytes read the leading ones as padding, or drop the trailing ones when
+        // right-padded
+        assert_eq!(*decode_indices(&indices, 3).unwrap(), data[2..]);
+        let right = encode_indices_padded(&data, Padding::Right);
+        assert_eq!(
+            *decode_indices_padded(&right, 3, Padding::Right).unwrap(),
+            data[..3]
+        );
+        assert!(decode_indices(&indices, 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parameter_indices_round_trip() {
+        for (m, o) in [
+            (2, 0),
+            (31, 31),
+            (32, 0),
+            (35, 10),
+            (255, 254),
+            (1023, 1023),
+        ] {
+            let indices = encode_parameter_indices(m, o).unwrap();
+            assert_eq!(indices.len(), if m < 32 && o < 32 { 1 } else { 2 });
+            assert_eq!(
+                decode_parameter_indices(&indices).unwrap(),
+                (m, o, indices.len())
+            );
+        }
+
+        assert_eq!(
+            encode_parameter_indices(1024, 0),
+            Err(BitsError::ParameterTooLarge(1024))
+        );
+        assert_eq!(
+            decode_parameter_indices(&[1056]),
+            Err(BitsError::MissingContinuation)
+        );
+        assert_eq!(
+            decode_parameter_indices(&[1056, 1056]),
+            Err(BitsError::DoubleContinuation)
+        );
+    }
+}
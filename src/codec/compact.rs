@@ -0,0 +1,339 @@
+//! Compact base64 share encoding
+//!
+//! An alternative to BIP39 words for users who store shares in a password manager. A
+//! compact share is the prefix `shameless:` followed by URL-safe base64 (no padding)
+//! of the bytes `[version (1)][M (1)][O (1)][payload]`, where the payload is the same
+//! length-prefixed, CRC32-checked share data the word encoding carries.
+//!
+//! The prefix can never start a word-encoded share (BIP39 words contain no `:`), so
+//! [`parse_share`](super::parse_share) uses it to auto-detect the encoding.
+
+use anyhow::{Context, Result, bail};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use zeroize::Zeroizing;
+
+use super::CodecOptions;
+use super::payload::{
+    ChecksumAlgorithm, DecodedPayload, MAX_OVERHEAD, PayloadLayout, ShareFlags, SharePart,
+    decode_payload, encode_payload,
+};
+use crate::domain::{ShareIndex, Threshold};
+
+/// Prefix that identifies a compact share
+pub const COMPACT_PREFIX: &str = "shameless:";
+
+/// Version marker byte of the compact layout
+const COMPACT_VERSION: u8 = 1;
+
+/// Bytes preceding the payload: version + threshold + index
+const COMPACT_HEADER_LEN: usize = 3;
+
+/// Encodes share data as a compact base64 share
+///
+/// # Errors
+/// Returns an error if share data is longer than
+/// [`CodecOptions::DEFAULT_MAX_DATA_LEN`]
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::codec::compact::{COMPACT_PREFIX, create_share_compact, parse_share_compact};
+/// use shameless::codec::CodecOptions;
+/// use shameless::domain::{ShareIndex, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let share = create_share_compact(&[0xDE, 0xAD], Threshold::new(2)?, ShareIndex::new(1)?)?;
+/// assert!(share.starts_with(COMPACT_PREFIX));
+///
+/// let (threshold, index, data) = parse_share_compact(&share, &CodecOptions::default())?;
+/// assert_eq!(*threshold, 2);
+/// assert_eq!(*index, 1);
+/// assert_eq!(*data, [0xDE, 0xAD]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_share_compact(
+    share_data: &[u8],
+    threshold: Threshold,
+    index: ShareIndex,
+) -> Result<String> {
+    encode_compact(
+        share_data,
+        threshold,
+        index,
+        PayloadLayout::Single,
+        CodecOptions::DEFAULT_MAX_DATA_LEN,
+    )
+}
+
+/// Encodes one part of a multipart secret as a compact base64 share
+///
+/// # Errors
+/// Returns an error if share data is longer than
+/// [`CodecOptions::DEFAULT_MAX_DATA_LEN`]
+pub fn create_share_compact_part(
+    share_data: &[u8],
+    threshold: Threshold,
+    index: ShareIndex,
+    part: SharePart,
+) -> Result<String> {
+    encode_compact(
+        share_data,
+        threshold,
+        index,
+        PayloadLayout::Part(part),
+        CodecOptions::DEFAULT_MAX_DATA_LEN,
+    )
+}
+
+/// Encodes share data as a compact share zero-padded to `pad_to` bytes of share data
+///
+/// # Errors
+/// Returns an error if share data is longer than
+/// [`CodecOptions::DEFAULT_MAX_DATA_LEN`] or longer than `pad_to`
+pub fn create_share_compact_padded(
+    share_data: &[u8],
+    threshold: Threshold,
+    index: ShareIndex,
+    pad_to: usize,
+) -> Result<String> {
+    encode_compact(
+        share_data,
+        threshold,
+        index,
+        PayloadLayout::Padded(pad_to),
+        CodecOptions::DEFAULT_MAX_DATA_LEN,
+    )
+}
+
+/// Encodes share data as a compact share recording `flags`, optionally zero-padded to
+/// `pad_to` bytes of share data
+///
+/// With no flag set, this writes exactly what [`create_share_compact`] or
+/// [`create_share_compact_padded`] would.
+///
+/// # Errors
+/// Returns an error if share data is longer than
+/// [`CodecOptions::DEFAULT_MAX_DATA_LEN`] or longer than `pad_to`
+pub fn create_share_compact_with_flags(
+    share_data: &[u8],
+    threshold: Threshold,
+    index: ShareIndex,
+    pad_to: Option<usize>,
+    flags: ShareFlags,
+) -> Result<String> {
+    let options = CodecOptions {
+        flags,
+        ..CodecOptions::default()
+    };
+    create_share_compact_with_options(share_data, threshold, index, pad_to, &options)
+}
+
+/// Encodes share data as a compact share recording `options.flags` and
+/// `options.share_count`, optionally zero-padded to `pad_to` bytes of share data
+///
+/// # Errors
+/// Returns an error if share data is longer than `options.max_data_len` or longer than
+/// `pad_to`
+pub fn create_share_compact_with_options(
+    share_data: &[u8],
+    threshold: Threshold,
+    index: ShareIndex,
+    pad_to: Option<usize>,
+    options: &CodecOptions,
+) -> Result<String> {
+    let layout = pad_to
+        .map_or(PayloadLayout::Single, PayloadLayout::Padded)
+        .with_flags(options.flags)?
+        .with_share_count(options.share_count)?;
+    encode_compact(share_data, threshold, index, layout, options.max_data_len)
+}
+
+/// Builds a compact share around a payload in the given layout
+fn encode_compact(
+    share_data: &[u8],
+    threshold: Threshold,
+    index: ShareIndex,
+    layout: PayloadLayout,
+    max_data_len: usize,
+) -> Result<String> {
+    let payload = encode_payload(
+        share_data,
+        ChecksumAlgorithm::default(),
+        layout,
+        max_data_len,
+    )?;
+
+    let mut bytes = Zeroizing::new(Vec::with_capacity(COMPACT_HEADER_LEN + payload.len()));
+    bytes.extend_from_slice(&[COMPACT_VERSION, *threshold, *index]);
+    bytes.extend_from_slice(&payload);
+
+    Ok(format!(
+        "{COMPACT_PREFIX}{}",
+        URL_SAFE_NO_PAD.encode(&*bytes)
+    ))
+}
+
+/// Parses a compact base64 share into components
+///
+/// # Errors
+/// Returns an error if the prefix is missing, the base64 is malformed, the version or
+/// parameters are invalid, the share data exceeds `options.max_data_len`, checksum
+/// verification fails, or the share is one part of a multipart secret
+pub fn parse_share_compact(
+    share: &str,
+    options: &CodecOptions,
+) -> Result<(Threshold, ShareIndex, Zeroizing<Vec<u8>>)> {
+    let (threshold, index, payload) = decode_compact(share, *options)?;
+    Ok((threshold, index, payload.into_single_part()?))
+}
+
+/// Decodes a compact share, keeping the payload's format details
+pub(super) fn decode_compact(
+    share: &str,
+    options: CodecOptions,
+) -> Result<(Threshold, ShareIndex, DecodedPayload)> {
+    let Some(encoded) = share.trim().strip_prefix(COMPACT_PREFIX) else {
+        bail!("Invalid compact share: expected prefix '{COMPACT_PREFIX}'");
+    };
+
+    // Bound the input size before decoding (4 base64 characters per 3 bytes)
+    let max_len = COMPACT_HEADER_LEN + options.max_data_len.saturating_add(MAX_OVERHEAD);
+    let max_encoded_len = max_len.saturating_mul(4).div_ceil(3);
+    if encoded.len() > max_encoded_len {
+        bail!(
+            "Compact share has {} characters, more than the {max_encoded_len} allowed for share data of at most {} bytes",
+            encoded.len(),
+            options.max_data_len
+        );
+    }
+
+    let bytes = Zeroizing::new(
+        URL_SAFE_NO_PAD
+            .decode(encoded)
+            .context("Invalid compact share: malformed base64")?,
+    );
+
+    let (threshold, index) = decode_header(&bytes)?;
+    let payload = decode_payload(&bytes[COMPACT_HEADER_LEN..], options)?;
+
+    Ok((threshold, index, payload))
+}
+
+/// Decodes only the threshold and index of a compact share
+///
+/// The 3-byte header is exactly the first 4 base64 characters, so it can be read even
+/// when the rest of the share is corrupt.
+///
+/// # Errors
+/// Returns an error if the prefix, version or parameters are invalid
+pub fn parse_share_compact_parameters(share: &str) -> Result<(Threshold, ShareIndex)> {
+    let Some(encoded) = share.trim().strip_prefix(COMPACT_PREFIX) else {
+        bail!("Invalid compact share: expected prefix '{COMPACT_PREFIX}'");
+    };
+
+    let header = encoded
+        .get(..4)
+        .context("Compact share too short: missing version and parameters")?;
+    let bytes = URL_SAFE_NO_PAD
+        .decode(header)
+        .context("Invalid compact share: malformed base64")?;
+
+    decode_header(&bytes)
+}
+
+/// Decodes the version byte and parameters at the start of a compact share
+fn decode_header(bytes: &[u8]) -> Result<(Threshold, ShareIndex)> {
+    if bytes.len() < COMPACT_HEADER_LEN {
+        bail!("Compact share too short: missing version and parameters");
+    }
+
+    if bytes[0] != COMPACT_VERSION {
+        bail!(
+            "Unsupported compact share version {}: expected {COMPACT_VERSION}",
+            bytes[0]
+        );
+    }
+
+    let threshold = Threshold::new(bytes[1])?;
+    let index = ShareIndex::new(bytes[2])?;
+
+    Ok((threshold, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_round_trip() {
+        for len in [0u16, 1, 17, 33, 300] {
+            let data: Vec<u8> = (0..len).map(|i| u8::try_from(i % 251).unwrap()).collect();
+            let share = create_share_compact(
+                &data,
+                Threshold::new(3).unwrap(),
+                ShareIndex::new(4).unwrap(),
+            )
+            .unwrap();
+
+            let (threshold, index, parsed) =
+                parse_share_compact(&share, &CodecOptions::default()).unwrap();
+            assert_eq!(*threshold, 3);
+            assert_eq!(*index, 4);
+            assert_eq!(*parsed, data);
+        }
+    }
+
+    #[test]
+    fn test_compact_is_url_safe() {
+        let share = create_share_compact(
+            &[0xFB, 0xFF, 0xFE],
+            Threshold::new(2).unwrap(),
+            ShareIndex::new(0).unwrap(),
+        )
+        .unwrap();
+        let encoded = share.strip_prefix(COMPACT_PREFIX).unwrap();
+        assert!(
+            encoded
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+    }
+
+    #[test]
+    fn test_compact_rejects_corruption() {
+        let share = create_share_compact(
+            &[1, 2, 3, 4],
+            Threshold::new(2).unwrap(),
+            ShareIndex::new(0).unwrap(),
+        )
+        .unwrap();
+
+        // Flip a character inside the share data
+        let mut chars: Vec<char> = share.chars().collect();
+        let pos = COMPACT_PREFIX.len() + 7;
+        chars[pos] = if chars[pos] == 'A' { 'B' } else { 'A' };
+        let corrupted: String = chars.into_iter().collect();
+
+        assert!(parse_share_compact(&corrupted, &CodecOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_compact_rejects_unknown_version() {
+        let bytes = [9u8, 2, 0, 0, 0, 0, 0, 0, 0];
+        let share = format!("{COMPACT_PREFIX}{}", URL_SAFE_NO_PAD.encode(bytes));
+        let err = parse_share_compact(&share, &CodecOptions::default()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unsupported compact share version 9")
+        );
+    }
+
+    #[test]
+    fn test_compact_rejects_oversized_input() {
+        let share = format!("{COMPACT_PREFIX}{}", "A".repeat(10_000));
+        let err = parse_share_compact(&share, &CodecOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("more than the"));
+    }
+}
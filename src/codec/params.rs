@@ -0,0 +1,283 @@
+//! Parameter word encoding for threshold (M) and share index (O)
+//!
+//! Each parameter word carries 11 bits laid out as
+//! `[continuation (1)][M bits (5)][O bits (5)]`. Values below 32 fit in a single word
+//! with the continuation bit clear. Larger values use two words: the first has the
+//! continuation bit set and carries bits 5-9 of M and O, the second has it clear and
+//! carries bits 0-4.
+//!
+//! These functions are public so other shamir39 implementations can cross-check their
+//! bit packing against this crate. The packing itself works on word indices in
+//! [`super::bits`]; this module maps it to the words of a wordlist.
+//!
+//! # Range
+//!
+//! Two words can express M and O up to 1023, but this crate's Shamir backend works over
+//! GF256, which supports at most 254 distinct shares. [`Threshold`] and [`ShareIndex`]
+//! are therefore `u8`-backed, and decoding rejects larger values with an error naming
+//! that limit rather than widening the types for a backend that doesn't exist.
+
+use anyhow::{Result, anyhow, bail};
+use bip39::Language;
+
+use super::bits::{self, has_continuation};
+use super::{word_from_index, word_to_index};
+use crate::domain::{ShareCount, ShareIndex, Threshold};
+
+/// Encodes threshold (M) and share index (O) into BIP39 words
+///
+/// Uses 11-bit word encoding: [`continuation_bit` (1)][M bits (5)][O bits (5)]
+/// - `continuation_bit` = 1: more words follow
+/// - `continuation_bit` = 0: final word
+///
+/// # Arguments
+/// * `threshold` - Minimum shares required (M)
+/// * `index` - Share index/order (O), 0-based
+///
+/// # Returns
+/// Vector of BIP39 words encoding the parameters
+///
+/// # Errors
+/// Returns an error if word index conversion fails
+///
+/// # Examples
+///
+/// Single word (M < 32 and O < 32): `0b0_00010_00001` = 65 → `"amused"`
+///
+/// ```rust
+/// use shameless::codec::params::encode_parameters;
+/// use shameless::domain::{ShareIndex, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let words = encode_parameters(Threshold::new(2)?, ShareIndex::new(1)?)?;
+/// assert_eq!(words, ["amused"]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Two words (M = 35 = `0b00001_00011`, O = 10 = `0b00000_01010`):
+/// - first word `0b1_00001_00000` = 1056 → `"lottery"` (continuation, high bits)
+/// - second word `0b0_00011_01010` = 106 → `"ask"` (low bits)
+///
+/// ```rust
+/// use shameless::codec::params::encode_parameters;
+/// use shameless::domain::{ShareIndex, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let words = encode_parameters(Threshold::new(35)?, ShareIndex::new(10)?)?;
+/// assert_eq!(words, ["lottery", "ask"]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_parameters(threshold: Threshold, index: ShareIndex) -> Result<Vec<String>> {
+    encode_parameters_in(Language::English, threshold, index)
+}
+
+/// Encodes threshold (M) and share index (O) into words of the given BIP39 wordlist
+///
+/// The bit packing is identical to [`encode_parameters`]; only the words differ.
+///
+/// # Errors
+/// Returns an error if word index conversion fails
+///
+/// # Examples
+///
+/// ```rust
+/// use bip39::Language;
+/// use shameless::codec::params::{decode_parameters_in, encode_parameters_in};
+/// use shameless::domain::{ShareIndex, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let words = encode_parameters_in(Language::Spanish, Threshold::new(2)?, ShareIndex::new(1)?)?;
+/// let (threshold, index) = decode_parameters_in(Language::Spanish, &words)?;
+/// assert_eq!((*threshold, *index), (2, 1));
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_parameters_in(
+    language: Language,
+    threshold: Threshold,
+    index: ShareIndex,
+) -> Result<Vec<String>> {
+    bits::encode_parameter_indices(u16::from(*threshold), u16::from(*index))?
+        .into_iter()
+        .map(|word_index| word_from_index(usize::from(word_index), language))
+        .collect()
+}
+
+/// Decodes threshold and share index from BIP39 parameter words
+///
+/// Accepts exactly the parameter words (one, or two when the first has its
+/// continuation bit set); words are matched case-insensitively.
+///
+/// # Arguments
+/// * `words` - Parameter words from shameless share
+///
+/// # Returns
+/// Tuple of (threshold, index)
+///
+/// # Errors
+/// Returns an error if word index lookup fails or parameter format is invalid
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::codec::params::decode_parameters;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // Single word: M=2, O=1
+/// let (threshold, index) = decode_parameters(&["amused"])?;
+/// assert_eq!((*threshold, *index), (2, 1));
+///
+/// // Two words: M=35, O=10
+/// let (threshold, index) = decode_parameters(&["lottery", "ask"])?;
+/// assert_eq!((*threshold, *index), (35, 10));
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_parameters<S: AsRef<str>>(words: &[S]) -> Result<(Threshold, ShareIndex)> {
+    decode_parameters_in(Language::English, words)
+}
+
+/// Decodes threshold and share index from parameter words of the given BIP39 wordlist
+///
+/// # Errors
+/// Returns an error under the same conditions as [`decode_parameters`]
+pub fn decode_parameters_in<S: AsRef<str>>(
+    language: Language,
+    words: &[S],
+) -> Result<(Threshold, ShareIndex)> {
+    if words.is_empty() {
+        bail!("No parameter words provided");
+    }
+
+    let first_index = word_to_index(words[0].as_ref(), language)?;
+    if !has_continuation(first_index) {
+        return decode_parameters_from_indices(&[first_index]);
+    }
+
+    if words.len() < 2 {
+        bail!("Continuation bit set but only one parameter word provided");
+    }
+    let second_index = word_to_index(words[1].as_ref(), language)?;
+
+    decode_parameters_from_indices(&[first_index, second_index])
+}
+
+/// Decodes threshold and share index from the wordlist indices (0-2047) of the
+/// parameter words
+///
+/// This is [`decode_parameters`] minus the word lookup, for callers that have already
+/// resolved the words. Indices past the ones the encoding needs are ignored.
+///
+/// # Errors
+/// Returns an error if an index is outside the wordlist or the parameter format is
+/// invalid
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::codec::params::decode_parameters_from_indices;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // "lottery ask": M=35, O=10
+/// let (threshold, index) = decode_parameters_from_indices(&[1056, 106])?;
+/// assert_eq!((*threshold, *index), (35, 10));
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_parameters_from_indices(indices: &[u16]) -> Result<(Threshold, ShareIndex)> {
+    let (m, o, _) = bits::decode_parameter_indices(indices)?;
+
+    // Two words can hold values up to 1023, beyond what the u8-backed types allow
+    let threshold = u8::try_from(m).map_err(|_| {
+        anyhow!(
+            "Threshold {m} is not supported: GF256 Shamir sharing allows at most {} shares",
+            ShareCount::MAX
+        )
+    })?;
+    let index = u8::try_from(o).map_err(|_| {
+        anyhow!(
+            "Share index {o} is not supported: GF256 Shamir sharing allows indices 0-{}",
+            ShareIndex::MAX
+        )
+    })?;
+
+    Ok((Threshold::new(threshold)?, ShareIndex::new(index)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_word_parameters() {
+        // M=2, O=1 should fit in single word
+        let words =
+            encode_parameters(Threshold::new(2).unwrap(), ShareIndex::new(1).unwrap()).unwrap();
+        assert_eq!(words.len(), 1);
+
+        let (m, o) = decode_parameters(&words).unwrap();
+        assert_eq!(*m, 2);
+        assert_eq!(*o, 1);
+    }
+
+    #[test]
+    fn test_decode_from_indices_matches_word_decoding() {
+        for (m, o) in [(2, 0), (31, 31), (32, 0), (35, 10), (255, 254)] {
+            let threshold = Threshold::new(m).unwrap();
+            let index = ShareIndex::new(o).unwrap();
+            let words = encode_parameters(threshold, index).unwrap();
+            let indices: Vec<u16> = words
+                .iter()
+                .map(|word| word_to_index(word, Language::English).unwrap())
+                .collect();
+
+            assert_eq!(
+                decode_parameters_from_indices(&indices).unwrap(),
+                (threshold, index)
+            );
+            assert_eq!(decode_parameters(&words).unwrap(), (threshold, index));
+        }
+
+        assert!(decode_parameters_from_indices(&[2048]).is_err());
+        assert!(decode_parameters_from_indices(&[1056]).is_err());
+    }
+
+    #[test]
+    fn test_two_word_parameters() {
+        // M=35, O=10 requires two words
+        let words =
+            encode_parameters(Threshold::new(35).unwrap(), ShareIndex::new(10).unwrap()).unwrap();
+        assert_eq!(words.len(), 2);
+
+        let (m, o) = decode_parameters(&words).unwrap();
+        assert_eq!(*m, 35);
+        assert_eq!(*o, 10);
+    }
+
+    #[test]
+    fn test_threshold_above_gf256_limit_has_domain_error() {
+        // M=300 (0b01001_01100), O=0 in the two-word encoding
+        let words = [
+            word_from_index((1 << 10) | (0b01001 << 5), Language::English).unwrap(),
+            word_from_index(0b01100 << 5, Language::English).unwrap(),
+        ];
+
+        let err = decode_parameters(&words).unwrap_err().to_string();
+        assert!(err.contains("Threshold 300 is not supported"));
+        assert!(err.contains("at most 254 shares"));
+    }
+
+    #[test]
+    fn test_index_above_gf256_limit_has_domain_error() {
+        // M=2, O=1023 (all 10 bits set)
+        let words = [
+            word_from_index((1 << 10) | 0b11111, Language::English).unwrap(),
+            word_from_index((2 << 5) | 0b11111, Language::English).unwrap(),
+        ];
+
+        let err = decode_parameters(&words).unwrap_err().to_string();
+        assert!(err.contains("Share index 1023 is not supported"));
+    }
+}
@@ -0,0 +1,968 @@
+//! Checksummed share payload carried by every share encoding
+//!
+//! These payload layouts exist:
+//! - Legacy: `[length (2)][share data][CRC32 (4)]`, with an implicit checksum
+//!   algorithm 0 (CRC-32/ISO-HDLC)
+//! - v1: `[0x81][checksum algorithm (1)][length (2)][share data][checksum (4)]`
+//! - v2: `[0x82][checksum algorithm (1)][part index (2)][part count (2)][length (2)]
+//!   [share data][checksum (4)]`, for one part of a multipart secret (see [`SharePart`]).
+//!   The checksum also covers the part fields and length, so a corrupted part tag is
+//!   caught rather than filed under the wrong part.
+//! - v3: `[0x83][checksum algorithm (1)][length (2)][share data][checksum (4)][zero padding]`,
+//!   a v1 payload padded with zero bytes so every share has the same size regardless of
+//!   its data length (see [`PayloadLayout::Padded`]). The length field still holds the
+//!   true share data length, and the checksum covers only the real share data; the
+//!   padding is checked to be all zero instead.
+//! - v4: `[0x84][checksum algorithm (1)][length (2)][share data][checksum (4)]`, the v1
+//!   layout with the checksum covering the whole header as well as the share data, so a
+//!   corrupted length or algorithm byte is caught too.
+//! - v5: `[0x85][checksum algorithm (1)][length (2)][share data][checksum (4)][zero padding]`,
+//!   the v3 layout with the checksum covering the header in the same way.
+//! - v6: `[0x86][checksum algorithm (1)][flags (1)][length (2)][share data][checksum (4)]
+//!   [zero padding]`, a v4 or v5 payload that also records [`ShareFlags`]. The padding
+//!   may be empty, and the checksum covers the whole header, flags included.
+//! - v7: `[0x87][checksum algorithm (1)][flags (1)][share count (1)][length (2)]
+//!   [share data][checksum (4)][zero padding]`, a v6 payload that also records how many
+//!   shares were dealt, so recovery can tell how many are missing.
+//!
+//! New single and padded shares are written as v4 and v5, as v6 when any flag is set,
+//! or as v7 when they record the share count; v1 and v3 shares still decode with their
+//! original, data-only checksum scope.
+//!
+//! A legacy payload starts with the high byte of its length, which is below `0x80` for
+//! any share data under 32 KiB. A first byte with the high bit set therefore marks a
+//! versioned layout, so new layouts can be introduced without misreading old shares.
+
+use anyhow::{Result, anyhow, bail};
+use crc::{CRC_32_ISO_HDLC, Crc};
+use zeroize::Zeroizing;
+
+use super::CodecOptions;
+use crate::domain::ShareCount;
+
+/// CRC-32/ISO-HDLC, the checksum used by legacy shares
+const CRC32_ISO_HDLC: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+
+/// First byte of a v1 payload
+const FORMAT_V1: u8 = 0x81;
+
+/// First byte of a v2 (multipart) payload
+const FORMAT_V2: u8 = 0x82;
+
+/// First byte of a v3 (padded) payload
+const FORMAT_V3: u8 = 0x83;
+
+/// First byte of a v4 payload (v1 with the header checksummed)
+const FORMAT_V4: u8 = 0x84;
+
+/// First byte of a v5 payload (v3 with the header checksummed)
+const FORMAT_V5: u8 = 0x85;
+
+/// First byte of a v6 payload (v4 or v5 with share flags)
+const FORMAT_V6: u8 = 0x86;
+
+/// First byte of a v7 payload (v6 with the share count)
+const FORMAT_V7: u8 = 0x87;
+
+/// Bytes of checksum trailing the share data
+const CHECKSUM_LEN: usize = 4;
+
+/// Smallest payload overhead (legacy: length + checksum)
+pub(super) const MIN_OVERHEAD: usize = 2 + CHECKSUM_LEN;
+
+/// Largest payload overhead (v2: marker + algorithm + part tag + length + checksum)
+pub(super) const MAX_OVERHEAD: usize = 1 + 1 + 4 + 2 + CHECKSUM_LEN;
+
+/// Checksum algorithm protecting the share data
+///
+/// The algorithm's id is stored in each share, so shares written with different
+/// algorithms can coexist. Legacy shares implicitly use id 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    /// CRC-32/ISO-HDLC (id 0)
+    #[default]
+    Crc32IsoHdlc,
+}
+
+impl ChecksumAlgorithm {
+    /// Identifier stored in the payload header
+    #[must_use]
+    pub const fn id(self) -> u8 {
+        match self {
+            Self::Crc32IsoHdlc => 0,
+        }
+    }
+
+    /// Looks up an algorithm by its stored identifier
+    ///
+    /// # Errors
+    /// Returns an error if the identifier is not a known algorithm
+    pub fn from_id(id: u8) -> Result<Self> {
+        match id {
+            0 => Ok(Self::Crc32IsoHdlc),
+            _ => bail!(
+                "Unknown checksum algorithm {id}: this version of shameless only supports 0 (CRC-32/ISO-HDLC)"
+            ),
+        }
+    }
+
+    /// Computes the checksum of `data`
+    #[must_use]
+    pub fn checksum(self, data: &[u8]) -> u32 {
+        match self {
+            Self::Crc32IsoHdlc => CRC32_ISO_HDLC.checksum(data),
+        }
+    }
+}
+
+/// Position of a share's data within a secret split into several parts
+///
+/// Each part of a multipart secret is split separately, and its shares carry the part
+/// tag so they can be grouped again when combining.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SharePart {
+    index: u16,
+    count: u16,
+}
+
+impl SharePart {
+    /// Creates a part tag for part `index` (0-based) of `count` parts
+    ///
+    /// # Errors
+    /// Returns an error if `index` is not below `count`
+    pub fn new(index: u16, count: u16) -> Result<Self> {
+        if index >= count {
+            bail!("Part index {index} out of range for a secret of {count} part(s)");
+        }
+        Ok(Self { index, count })
+    }
+
+    /// Part index (0-based)
+    #[must_use]
+    pub const fn index(self) -> u16 {
+        self.index
+    }
+
+    /// Total number of parts in the secret
+    #[must_use]
+    pub const fn count(self) -> u16 {
+        self.count
+    }
+}
+
+impl std::fmt::Display for SharePart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "part {} of {}", self.index + 1, self.count)
+    }
+}
+
+/// Non-secret markers recorded in a share alongside its data
+///
+/// Flags are informational: they never affect how the secret is recovered. Shares
+/// with no flag set are written exactly as before flags existed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ShareFlags {
+    /// The wallet behind the mnemonic also uses a BIP39 passphrase (the "25th word"),
+    /// which is not part of the shares and must be remembered separately
+    pub passphrase_reminder: bool,
+    /// The share is one of the extra parity shares dealt beyond the requested share
+    /// count (see `commands::split_with_parity`)
+    pub parity: bool,
+}
+
+impl ShareFlags {
+    /// Bit of [`Self::passphrase_reminder`] in the stored flags byte
+    const PASSPHRASE_REMINDER: u8 = 0b0000_0001;
+
+    /// Bit of [`Self::parity`] in the stored flags byte
+    const PARITY: u8 = 0b0000_0010;
+
+    /// Whether any flag is set
+    #[must_use]
+    pub const fn any(self) -> bool {
+        self.passphrase_reminder || self.parity
+    }
+
+    /// Flags byte stored in a v6 payload
+    const fn to_byte(self) -> u8 {
+        let mut byte = 0;
+        if self.passphrase_reminder {
+            byte |= Self::PASSPHRASE_REMINDER;
+        }
+        if self.parity {
+            byte |= Self::PARITY;
+        }
+        byte
+    }
+
+    /// Reads a stored flags byte
+    ///
+    /// # Errors
+    /// Returns an error if an unknown bit is set
+    fn from_byte(byte: u8) -> Result<Self> {
+        if byte & !(Self::PASSPHRASE_REMINDER | Self::PARITY) != 0 {
+            bail!(
+                "Unknown share flags 0x{byte:02x}: the share may have been created by a newer version of shameless"
+            );
+        }
+        Ok(Self {
+            passphrase_reminder: byte & Self::PASSPHRASE_REMINDER != 0,
+            parity: byte & Self::PARITY != 0,
+        })
+    }
+}
+
+/// Layout of a payload being encoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PayloadLayout {
+    /// A whole secret's share (v4)
+    Single,
+    /// One part of a multipart secret (v2)
+    Part(SharePart),
+    /// A whole secret's share, zero-padded as if it held this many bytes of share data (v5)
+    Padded(usize),
+    /// A whole secret's share recording flags, optionally zero-padded (v6)
+    Flagged(ShareFlags, Option<usize>),
+    /// A whole secret's share recording flags and the number of shares dealt,
+    /// optionally zero-padded (v7)
+    Counted(ShareFlags, ShareCount, Option<usize>),
+}
+
+impl PayloadLayout {
+    /// The layout that records `flags` on top of this one
+    ///
+    /// # Errors
+    /// Returns an error if flags are set on a multipart layout, which cannot record them
+    pub(super) fn with_flags(self, flags: ShareFlags) -> Result<Self> {
+        if !flags.any() {
+            return Ok(self);
+        }
+        match self {
+            Self::Single => Ok(Self::Flagged(flags, None)),
+            Self::Padded(pad_to) => Ok(Self::Flagged(flags, Some(pad_to))),
+            Self::Flagged(_, pad_to) => Ok(Self::Flagged(flags, pad_to)),
+            Self::Counted(_, share_count, pad_to) => Ok(Self::Counted(flags, share_count, pad_to)),
+            Self::Part(_) => bail!("Shares of a multipart secret cannot record share flags"),
+        }
+    }
+
+    /// The layout that records `share_count` on top of this one
+    ///
+    /// # Errors
+    /// Returns an error if a share count is given for a multipart layout, which cannot
+    /// record it
+    pub(super) fn with_share_count(self, share_count: Option<ShareCount>) -> Result<Self> {
+        let Some(share_count) = share_count else {
+            return Ok(self);
+        };
+        match self {
+            Self::Single => Ok(Self::Counted(ShareFlags::default(), share_count, None)),
+            Self::Padded(pad_to) => Ok(Self::Counted(
+                ShareFlags::default(),
+                share_count,
+                Some(pad_to),
+            )),
+            Self::Flagged(flags, pad_to) | Self::Counted(flags, _, pad_to) => {
+                Ok(Self::Counted(flags, share_count, pad_to))
+            }
+            Self::Part(_) => bail!("Shares of a multipart secret cannot record the share count"),
+        }
+    }
+}
+
+/// Layout information read from the start of a payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct PayloadHeader {
+    /// Payload layout version (0 for legacy)
+    pub(super) format_version: u8,
+    /// Bytes preceding the share data
+    pub(super) header_len: usize,
+    /// Stored checksum algorithm id, not yet validated
+    pub(super) checksum_id: u8,
+    /// Offset where the checksummed bytes begin
+    pub(super) checksum_from: usize,
+    /// Stored part tag, not yet validated (v2 only)
+    pub(super) part: Option<(u16, u16)>,
+    /// Stored flags byte, not yet validated (v6 and v7 only)
+    pub(super) flags: u8,
+    /// Stored share count, not yet validated (v7 only)
+    pub(super) share_count: Option<u8>,
+    /// Declared share data length
+    pub(super) data_len: usize,
+}
+
+impl PayloadHeader {
+    /// Reads the header at the start of `encoded_data`
+    ///
+    /// # Errors
+    /// Returns an error if the data is too short for its header or uses an unknown
+    /// format marker
+    pub(super) fn parse(encoded_data: &[u8]) -> Result<Self> {
+        let stored_id = encoded_data.get(1).copied().unwrap_or_default();
+        let (format_version, header_len, checksum_id, checksum_from) = match encoded_data.first() {
+            Some(&marker) if marker & 0x80 == 0 => (0, 2, ChecksumAlgorithm::Crc32IsoHdlc.id(), 2),
+            Some(&FORMAT_V1) => (1, 4, stored_id, 4),
+            Some(&FORMAT_V2) => (2, 8, stored_id, 2),
+            Some(&FORMAT_V3) => (3, 4, stored_id, 4),
+            Some(&FORMAT_V4) => (4, 4, stored_id, 0),
+            Some(&FORMAT_V5) => (5, 4, stored_id, 0),
+            Some(&FORMAT_V6) => (6, 5, stored_id, 0),
+            Some(&FORMAT_V7) => (7, 6, stored_id, 0),
+            Some(&marker) => bail!(
+                "Unsupported share format marker 0x{marker:02x}: the share may have been created by a newer version of shameless"
+            ),
+            None => bail!("Encoded data is empty"),
+        };
+
+        if encoded_data.len() < header_len + CHECKSUM_LEN {
+            bail!(
+                "Encoded data too short: need at least {} bytes (header + checksum), got {}",
+                header_len + CHECKSUM_LEN,
+                encoded_data.len()
+            );
+        }
+
+        let read_u16 = |at: usize| u16::from_be_bytes([encoded_data[at], encoded_data[at + 1]]);
+
+        // The length is always the last field of the header
+        let data_len = read_u16(header_len - 2) as usize;
+        let part = (format_version == 2).then(|| (read_u16(2), read_u16(4)));
+        let flags = if matches!(format_version, 6 | 7) {
+            encoded_data[2]
+        } else {
+            0
+        };
+        let share_count = (format_version == 7).then(|| encoded_data[3]);
+
+        Ok(Self {
+            format_version,
+            header_len,
+            checksum_id,
+            checksum_from,
+            part,
+            flags,
+            share_count,
+            data_len,
+        })
+    }
+
+    /// Whether the header uses a versioned layout rather than the legacy one
+    pub(super) fn is_versioned(&self) -> bool {
+        self.format_version != 0
+    }
+
+    /// Whether the payload may be followed by zero padding
+    pub(super) fn is_padded(&self) -> bool {
+        matches!(self.format_version, 3 | 5 | 6 | 7)
+    }
+
+    /// Total payload size implied by the header, excluding any padding
+    pub(super) fn total_len(&self) -> usize {
+        self.header_len + self.data_len + CHECKSUM_LEN
+    }
+
+    /// Whether a payload of `len` bytes is consistent with the header
+    pub(super) fn fits(&self, len: usize) -> bool {
+        if self.is_padded() {
+            len >= self.total_len()
+        } else {
+            len == self.total_len()
+        }
+    }
+}
+
+/// Builds a payload around `share_data` in the given layout
+///
+/// `max_data_len` is the parser's [`CodecOptions::max_data_len`], so that every share
+/// created here can be read back with the same options.
+///
+/// # Errors
+/// Returns an error if share data, or the padding target of a padded layout, is longer
+/// than `max_data_len` (capped at 65535 bytes), or if share data is longer than the
+/// padding target
+pub(super) fn encode_payload(
+    share_data: &[u8],
+    algorithm: ChecksumAlgorithm,
+    layout: PayloadLayout,
+    max_data_len: usize,
+) -> Result<Zeroizing<Vec<u8>>> {
+    // Never create a share the parser would refuse, and keep the length within a u16
+    let max_data_len = max_data_len.min(u16::MAX as usize);
+    if share_data.len() > max_data_len {
+        bail!(
+            "Share data too large: {} bytes, but a share holds at most {max_data_len} bytes. \
+             Share data is the secret plus 1 byte of Sharks overhead, so secrets are limited \
+             to {} bytes; split larger secrets into chunks and share each chunk separately \
+             (see `split_bytes_multipart`)",
+            share_data.len(),
+            max_data_len.saturating_sub(1)
+        );
+    }
+
+    let padded_len = match layout {
+        PayloadLayout::Padded(pad_to)
+        | PayloadLayout::Flagged(_, Some(pad_to))
+        | PayloadLayout::Counted(_, _, Some(pad_to))
+            if share_data.len() > pad_to =>
+        {
+            bail!(
+                "Share data is {} bytes, longer than the padding target of {pad_to} bytes",
+                share_data.len()
+            )
+        }
+        PayloadLayout::Padded(pad_to)
+        | PayloadLayout::Flagged(_, Some(pad_to))
+        | PayloadLayout::Counted(_, _, Some(pad_to))
+            if pad_to > max_data_len =>
+        {
+            bail!(
+                "Padding target too large: {pad_to} bytes, but a share holds at most \
+                 {max_data_len} bytes"
+            )
+        }
+        PayloadLayout::Padded(pad_to)
+        | PayloadLayout::Flagged(_, Some(pad_to))
+        | PayloadLayout::Counted(_, _, Some(pad_to)) => pad_to,
+        PayloadLayout::Single
+        | PayloadLayout::Part(_)
+        | PayloadLayout::Flagged(_, None)
+        | PayloadLayout::Counted(_, _, None) => share_data.len(),
+    };
+
+    let mut encoded_data = Zeroizing::new(Vec::with_capacity(padded_len + MAX_OVERHEAD));
+    #[allow(
+        clippy::cast_possible_truncation,
+        reason = "share_data.len() already validated to be <= u16::MAX above"
+    )]
+    let length = share_data.len() as u16;
+    let checksum_from = match layout {
+        PayloadLayout::Single | PayloadLayout::Padded(_) => {
+            let marker = if layout == PayloadLayout::Single {
+                FORMAT_V4
+            } else {
+                FORMAT_V5
+            };
+            encoded_data.extend_from_slice(&[marker, algorithm.id()]);
+            encoded_data.extend_from_slice(&length.to_be_bytes());
+            0
+        }
+        PayloadLayout::Flagged(flags, _) => {
+            encoded_data.extend_from_slice(&[FORMAT_V6, algorithm.id(), flags.to_byte()]);
+            encoded_data.extend_from_slice(&length.to_be_bytes());
+            0
+        }
+        PayloadLayout::Counted(flags, share_count, _) => {
+            encoded_data.extend_from_slice(&[
+                FORMAT_V7,
+                algorithm.id(),
+                flags.to_byte(),
+                *share_count,
+            ]);
+            encoded_data.extend_from_slice(&length.to_be_bytes());
+            0
+        }
+        PayloadLayout::Part(part) => {
+            encoded_data.extend_from_slice(&[FORMAT_V2, algorithm.id()]);
+            encoded_data.extend_from_slice(&part.index.to_be_bytes());
+            encoded_data.extend_from_slice(&part.count.to_be_bytes());
+            encoded_data.extend_from_slice(&length.to_be_bytes());
+            2
+        }
+    };
+    encoded_data.extend_from_slice(share_data);
+
+    let checksum = algorithm.checksum(&encoded_data[checksum_from..]);
+    encoded_data.extend_from_slice(&checksum.to_be_bytes());
+    let unpadded_len = encoded_data.len();
+    encoded_data.resize(unpadded_len + padded_len - share_data.len(), 0);
+
+    Ok(encoded_data)
+}
+
+/// Share data extracted from a validated payload
+pub(super) struct DecodedPayload {
+    /// Payload layout version (0 for legacy)
+    pub(super) format_version: u8,
+    /// Part tag of a multipart share (v2 only)
+    pub(super) part: Option<SharePart>,
+    /// Markers recorded with the share (v6 and v7 only; empty otherwise)
+    pub(super) flags: ShareFlags,
+    /// Number of shares dealt alongside this one (v7 only)
+    pub(super) share_count: Option<ShareCount>,
+    /// The share data, wrapped in `Zeroizing` for automatic memory cleanup
+    pub(super) data: Zeroizing<Vec<u8>>,
+}
+
+impl DecodedPayload {
+    /// Returns the share data, rejecting shares that hold one part of a multipart secret
+    ///
+    /// # Errors
+    /// Returns an error if the payload carries a part tag
+    pub(super) fn into_single_part(self) -> Result<Zeroizing<Vec<u8>>> {
+        if let Some(part) = self.part {
+            bail!(
+                "Share holds {part} of a multipart secret; combine it with combine_bytes_multipart"
+            );
+        }
+        Ok(self.data)
+    }
+}
+
+/// Validates a payload in any layout and extracts the share data
+///
+/// # Errors
+/// Returns an error if the payload size disagrees with its header, the share data
+/// exceeds `options.max_data_len`, the checksum algorithm is unknown, checksum
+/// verification fails, the padding is not all zero, or the part tag is out of range
+pub(super) fn decode_payload(encoded_data: &[u8], options: CodecOptions) -> Result<DecodedPayload> {
+    let header = PayloadHeader::parse(encoded_data)?;
+
+    if header.data_len > options.max_data_len {
+        bail!(
+            "Share data length {} exceeds the maximum of {} bytes",
+            header.data_len,
+            options.max_data_len
+        );
+    }
+
+    // Verify total size matches exactly: header + share data + checksum (+ padding)
+    if !header.fits(encoded_data.len()) {
+        bail!(
+            "Encoded data size mismatch: expected {}{} bytes ({} + {} + {CHECKSUM_LEN}), got {}",
+            if header.is_padded() { "at least " } else { "" },
+            header.total_len(),
+            header.header_len,
+            header.data_len,
+            encoded_data.len()
+        );
+    }
+
+    // Resolve the algorithm before comparing, so an unknown id isn't reported as corruption
+    let algorithm = ChecksumAlgorithm::from_id(header.checksum_id)?;
+
+    let data_end = header.header_len + header.data_len;
+    let share_data = &encoded_data[header.header_len..data_end];
+    let checksum_bytes = &encoded_data[data_end..data_end + CHECKSUM_LEN];
+
+    let expected_checksum = algorithm.checksum(&encoded_data[header.checksum_from..data_end]);
+    let actual_checksum = u32::from_be_bytes([
+        checksum_bytes[0],
+        checksum_bytes[1],
+        checksum_bytes[2],
+        checksum_bytes[3],
+    ]);
+
+    if expected_checksum != actual_checksum {
+        bail!(
+            "Checksum verification failed: expected 0x{expected_checksum:08x}, got 0x{actual_checksum:08x}"
+        );
+    }
+
+    // Padding is not checksummed, so insist on the exact bytes the encoder wrote
+    if encoded_data[header.total_len()..].iter().any(|&b| b != 0) {
+        bail!("Share padding is corrupt: padding bytes after the checksum must be zero");
+    }
+
+    let part = header
+        .part
+        .map(|(index, count)| SharePart::new(index, count))
+        .transpose()?;
+    let flags = ShareFlags::from_byte(header.flags)?;
+    let share_count = header
+        .share_count
+        .map(|count| {
+            ShareCount::new(count).map_err(|e| anyhow!("Share records an invalid share count: {e}"))
+        })
+        .transpose()?;
+
+    Ok(DecodedPayload {
+        format_version: header.format_version,
+        part,
+        flags,
+        share_count,
+        data: Zeroizing::new(share_data.to_vec()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a legacy payload the way shares were written before format markers
+    fn legacy_payload(share_data: &[u8]) -> Vec<u8> {
+        let mut payload = u16::try_from(share_data.len())
+            .unwrap()
+            .to_be_bytes()
+            .to_vec();
+        payload.extend_from_slice(share_data);
+        payload.extend_from_slice(&CRC32_ISO_HDLC.checksum(share_data).to_be_bytes());
+        payload
+    }
+
+    /// Builds a v1 or v3 payload, whose checksum covers only the share data
+    fn data_checksummed_payload(marker: u8, share_data: &[u8], padding: usize) -> Vec<u8> {
+        let mut payload = vec![marker, 0];
+        payload.extend_from_slice(&legacy_payload(share_data));
+        payload.resize(payload.len() + padding, 0);
+        payload
+    }
+
+    #[test]
+    fn test_v4_payload_round_trip() {
+        let payload = encode_payload(
+            &[1, 2, 3],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Single,
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        assert_eq!(&payload[..4], &[FORMAT_V4, 0, 0, 3]);
+
+        // The checksum covers the header, length included, as well as the data
+        let (body, checksum) = payload.split_at(payload.len() - CHECKSUM_LEN);
+        assert_eq!(checksum, CRC32_ISO_HDLC.checksum(body).to_be_bytes());
+
+        let decoded = decode_payload(&payload, CodecOptions::default()).unwrap();
+        assert_eq!(decoded.format_version, 4);
+        assert_eq!(*decoded.data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_v1_and_v3_payloads_still_decode() {
+        let v1 = data_checksummed_payload(FORMAT_V1, &[1, 2, 3], 0);
+        let decoded = decode_payload(&v1, CodecOptions::default()).unwrap();
+        assert_eq!(decoded.format_version, 1);
+        assert_eq!(*decoded.data, [1, 2, 3]);
+
+        let v3 = data_checksummed_payload(FORMAT_V3, &[1, 2, 3], 5);
+        let decoded = decode_payload(&v3, CodecOptions::default()).unwrap();
+        assert_eq!(decoded.format_version, 3);
+        assert_eq!(*decoded.data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_v2_payload_carries_part_tag() {
+        let part = SharePart::new(1, 3).unwrap();
+        let payload = encode_payload(
+            &[1, 2, 3],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Part(part),
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        assert_eq!(&payload[..8], &[FORMAT_V2, 0, 0, 1, 0, 3, 0, 3]);
+
+        let decoded = decode_payload(&payload, CodecOptions::default()).unwrap();
+        assert_eq!(decoded.format_version, 2);
+        assert_eq!(decoded.part, Some(part));
+        assert_eq!(*decoded.data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_v2_checksum_covers_part_tag() {
+        let part = SharePart::new(0, 2).unwrap();
+        let mut payload = encode_payload(
+            &[1, 2, 3],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Part(part),
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        payload[3] = 1;
+
+        let err = decode_payload(&payload, CodecOptions::default())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Checksum verification failed"));
+    }
+
+    #[test]
+    fn test_v5_payload_pads_without_changing_data() {
+        let layout = PayloadLayout::Padded(33);
+        let short = encode_payload(
+            &[1, 2, 3],
+            ChecksumAlgorithm::default(),
+            layout,
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        let full = encode_payload(
+            &[7; 33],
+            ChecksumAlgorithm::default(),
+            layout,
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        assert_eq!(short.len(), full.len());
+        assert_eq!(&short[..4], &[FORMAT_V5, 0, 0, 3]);
+
+        let decoded = decode_payload(&short, CodecOptions::default()).unwrap();
+        assert_eq!(decoded.format_version, 5);
+        assert_eq!(*decoded.data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_v5_checksum_covers_length() {
+        let mut payload = encode_payload(
+            &[1, 2, 3, 4],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Padded(16),
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        // A shorter length still fits the padded payload, so only the checksum catches it
+        payload[3] = 3;
+
+        let err = decode_payload(&payload, CodecOptions::default())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Checksum verification failed"));
+    }
+
+    #[test]
+    fn test_v6_payload_records_flags() {
+        let flags = ShareFlags {
+            passphrase_reminder: true,
+            ..ShareFlags::default()
+        };
+        for (layout, padded_len) in [
+            (PayloadLayout::Single.with_flags(flags).unwrap(), 3),
+            (PayloadLayout::Padded(8).with_flags(flags).unwrap(), 8),
+        ] {
+            let payload = encode_payload(
+                &[1, 2, 3],
+                ChecksumAlgorithm::default(),
+                layout,
+                CodecOptions::DEFAULT_MAX_DATA_LEN,
+            )
+            .unwrap();
+            assert_eq!(&payload[..5], &[FORMAT_V6, 0, 1, 0, 3]);
+            assert_eq!(payload.len(), 5 + padded_len + CHECKSUM_LEN);
+
+            let decoded = decode_payload(&payload, CodecOptions::default()).unwrap();
+            assert_eq!(decoded.format_version, 6);
+            assert_eq!(decoded.flags, flags);
+            assert_eq!(*decoded.data, [1, 2, 3]);
+        }
+
+        // Without flags, the layout and its v4/v5 encoding are unchanged
+        assert_eq!(
+            PayloadLayout::Single
+                .with_flags(ShareFlags::default())
+                .unwrap(),
+            PayloadLayout::Single
+        );
+        assert!(
+            PayloadLayout::Part(SharePart::new(0, 2).unwrap())
+                .with_flags(flags)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_v7_payload_records_share_count() {
+        let flags = ShareFlags {
+            passphrase_reminder: true,
+            ..ShareFlags::default()
+        };
+        let five = ShareCount::new(5).unwrap();
+        let share_count = Some(five);
+        for (layout, padded_len, flag_byte) in [
+            (PayloadLayout::Single, 3, 0),
+            (PayloadLayout::Padded(8), 8, 0),
+            (PayloadLayout::Single.with_flags(flags).unwrap(), 3, 1),
+        ] {
+            let layout = layout.with_share_count(share_count).unwrap();
+            let payload = encode_payload(
+                &[1, 2, 3],
+                ChecksumAlgorithm::default(),
+                layout,
+                CodecOptions::DEFAULT_MAX_DATA_LEN,
+            )
+            .unwrap();
+            assert_eq!(&payload[..6], &[FORMAT_V7, 0, flag_byte, 5, 0, 3]);
+            assert_eq!(payload.len(), 6 + padded_len + CHECKSUM_LEN);
+
+            let decoded = decode_payload(&payload, CodecOptions::default()).unwrap();
+            assert_eq!(decoded.format_version, 7);
+            assert_eq!(decoded.share_count, share_count);
+            assert_eq!(decoded.flags.passphrase_reminder, flag_byte == 1);
+            assert_eq!(*decoded.data, [1, 2, 3]);
+        }
+
+        // Flags added afterwards keep the share count
+        assert_eq!(
+            PayloadLayout::Single
+                .with_share_count(share_count)
+                .and_then(|layout| layout.with_flags(flags))
+                .unwrap(),
+            PayloadLayout::Counted(flags, five, None)
+        );
+        assert_eq!(
+            PayloadLayout::Single.with_share_count(None).unwrap(),
+            PayloadLayout::Single
+        );
+        assert!(
+            PayloadLayout::Part(SharePart::new(0, 2).unwrap())
+                .with_share_count(share_count)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_flags_byte_round_trips_each_flag() {
+        for (passphrase_reminder, parity, byte) in [
+            (false, false, 0b00),
+            (true, false, 0b01),
+            (false, true, 0b10),
+            (true, true, 0b11),
+        ] {
+            let flags = ShareFlags {
+                passphrase_reminder,
+                parity,
+            };
+            assert_eq!(flags.to_byte(), byte);
+            assert_eq!(ShareFlags::from_byte(byte).unwrap(), flags);
+            assert_eq!(flags.any(), byte != 0);
+        }
+    }
+
+    #[test]
+    fn test_v6_rejects_unknown_flags() {
+        let flags = ShareFlags {
+            passphrase_reminder: true,
+            ..ShareFlags::default()
+        };
+        let mut payload = encode_payload(
+            &[1, 2, 3],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Flagged(flags, None),
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        payload[2] = 0b100;
+        let (body, _) = payload.split_at(payload.len() - CHECKSUM_LEN);
+        let checksum = CRC32_ISO_HDLC.checksum(body).to_be_bytes();
+        let len = payload.len();
+        payload[len - CHECKSUM_LEN..].copy_from_slice(&checksum);
+
+        let err = decode_payload(&payload, CodecOptions::default())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Unknown share flags"));
+    }
+
+    #[test]
+    fn test_padding_must_be_zero() {
+        let mut payload = encode_payload(
+            &[1, 2, 3],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Padded(8),
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        *payload.last_mut().unwrap() = 1;
+
+        let err = decode_payload(&payload, CodecOptions::default())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Share padding is corrupt"));
+    }
+
+    #[test]
+    fn test_padding_target_below_data_len() {
+        let err = encode_payload(
+            &[0; 33],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Padded(17),
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .err()
+        .unwrap()
+        .to_string();
+        assert!(err.contains("longer than the padding target of 17 bytes"));
+    }
+
+    #[test]
+    fn test_legacy_payload_is_implicit_algorithm_zero() {
+        let payload = legacy_payload(&[0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let header = PayloadHeader::parse(&payload).unwrap();
+        assert!(!header.is_versioned());
+        assert_eq!(header.checksum_id, ChecksumAlgorithm::Crc32IsoHdlc.id());
+
+        let decoded = decode_payload(&payload, CodecOptions::default()).unwrap();
+        assert_eq!(decoded.format_version, 0);
+        assert_eq!(*decoded.data, [0xDE, 0xAD, 0xBE, 0xEF]);
+    }
+
+    #[test]
+    fn test_unknown_checksum_algorithm_is_not_a_checksum_mismatch() {
+        let mut payload = encode_payload(
+            &[1, 2, 3],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Single,
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        payload[1] = 7;
+
+        let err = decode_payload(&payload, CodecOptions::default())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Unknown checksum algorithm 7"));
+        assert!(!err.contains("Checksum verification failed"));
+    }
+
+    #[test]
+    fn test_oversized_share_data_explains_the_limit() {
+        let err = encode_payload(
+            &vec![0; 2000],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Single,
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .err()
+        .unwrap()
+        .to_string();
+        assert!(err.contains("2000 bytes, but a share holds at most 1024 bytes"));
+        assert!(err.contains("secrets are limited to 1023 bytes"));
+        assert!(err.contains("split larger secrets into chunks"));
+
+        // A raised parse limit still cannot exceed what the length field holds
+        let err = encode_payload(
+            &vec![0; 70_000],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Single,
+            usize::MAX,
+        )
+        .err()
+        .unwrap()
+        .to_string();
+        assert!(err.contains("70000 bytes, but a share holds at most 65535 bytes"));
+        assert!(err.contains("secrets are limited to 65534 bytes"));
+    }
+
+    #[test]
+    fn test_unknown_format_marker() {
+        let mut payload = encode_payload(
+            &[1, 2, 3],
+            ChecksumAlgorithm::default(),
+            PayloadLayout::Single,
+            CodecOptions::DEFAULT_MAX_DATA_LEN,
+        )
+        .unwrap();
+        payload[0] = 0x9F;
+
+        let err = decode_payload(&payload, CodecOptions::default())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("Unsupported share format marker 0x9f"));
+    }
+}
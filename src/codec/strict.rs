@@ -0,0 +1,241 @@
+//! Strict shamir39 share layout, for exchanging shares with reference implementations
+//!
+//! A strict share follows the original [shamir39 specification] word for word:
+//! `shamir39-p1 <param words> <data words>`, where the data words carry the raw share
+//! bytes with no format marker, length prefix or checksum. Decoders that only know
+//! the specification can read the data words; in exchange, a strict share has no
+//! integrity check of its own; a mistyped word yields wrong share data rather than an
+//! error. The only safeguard left is the BIP39 checksum of the recovered mnemonic.
+//!
+//! The share data length is not stored. The data words hold 0-10 bits of left padding,
+//! leaving at most two candidate lengths, and a Sharks share always starts with its
+//! x-coordinate, `index + 1`. Reading one byte too many would start with 8 padding bits
+//! instead, which is zero, so the candidate that starts with the expected x-coordinate
+//! is the right one.
+//!
+//! [shamir39 specification]: https://github.com/iancoleman/shamir39/blob/master/specification.md
+
+use anyhow::{Result, bail};
+use zeroize::Zeroizing;
+
+use super::params::encode_parameters_in;
+use super::payload::{DecodedPayload, ShareFlags};
+use super::{
+    CodecOptions, Padding, Shamir39Mnemonic, bits, decode_header_words, encode_share_data,
+    word_indices,
+};
+use crate::domain::{ShareIndex, Threshold};
+
+/// Version word that identifies a strict shamir39 share
+pub const STRICT_VERSION_WORD: &str = "shamir39-p1";
+
+/// Encodes a Sharks share as a strict shamir39 mnemonic
+///
+/// `share_data` must be a Sharks share (x-coordinate followed by the y-values) dealt
+/// for `index`, as [`parse_share_strict`] relies on its first byte to infer its length.
+///
+/// # Errors
+/// Returns an error if `share_data` is empty, longer than
+/// [`CodecOptions::DEFAULT_MAX_DATA_LEN`] or does not start with the x-coordinate
+/// `index + 1`, or if parameter encoding fails
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::codec::CodecOptions;
+/// use shameless::codec::strict::{create_share_strict, parse_share_strict};
+/// use shameless::domain::{ShareIndex, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // Share index 2 has Sharks x-coordinate 3
+/// let share = create_share_strict(&[3, 0xDE, 0xAD], Threshold::new(2)?, ShareIndex::new(2)?)?;
+/// assert!(share.as_str().starts_with("shamir39-p1 "));
+///
+/// let (_, index, data) = parse_share_strict(share.as_str(), &CodecOptions::default())?;
+/// assert_eq!(*index, 2);
+/// assert_eq!(*data, [3, 0xDE, 0xAD]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_share_strict(
+    share_data: &[u8],
+    threshold: Threshold,
+    index: ShareIndex,
+) -> Result<Shamir39Mnemonic> {
+    create_share_strict_with_options(share_data, threshold, index, &CodecOptions::default())
+}
+
+/// Encodes a Sharks share as a strict shamir39 mnemonic using custom [`CodecOptions`]
+///
+/// The parameter and data words are drawn from the `options.language` wordlist.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`create_share_strict`] (with
+/// `options.max_data_len` as the length limit), or if `options.flags` sets any flag or
+/// `options.share_count` is set, as strict shares have no payload to record them in
+pub fn create_share_strict_with_options(
+    share_data: &[u8],
+    threshold: Threshold,
+    index: ShareIndex,
+    options: &CodecOptions,
+) -> Result<Shamir39Mnemonic> {
+    if options.flags.any() {
+        bail!("Strict shamir39 shares cannot record share flags; use words or base64");
+    }
+    if options.share_count.is_some() {
+        bail!("Strict shamir39 shares cannot record the share count; use words or base64");
+    }
+
+    if share_data.len() > options.max_data_len {
+        bail!(
+            "Share data too large: {} bytes, but a share holds at most {} bytes",
+            share_data.len(),
+            options.max_data_len
+        );
+    }
+
+    let expected_x = index.x_coordinate();
+    if share_data.first() != Some(&expected_x) {
+        bail!(
+            "Strict shares must hold a Sharks share starting with x-coordinate {expected_x} for share index {index}"
+        );
+    }
+
+    let mut words = vec![STRICT_VERSION_WORD.to_string()];
+    words.extend(encode_parameters_in(options.language, threshold, index)?);
+    // The shamir39 specification fixes the alignment
+    words.extend(encode_share_data(
+        share_data,
+        options.language,
+        Padding::Left,
+    )?);
+
+    Ok(Shamir39Mnemonic::new_unchecked(words.join(" ")))
+}
+
+/// Parses a strict shamir39 mnemonic into components
+///
+/// No checksum is verified, since the layout has none.
+///
+/// # Errors
+/// Returns an error if the version word or parameters are invalid, the share data
+/// exceeds `options.max_data_len`, or no candidate length starts with the share's
+/// x-coordinate
+pub fn parse_share_strict(
+    mnemonic: &str,
+    options: &CodecOptions,
+) -> Result<(Threshold, ShareIndex, Zeroizing<Vec<u8>>)> {
+    let (threshold, index, payload) = decode_strict(mnemonic, *options)?;
+    Ok((threshold, index, payload.data))
+}
+
+/// Decodes a strict share into the payload shape shared with the other encodings
+pub(super) fn decode_strict(
+    mnemonic: &str,
+    options: CodecOptions,
+) -> Result<(Threshold, ShareIndex, DecodedPayload)> {
+    // Bound the input size before allocating anything proportional to it
+    let word_count = mnemonic.split_whitespace().count();
+    let max_words = 1 + 2 + options.max_data_len.saturating_mul(8).div_ceil(11);
+    if word_count > max_words {
+        bail!(
+            "Share has {word_count} words, far more than expected for a Shamir share (at most \
+             {max_words} for share data of at most {} bytes) — this doesn't look like a valid share",
+            options.max_data_len
+        );
+    }
+
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    let (threshold, index, param_word_count) =
+        decode_header_words(&words, STRICT_VERSION_WORD, options.language)?;
+
+    let data_words = &words[1 + param_word_count..];
+    if data_words.is_empty() {
+        bail!(
+            "No share data words found: the share ends after its parameter words and appears \
+             truncated; check that the whole share was copied"
+        );
+    }
+
+    let indices = word_indices(data_words, options.language)?;
+    let expected_x = index.x_coordinate();
+    let max_bytes = data_words.len() * 11 / 8;
+    for byte_len in [max_bytes, max_bytes.saturating_sub(1)] {
+        if byte_len == 0 || bits::word_count(byte_len) != data_words.len() {
+            continue;
+        }
+
+        let data = bits::decode_indices(&indices, byte_len)?;
+        if data[0] == expected_x {
+            if data.len() > options.max_data_len {
+                bail!(
+                    "Share data length {} exceeds the maximum of {} bytes",
+                    data.len(),
+                    options.max_data_len
+                );
+            }
+
+            return Ok((
+                threshold,
+                index,
+                DecodedPayload {
+                    format_version: 0,
+                    part: None,
+                    flags: ShareFlags::default(),
+                    share_count: None,
+                    data,
+                },
+            ));
+        }
+    }
+
+    bail!(
+        "Share data does not start with x-coordinate {expected_x} expected for share index {index}: \
+         the share is corrupt or was not dealt for this index"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_round_trip_infers_length() {
+        for len in 1u16..200 {
+            let index = ShareIndex::new(u8::try_from(len % 200).unwrap()).unwrap();
+            let mut data = vec![*index + 1];
+            data.extend((1..len).map(|i| u8::try_from(i % 251).unwrap()));
+
+            let share = create_share_strict(&data, Threshold::new(3).unwrap(), index).unwrap();
+            let (threshold, parsed_index, parsed) =
+                parse_share_strict(share.as_str(), &CodecOptions::default()).unwrap();
+            assert_eq!(*threshold, 3);
+            assert_eq!(parsed_index, index);
+            assert_eq!(*parsed, data);
+        }
+    }
+
+    #[test]
+    fn test_strict_share_has_no_length_or_checksum() {
+        let share = create_share_strict(
+            &[1, 2, 3, 4],
+            Threshold::new(2).unwrap(),
+            ShareIndex::new(0).unwrap(),
+        )
+        .unwrap();
+
+        // version + one parameter word + ceil(32 / 11) data words
+        assert_eq!(share.as_str().split(' ').count(), 1 + 1 + 3);
+    }
+
+    #[test]
+    fn test_strict_rejects_data_not_dealt_for_index() {
+        let err = create_share_strict(
+            &[9, 2, 3],
+            Threshold::new(2).unwrap(),
+            ShareIndex::new(0).unwrap(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("x-coordinate 1"));
+    }
+}
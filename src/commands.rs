@@ -1,254 +1,4400 @@
+use std::collections::HashMap;
+use std::io::{Read, Write};
+
 use anyhow::{Context, Result, anyhow, bail};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use bip39::{Language, Mnemonic};
 use blahaj::Sharks;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use log::{debug, info};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use zeroize::Zeroizing;
 
-use crate::codec;
-use crate::domain::{ShareIndex, SplitConfig};
+use crate::codec::{
+    self, CodecOptions, DecodedShare, Shamir39Mnemonic, ShareEncoding, ShareFlags, SharePart,
+};
+use crate::domain::{ShareCount, ShareIndex, SplitConfig, Threshold};
 
 /// Split a mnemonic into Shamir Secret Shares encoded as shamir39 mnemonics
 ///
-/// Returns a vector of shamir39-encoded share mnemonics.
+/// Returns a vector of shamir39-encoded share mnemonics. The order is a guarantee:
+/// the share at position `N` always embeds [`ShareIndex`] `N`, so callers may key
+/// shares by their position.
 ///
 /// # Errors
 /// Returns an error if mnemonic parsing fails, share creation fails, or encoding fails
 pub fn split_mnemonic(mnemonic_str: &str, config: SplitConfig) -> Result<Vec<String>> {
+    split_mnemonic_with_options(mnemonic_str, config, &SplitOptions::default())
+}
+
+/// Collapses the separators found in copy-pasted mnemonics to single ASCII spaces
+///
+/// Any Unicode whitespace (no-break spaces, tabs, newlines, ...) and the invisible
+/// zero-width space, word joiner and byte-order mark all separate words. NFKD
+/// normalization, which BIP39 specifies for the mnemonic, is left to
+/// `Mnemonic::parse_in`, which applies it to its input.
+fn normalize_mnemonic_input(mnemonic_str: &str) -> Zeroizing<String> {
+    let is_separator =
+        |c: char| c.is_whitespace() || matches!(c, '\u{200B}' | '\u{2060}' | '\u{FEFF}');
+
+    let mut normalized = Zeroizing::new(String::with_capacity(mnemonic_str.len()));
+    for word in mnemonic_str
+        .split(is_separator)
+        .filter(|word| !word.is_empty())
+    {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        normalized.push_str(word);
+    }
+    normalized
+}
+
+/// Turns a BIP39 parse error into a message that tells the user what to fix
+///
+/// An unknown word is likely a typo in a single word, while a checksum failure means
+/// every word is valid but the phrase as a whole is wrong, so the two get distinct
+/// messages.
+fn describe_mnemonic_error(
+    mnemonic_str: &str,
+    language: Language,
+    error: &bip39::Error,
+) -> anyhow::Error {
+    match *error {
+        bip39::Error::UnknownWord(idx) => {
+            let word = mnemonic_str.split_whitespace().nth(idx).unwrap_or_default();
+            anyhow!(
+                "word {} ('{word}') is not in the BIP39 {language} wordlist",
+                idx + 1
+            )
+        }
+        bip39::Error::InvalidChecksum => {
+            anyhow!("the mnemonic's BIP39 checksum is invalid — did you transcribe a word wrong?")
+        }
+        _ => anyhow!("{error}"),
+    }
+}
+
+/// Options controlling how shares are produced
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SplitOptions {
+    /// Text encoding of the emitted shares (BIP39 words by default)
+    pub encoding: ShareEncoding,
+
+    /// Zero-pad every share as if it held this many bytes of share data
+    ///
+    /// Share data is the secret plus one byte, so a target of 33 makes shares of 12- and
+    /// 24-word mnemonics the same size. Not supported for strict or multipart shares.
+    pub pad_to: Option<usize>,
+
+    /// BIP39 wordlist of the input mnemonic, also used for the words of the shares
+    /// (English by default)
+    pub language: Language,
+
+    /// Mark every share as belonging to a wallet that also uses a BIP39 passphrase
+    ///
+    /// Only the fact that a passphrase exists is recorded, never the passphrase itself;
+    /// combining such shares reports [`RecoveredMnemonic::passphrase_reminder`]. Not
+    /// supported for strict or multipart shares.
+    pub passphrase_reminder: bool,
+
+    /// Leave the number of shares dealt out of the shares
+    ///
+    /// By default, word and base64 shares of a whole secret record the share count, so
+    /// recovery can report how many shares are missing (see
+    /// [`RecoveredMnemonic::missing_shares`]). Omit it to keep each custodian from
+    /// learning how many other shares exist. Strict and multipart shares never record it.
+    pub omit_share_count: bool,
+
+    /// Deal this many parity shares on top of the configured share count, see
+    /// [`split_with_parity`]. Not supported for strict or multipart shares.
+    pub parity_shares: u8,
+}
+
+/// Split a mnemonic into Shamir Secret Shares using custom [`SplitOptions`]
+///
+/// # Errors
+/// Returns an error under the same conditions as [`split_mnemonic`]
+pub fn split_mnemonic_with_options(
+    mnemonic_str: &str,
+    config: SplitConfig,
+    options: &SplitOptions,
+) -> Result<Vec<String>> {
+    split_mnemonic_typed_with_options(mnemonic_str, config, options)
+        .map(|shares| into_strings(&shares))
+}
+
+/// Split a mnemonic into shares, keeping them as [`Shamir39Mnemonic`] values
+///
+/// Like [`split_mnemonic`], but each share stays in its `Zeroizing` backing instead of
+/// being copied into a plain `String`, which is not wiped when dropped. The order
+/// guarantee of [`split_mnemonic`] holds.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`split_mnemonic`]
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::split_mnemonic_typed;
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+/// let config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+///
+/// let shares = split_mnemonic_typed(mnemonic, config)?;
+/// assert_eq!(shares.len(), 3);
+/// assert!(shares[0].as_str().starts_with("shameless "));
+/// # Ok(())
+/// # }
+/// ```
+pub fn split_mnemonic_typed(
+    mnemonic_str: &str,
+    config: SplitConfig,
+) -> Result<Vec<Shamir39Mnemonic>> {
+    split_mnemonic_typed_with_options(mnemonic_str, config, &SplitOptions::default())
+}
+
+/// Split a mnemonic into [`Shamir39Mnemonic`] shares using custom [`SplitOptions`]
+///
+/// # Errors
+/// Returns an error under the same conditions as [`split_mnemonic`]
+pub fn split_mnemonic_typed_with_options(
+    mnemonic_str: &str,
+    config: SplitConfig,
+    options: &SplitOptions,
+) -> Result<Vec<Shamir39Mnemonic>> {
     // Parse the input mnemonic
-    let mnemonic = Mnemonic::parse_in(Language::English, mnemonic_str)
+    let normalized = normalize_mnemonic_input(mnemonic_str);
+    let mnemonic = Mnemonic::parse_in(options.language, normalized.as_str())
+        .map_err(|e| describe_mnemonic_error(&normalized, options.language, &e))
         .context("Failed to parse input mnemonic")?;
 
     let entropy = Zeroizing::new(mnemonic.to_entropy());
 
+    deal_shares(&entropy, config, *options, None)
+}
+
+/// Split a mnemonic into shares plus `parity_shares` extra parity shares
+///
+/// Shamir shares are already a Reed-Solomon code: share `i` is the value at `x = i + 1`
+/// of a random polynomial of degree `threshold - 1` whose constant term is the secret,
+/// so any `threshold` shares determine it. Parity shares are further values of the same
+/// polynomial. Splitting `k`-of-`n` with `p` parity shares therefore deals `n + p`
+/// shares, any `k` of which reconstruct the secret: up to `n + p - k` shares may be
+/// lost, `p` more than the plain split tolerates, while fewer than `k` shares still
+/// reveal nothing.
+///
+/// The shares record `n + p` as their share count, and the parity shares (the last `p`,
+/// at indices `n` to `n + p - 1`) are marked in their flags. When more than `k` shares
+/// are combined, parity or not, the spare shares act as check symbols:
+/// [`combine_shares`] verifies that every share lies on the recovered polynomial and
+/// fails otherwise, catching a share from another split that checksums alone cannot.
+/// With `m` shares, that check detects up to `m - k` inconsistent shares;
+/// [`CombineOptions::robust`] can skip them instead.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`split_mnemonic`], or if the shares
+/// and parity shares together exceed [`ShareCount::MAX`]
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{combine_shares, split_with_parity};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+/// let config = SplitConfig::new(Threshold::new(3)?, ShareCount::new(5)?)?;
+///
+/// // 3-of-5 plus 2 parity shares: any 3 of the 7 still recover the mnemonic
+/// let shares = split_with_parity(mnemonic, config, 2)?;
+/// assert_eq!(shares.len(), 7);
+/// assert_eq!(*combine_shares(&shares[4..])?, mnemonic);
+/// # Ok(())
+/// # }
+/// ```
+pub fn split_with_parity(
+    mnemonic_str: &str,
+    config: SplitConfig,
+    parity_shares: u8,
+) -> Result<Vec<String>> {
+    let options = SplitOptions {
+        parity_shares,
+        ..SplitOptions::default()
+    };
+    split_mnemonic_with_options(mnemonic_str, config, &options)
+}
+
+/// Copies shares into plain strings for the functions that return `String`s
+fn into_strings(shares: &[Shamir39Mnemonic]) -> Vec<String> {
+    shares.iter().map(ToString::to_string).collect()
+}
+
+/// Smallest secret, in bytes, worth protecting with Shamir sharing
+///
+/// Sharing hides the secret from anyone holding fewer than threshold shares, but a
+/// secret short enough to enumerate can simply be guessed. 16 bytes (128 bits) matches
+/// the entropy of the shortest mnemonic `split` accepts; callers splitting raw secrets
+/// with [`split_bytes_multipart`] should warn about anything shorter.
+pub const RECOMMENDED_MIN_SECRET_LEN: usize = 16;
+
+// Every share count `ShareCount` admits must map onto a distinct share index, and so a
+// distinct nonzero GF256 x-coordinate
+const _: () = assert!(ShareCount::MAX - 1 <= ShareIndex::MAX);
+
+/// Checks that `num_shares` shares fit in GF256: at most [`ShareCount::MAX`], so the
+/// highest index dealt, `num_shares - 1`, stays below [`ShareIndex::MAX`]
+///
+/// [`ShareCount`] already enforces this; the check spells out the hard limit on the
+/// split path itself.
+fn ensure_shares_fit_field(num_shares: usize) -> Result<()> {
+    if num_shares > usize::from(ShareCount::MAX) {
+        bail!(
+            "cannot create {num_shares} shares: GF256 supports at most {} distinct shares",
+            ShareCount::MAX
+        );
+    }
+    Ok(())
+}
+
+/// Number of shares to deal for `config`: the configured share count plus any parity
+/// shares requested in `options`
+fn dealt_share_count(
+    config: SplitConfig,
+    options: SplitOptions,
+    part: Option<SharePart>,
+) -> Result<ShareCount> {
+    if options.parity_shares == 0 {
+        ensure_shares_fit_field(usize::from(*config.share_count()))?;
+        return Ok(config.share_count());
+    }
+    if part.is_some() {
+        bail!("Multipart secrets cannot have parity shares");
+    }
+    if options.encoding == ShareEncoding::StrictSpec {
+        bail!("Strict shamir39 shares cannot mark parity shares; use words or base64");
+    }
+
+    let total = usize::from(*config.share_count()) + usize::from(options.parity_shares);
+    ensure_shares_fit_field(total)?;
+    ShareCount::new(u8::try_from(total)?)
+}
+
+/// Splits raw entropy into shares and encodes them per `options`, tagging each share
+/// with `part` when the entropy is one part of a multipart secret
+fn deal_shares(
+    entropy: &[u8],
+    config: SplitConfig,
+    options: SplitOptions,
+    part: Option<SharePart>,
+) -> Result<Vec<Shamir39Mnemonic>> {
+    // Sharks needs at least one byte of secret to produce a share with any y-values
+    if entropy.is_empty() {
+        bail!("Cannot split an empty secret: Shamir sharing needs at least 1 byte");
+    }
+
     // Extract threshold and share count from config
     let threshold = config.threshold();
-    let num_shares = *config.share_count();
+    let data_shares = *config.share_count();
+    let dealt = dealt_share_count(config, options, part)?;
+    let num_shares = *dealt;
 
     // Create Sharks dealer for this threshold
     let sharks = Sharks(*threshold);
 
     // Create shares using blahaj
-    let dealer = sharks.dealer(&entropy);
+    let dealer = sharks.dealer(entropy);
     let share_vec: Vec<_> = dealer.take(num_shares as usize).collect();
 
+    // The dealer is expected to be unbounded up to the GF256 limit; make that explicit
+    // rather than silently emitting fewer shares than requested
+    if share_vec.len() != usize::from(num_shares) {
+        bail!(
+            "Dealer produced fewer shares than requested: got {}, expected {num_shares}",
+            share_vec.len()
+        );
+    }
+
+    let flags = ShareFlags {
+        passphrase_reminder: options.passphrase_reminder,
+        parity: false,
+    };
+    let records_share_count = !options.omit_share_count
+        && part.is_none()
+        && options.encoding != ShareEncoding::StrictSpec;
+    let base_options = CodecOptions {
+        language: options.language,
+        flags,
+        share_count: records_share_count.then_some(dealt),
+        ..CodecOptions::default()
+    };
+
+    info!(
+        "dealing {num_shares} shares with threshold {threshold} as {:?}",
+        options.encoding
+    );
+    if options.parity_shares > 0 {
+        info!(
+            "the last {} shares are parity shares",
+            options.parity_shares
+        );
+    }
+
     // Encode each share as a shamir39 mnemonic
     let mut share_mnemonics = Vec::new();
-    for (idx, share) in share_vec.iter().enumerate() {
+    for (index, share) in dealt.iter_indices().zip(&share_vec) {
         // Convert share to bytes
         let share_bytes = Zeroizing::new(Vec::from(share));
+        let flags = ShareFlags {
+            parity: *index >= data_shares,
+            ..flags
+        };
+        let codec_options = CodecOptions {
+            flags,
+            ..base_options
+        };
 
         // Create shamir39 mnemonic with embedded metadata
-        // Safe: idx < num_shares (which is u8), so idx always fits in u8
-        let idx_u8 =
-            u8::try_from(idx).unwrap_or_else(|_| unreachable!("idx < num_shares fits in u8"));
-        let share_mnemonic =
-            codec::create_share(&share_bytes, threshold, ShareIndex::new(idx_u8)?)?;
+        let share_mnemonic = match (options.encoding, part, options.pad_to) {
+            (_, Some(_), Some(_)) => bail!("Multipart shares cannot be padded"),
+            (ShareEncoding::Words, None, None) => {
+                codec::create_share_with_options(&share_bytes, threshold, index, &codec_options)?
+            }
+            (ShareEncoding::Words, Some(part), None) => {
+                codec::create_share_part(&share_bytes, threshold, index, part, &codec_options)?
+            }
+            (ShareEncoding::Words, None, Some(pad_to)) => {
+                codec::create_share_padded(&share_bytes, threshold, index, pad_to, &codec_options)?
+            }
+            (ShareEncoding::Base64, Some(_), None) if flags.any() => {
+                bail!("Shares of a multipart secret cannot record share flags")
+            }
+            (ShareEncoding::Base64, Some(part), None) => Shamir39Mnemonic::new_unchecked(
+                codec::compact::create_share_compact_part(&share_bytes, threshold, index, part)?,
+            ),
+            (ShareEncoding::Base64, None, pad_to) => {
+                Shamir39Mnemonic::new_unchecked(codec::compact::create_share_compact_with_options(
+                    &share_bytes,
+                    threshold,
+                    index,
+                    pad_to,
+                    &codec_options,
+                )?)
+            }
+            (ShareEncoding::StrictSpec, None, None) => {
+                codec::strict::create_share_strict_with_options(
+                    &share_bytes,
+                    threshold,
+                    index,
+                    &codec_options,
+                )?
+            }
+            (ShareEncoding::StrictSpec, Some(_), None) => {
+                bail!("Strict shamir39 shares cannot record a part tag; use words or base64")
+            }
+            (ShareEncoding::StrictSpec, None, Some(_)) => {
+                bail!("Strict shamir39 shares have no length field and cannot be padded")
+            }
+        };
 
-        share_mnemonics.push(share_mnemonic.to_string());
+        share_mnemonics.push(share_mnemonic);
     }
 
     Ok(share_mnemonics)
 }
 
-/// Combine Shamir Secret Shares to reconstruct the original mnemonic
+/// Options controlling how shares are combined
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CombineOptions {
+    /// Tolerate shares whose threshold disagrees with a clear majority
+    ///
+    /// When `false` (the default), any threshold disagreement is an error. When `true`,
+    /// the threshold shared by more than half of the shares wins, and outliers are
+    /// dropped with a warning.
+    pub lenient: bool,
+
+    /// Tolerate corrupt shares when more than the threshold are supplied
+    ///
+    /// Shares that fail to parse (e.g. checksum errors) are dropped with a warning, and
+    /// threshold-sized subsets of the rest are tried until one recovers a secret that a
+    /// spare share confirms (see [`combine_shares_with_options`]).
+    pub robust: bool,
+
+    /// Threshold the shares are known to have been dealt with
+    ///
+    /// When set, combining fails if the threshold derived from the shares differs, e.g.
+    /// because shares from another scheme were slipped in.
+    pub expect_threshold: Option<Threshold>,
+
+    /// BIP39 wordlist of the shares' words, also used for the recovered mnemonic
+    /// (English by default)
+    pub language: Language,
+
+    /// What to do with several copies of the share at one index
+    pub dedup: DedupPolicy,
+
+    /// After recovering the secret, check that it regenerates every share it was
+    /// recovered from
+    ///
+    /// Each share is recomputed from the secret and threshold-1 of the other shares,
+    /// using GF(256) arithmetic written independently of blahaj, so a silent error in
+    /// the Shamir math fails the combine instead of yielding a wrong secret.
+    pub paranoid_verify: bool,
+}
+
+/// How combining treats shares given more than once, e.g. both a transcribed and a
+/// photographed copy of the same share
+///
+/// Copies are matched by their decoded share index. Copies that disagree on their
+/// threshold or data conflict, and are an error under every policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum DedupPolicy {
+    /// Fail on any repeated share index
+    #[default]
+    Error,
+    /// Keep the first copy of each share
+    First,
+    /// Keep the last copy of each share
+    Last,
+}
+
+/// Domain-separation tag mixed into secret fingerprints
+const FINGERPRINT_TAG: &[u8] = b"shameless/fingerprint/v1";
+
+/// Computes a short fingerprint identifying a secret without revealing it
+///
+/// The fingerprint is the first 4 bytes of a SHA-256 hash over a fixed tag and the
+/// entropy, as 8 lowercase hex characters. It is stable across runs, so it can be
+/// logged (e.g. in CI) to confirm the same secret was recovered each time.
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::entropy_fingerprint;
+///
+/// let fingerprint = entropy_fingerprint(&[0u8; 16]);
+/// assert_eq!(fingerprint.len(), 8);
+/// assert_eq!(fingerprint, entropy_fingerprint(&[0u8; 16]));
+/// assert_ne!(fingerprint, entropy_fingerprint(&[1u8; 16]));
+/// ```
+#[must_use]
+pub fn entropy_fingerprint(entropy: &[u8]) -> String {
+    let digest = Sha256::new()
+        .chain_update(FINGERPRINT_TAG)
+        .chain_update(entropy)
+        .finalize();
+
+    hex::encode(&digest[..4])
+}
+
+/// Entropy lengths, in bytes, that BIP39 turns into 12, 15, 18, 21 and 24 words
+const BIP39_ENTROPY_LENS: [usize; 5] = [16, 20, 24, 28, 32];
+
+/// Create a BIP39 mnemonic from caller-supplied entropy (e.g. hashed dice rolls)
 ///
-/// Returns the reconstructed BIP39 mnemonic as a string.
+/// Unlike a generated mnemonic, the entropy here comes from the user, so it is checked
+/// for the patterns of a catastrophic mistake: all bytes the same (e.g. all zero), a
+/// counting sequence, bytes confined to a narrow range, as when ASCII dice digits are
+/// passed instead of the bytes they encode, or a short repeating pattern. These checks
+/// only catch the obvious cases; they cannot tell whether the entropy was truly random.
 ///
 /// # Errors
-/// Returns an error if share decoding fails, share combination fails, or mnemonic reconstruction fails
-pub fn combine_shares(share_strings: &[String]) -> Result<String> {
-    if share_strings.is_empty() {
-        bail!("No shares provided");
+/// Returns an error if the entropy is not 16, 20, 24, 28 or 32 bytes long, or looks
+/// too regular to be random
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::generate_mnemonic_from_entropy;
+///
+/// let entropy = [
+///     0x0c, 0x1e, 0x24, 0xe5, 0x91, 0x77, 0x79, 0xd2,
+///     0x97, 0xe1, 0x4d, 0x45, 0xf1, 0x4e, 0x1a, 0x1a,
+/// ];
+/// let mnemonic = generate_mnemonic_from_entropy(&entropy).unwrap();
+/// assert_eq!(mnemonic.split_whitespace().count(), 12);
+///
+/// assert!(generate_mnemonic_from_entropy(&[0u8; 16]).is_err());
+/// assert!(generate_mnemonic_from_entropy(b"1234561234561234").is_err());
+/// ```
+pub fn generate_mnemonic_from_entropy(entropy: &[u8]) -> Result<Zeroizing<String>> {
+    if !BIP39_ENTROPY_LENS.contains(&entropy.len()) {
+        bail!(
+            "Entropy must be 16, 20, 24, 28 or 32 bytes for a 12- to 24-word mnemonic (got {} bytes)",
+            entropy.len()
+        );
     }
 
-    let mut parsed_shares = Vec::new();
-    let mut threshold_from_shares = None;
+    if let Some(reason) = low_entropy_reason(entropy) {
+        bail!(
+            "Refusing to create a mnemonic from low-entropy input: {reason}; anyone could guess the resulting mnemonic"
+        );
+    }
 
-    for (idx, share_str) in share_strings.iter().enumerate() {
-        // Parse shamir39 mnemonic
-        let (threshold, _share_index, share_data) = codec::parse_share(share_str)
-            .with_context(|| format!("Failed to parse share #{}", idx + 1))?;
+    Mnemonic::from_entropy(entropy)
+        .map(|mnemonic| mnemonic_to_zeroizing_string(&mnemonic))
+        .context("Failed to create mnemonic from entropy")
+}
 
-        // Validate threshold consistency
-        match threshold_from_shares {
-            None => {
-                threshold_from_shares = Some(threshold);
-            }
-            Some(t) if t != threshold => {
+/// Explains why `entropy` is obviously not random, if it is
+///
+/// Random entropy of 16 bytes or more all but never fits in a window of 64 byte values
+/// or has fewer distinct byte values than half its length. The check is a heuristic that
+/// errs toward letting input through: a `None` says nothing about how random the bytes
+/// are, e.g. a hash of a guessable phrase passes.
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::low_entropy_reason;
+///
+/// assert_eq!(low_entropy_reason(&[0u8; 16]).unwrap(), "every byte is 0x00");
+/// assert!(low_entropy_reason(&hex::decode("0c1e24e5917779d297e14d45f14e1a1a").unwrap()).is_none());
+/// ```
+#[must_use]
+pub fn low_entropy_reason(entropy: &[u8]) -> Option<String> {
+    let first = *entropy.first()?;
+    if entropy.iter().all(|&byte| byte == first) {
+        return Some(format!("every byte is 0x{first:02x}"));
+    }
+
+    let step = entropy.get(1)?.wrapping_sub(first);
+    if entropy
+        .windows(2)
+        .all(|pair| pair[1].wrapping_sub(pair[0]) == step)
+    {
+        return Some("the bytes form a counting sequence".to_string());
+    }
+
+    let (min, max) = entropy
+        .iter()
+        .fold((u8::MAX, u8::MIN), |(min, max), &byte| {
+            (min.min(byte), max.max(byte))
+        });
+    if max - min < 64 {
+        return Some(format!(
+            "every byte lies between 0x{min:02x} and 0x{max:02x} (were dice digits or hex passed as text?)"
+        ));
+    }
+
+    let mut seen = [false; 256];
+    for &byte in entropy {
+        seen[usize::from(byte)] = true;
+    }
+    let distinct = seen.iter().filter(|&&seen| seen).count();
+    if distinct < entropy.len() / 2 {
+        return Some(format!(
+            "only {distinct} distinct byte values in {} bytes",
+            entropy.len()
+        ));
+    }
+
+    None
+}
+
+/// A reconstructed mnemonic along with any non-fatal advisories raised while combining
+///
+/// `Debug` shows everything but the mnemonic, so results can be logged safely.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecoveredMnemonic {
+    /// The reconstructed BIP39 mnemonic, wrapped in `Zeroizing` for automatic memory cleanup
+    pub mnemonic: Zeroizing<String>,
+    /// Fingerprint of the recovered entropy (see [`entropy_fingerprint`])
+    pub fingerprint: String,
+    /// Threshold of the shares the mnemonic was recovered from
+    pub threshold: Threshold,
+    /// Indices of the shares the mnemonic was recovered from, in input order
+    ///
+    /// Shares dropped along the way (e.g. outliers in lenient mode, or corrupt shares in
+    /// robust mode) are left out. This is share metadata, not secret, so it is safe to
+    /// log, e.g. to record which custodians took part.
+    pub used_indices: Vec<ShareIndex>,
+    /// Human-readable warnings (e.g. outlier shares dropped in lenient mode)
+    pub warnings: Vec<String>,
+    /// Whether any share used was marked as belonging to a wallet with a BIP39
+    /// passphrase (see [`SplitOptions::passphrase_reminder`])
+    ///
+    /// The passphrase itself is not recoverable from shares: without it, the mnemonic
+    /// derives a different, empty wallet.
+    pub passphrase_reminder: bool,
+    /// Number of shares originally dealt, if the shares used record it
+    pub share_count: Option<ShareCount>,
+    /// Number of words in the mnemonic, e.g. 12 or 24
+    pub word_count: usize,
+    /// Length of the recovered BIP39 entropy in bytes, e.g. 16 or 32
+    pub entropy_len: usize,
+}
+
+impl std::fmt::Debug for RecoveredMnemonic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecoveredMnemonic")
+            .field("mnemonic", &"<redacted>")
+            .field("fingerprint", &self.fingerprint)
+            .field("threshold", &self.threshold)
+            .field("used_indices", &self.used_indices)
+            .field("warnings", &self.warnings)
+            .field("passphrase_reminder", &self.passphrase_reminder)
+            .field("share_count", &self.share_count)
+            .field("word_count", &self.word_count)
+            .field("entropy_len", &self.entropy_len)
+            .finish()
+    }
+}
+
+impl RecoveredMnemonic {
+    /// How many of the shares originally dealt were not used to recover the mnemonic
+    ///
+    /// Returns `None` when the shares do not record their share count (see
+    /// [`SplitOptions::omit_share_count`]). Shares that are missing now may be lost for
+    /// good, so a non-zero count is a cue to re-split with [`rotate_shares`].
+    #[must_use]
+    pub fn missing_shares(&self) -> Option<usize> {
+        self.share_count
+            .map(|share_count| usize::from(*share_count).saturating_sub(self.used_indices.len()))
+    }
+
+    /// Describes the shares missing from the recovery, e.g. "recovered using 3 of 5
+    /// original shares; 2 shares are missing"
+    ///
+    /// Returns `None` when no share is missing or the count is unknown.
+    #[must_use]
+    pub fn missing_shares_message(&self) -> Option<String> {
+        let share_count = self.share_count?;
+        match self.missing_shares()? {
+            0 => None,
+            missing => Some(format!(
+                "recovered using {} of {share_count} original shares; {missing} {} missing",
+                self.used_indices.len(),
+                if missing == 1 {
+                    "share is"
+                } else {
+                    "shares are"
+                }
+            )),
+        }
+    }
+}
+
+/// A secret interpolated from shares, with the metadata of the shares that produced it
+struct RecoveredSecret {
+    secret: Zeroizing<Vec<u8>>,
+    threshold: Threshold,
+    used_indices: Vec<ShareIndex>,
+    passphrase_reminder: bool,
+    share_count: Option<ShareCount>,
+}
+
+impl RecoveredSecret {
+    /// Describes a secret recovered from `used` shares
+    fn new<'a>(
+        secret: Zeroizing<Vec<u8>>,
+        threshold: Threshold,
+        used: impl Iterator<Item = &'a ParsedShare> + Clone,
+    ) -> Self {
+        Self {
+            secret,
+            threshold,
+            used_indices: used.clone().map(|share| share.index).collect(),
+            passphrase_reminder: used.clone().any(|share| share.flags.passphrase_reminder),
+            share_count: used.filter_map(|share| share.share_count).max(),
+        }
+    }
+}
+
+/// A decoded share together with its 1-based position in the caller's input
+struct ParsedShare {
+    position: usize,
+    threshold: Threshold,
+    index: ShareIndex,
+    part: Option<SharePart>,
+    flags: ShareFlags,
+    share_count: Option<ShareCount>,
+    data: Zeroizing<Vec<u8>>,
+}
+
+/// Names a share that failed to parse by its 1-based input position
+///
+/// When the parameters are still readable (e.g. only the checksum failed), the decoded
+/// share index is included to help match the error to a physical share.
+fn describe_share(share_str: &str, position: usize, options: &CodecOptions) -> String {
+    match codec::parse_share_parameters_with_options(share_str, options) {
+        Ok((_, index)) => format!("Failed to parse share #{position} (index {index})"),
+        Err(_) => format!("Failed to parse share #{position}"),
+    }
+}
+
+/// A pile of shares, such as those gathered to recover a secret
+///
+/// Validation reads only the parameter words of each share, so it is cheap; the share
+/// data is checked when the set is combined. Pass [`ShareSet::as_slice`] to any function
+/// taking shares, e.g. [`combine_shares`].
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{ShareSet, combine_shares, split_mnemonic_typed};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+/// let config = SplitConfig::new(Threshold::new(3)?, ShareCount::new(5)?)?;
+///
+/// let mut dealt = split_mnemonic_typed(mnemonic, config)?.into_iter();
+///
+/// let mut shares: ShareSet = dealt.by_ref().take(2).collect();
+/// assert_eq!(*shares.threshold()?, 3);
+/// assert!(!shares.is_recoverable());
+///
+/// shares.extend(dealt);
+/// assert!(shares.is_recoverable());
+/// assert_eq!(*combine_shares(shares.as_slice())?, mnemonic);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShareSet(Vec<Shamir39Mnemonic>);
+
+impl ShareSet {
+    /// Creates an empty set
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a share to the set
+    pub fn push(&mut self, share: Shamir39Mnemonic) {
+        self.0.push(share);
+    }
+
+    /// Number of shares in the set, counting duplicates
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the set holds no shares
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The shares, in the order they were added
+    #[must_use]
+    pub fn as_slice(&self) -> &[Shamir39Mnemonic] {
+        &self.0
+    }
+
+    /// Iterates over the shares in the order they were added
+    pub fn iter(&self) -> std::slice::Iter<'_, Shamir39Mnemonic> {
+        self.0.iter()
+    }
+
+    /// The threshold shared by every member
+    ///
+    /// # Errors
+    /// Returns an error if the set is empty, a share cannot be parsed, or two shares
+    /// disagree on the threshold
+    pub fn threshold(&self) -> Result<Threshold> {
+        let mut threshold = None;
+        for (position, (share_threshold, _)) in self.parameters()?.into_iter().enumerate() {
+            let expected = *threshold.get_or_insert(share_threshold);
+            if share_threshold != expected {
                 bail!(
-                    "Share #{} has inconsistent threshold: expected {}, got {}",
-                    idx + 1,
-                    *t,
-                    *threshold
+                    "Share #{} has inconsistent threshold: expected {expected}, got {share_threshold}",
+                    position + 1
                 );
             }
-            _ => {}
         }
+        threshold.ok_or_else(|| anyhow!("No shares provided"))
+    }
+
+    /// The distinct share indices in the set, in ascending order
+    ///
+    /// # Errors
+    /// Returns an error if a share cannot be parsed
+    pub fn distinct_indices(&self) -> Result<Vec<ShareIndex>> {
+        let mut indices: Vec<ShareIndex> = self
+            .parameters()?
+            .into_iter()
+            .map(|(_, index)| index)
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        Ok(indices)
+    }
+
+    /// Whether the members agree on a threshold and hold at least that many distinct
+    /// indices
+    ///
+    /// This does not verify the share data, so combining can still fail on a share
+    /// whose data words are damaged.
+    #[must_use]
+    pub fn is_recoverable(&self) -> bool {
+        match (self.threshold(), self.distinct_indices()) {
+            (Ok(threshold), Ok(indices)) => indices.len() >= usize::from(*threshold),
+            _ => false,
+        }
+    }
+
+    /// Threshold and index of every member, in order
+    fn parameters(&self) -> Result<Vec<(Threshold, ShareIndex)>> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(idx, share)| {
+                codec::parse_share_parameters(share.as_str())
+                    .with_context(|| format!("Failed to parse share #{}", idx + 1))
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<Shamir39Mnemonic> for ShareSet {
+    fn from_iter<I: IntoIterator<Item = Shamir39Mnemonic>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Shamir39Mnemonic> for ShareSet {
+    fn extend<I: IntoIterator<Item = Shamir39Mnemonic>>(&mut self, iter: I) {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for ShareSet {
+    type Item = Shamir39Mnemonic;
+    type IntoIter = std::vec::IntoIter<Shamir39Mnemonic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ShareSet {
+    type Item = &'a Shamir39Mnemonic;
+    type IntoIter = std::slice::Iter<'a, Shamir39Mnemonic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl AsRef<[Shamir39Mnemonic]> for ShareSet {
+    fn as_ref(&self) -> &[Shamir39Mnemonic] {
+        &self.0
+    }
+}
+
+/// Accumulates shares one at a time, rejecting any that could not be combined with the
+/// shares accepted so far
+///
+/// Accepted shares are kept as [`Shamir39Mnemonic`]s, so they are zeroized on drop and
+/// `Debug` shows only their threshold and index.
+#[derive(Debug, Default)]
+pub struct ShareCollector {
+    options: CodecOptions,
+    threshold: Option<Threshold>,
+    shares: Vec<Shamir39Mnemonic>,
+    indices: HashMap<ShareIndex, usize>,
+}
+
+impl ShareCollector {
+    /// Creates an empty collector
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty collector that parses shares with custom [`CodecOptions`]
+    #[must_use]
+    pub fn with_options(options: CodecOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Validates `share` and accepts it, returning its decoded index
+    ///
+    /// A word marked as unknown with [`codec::UNKNOWN_WORD`] is filled in first, and
+    /// the completed share is the one kept.
+    ///
+    /// # Errors
+    /// Returns an error if the share does not parse, disagrees with the threshold of the
+    /// shares accepted so far, or repeats an index already accepted
+    pub fn add(&mut self, share: &str) -> Result<ShareIndex> {
+        let share = share.trim();
+        let resolved = codec::resolve_unknown_word(share, &self.options)?;
+        let text = resolved.as_deref().map_or(share, String::as_str);
+        let (threshold, index, _data) = codec::parse_share_with_options(text, &self.options)?;
+
+        if let Some(expected) = self.threshold
+            && threshold != expected
+        {
+            bail!(
+                "threshold {threshold} disagrees with threshold {expected} of the shares entered so far"
+            );
+        }
+
+        if let Some(first) = self.indices.get(&index) {
+            bail!("index {index} was already entered as share #{first}");
+        }
+
+        self.threshold = Some(threshold);
+        self.shares.push(match resolved {
+            Some(resolved) => Shamir39Mnemonic::from_zeroizing(resolved),
+            None => Shamir39Mnemonic::new_unchecked(share.to_string()),
+        });
+        self.indices.insert(index, self.shares.len());
+
+        Ok(index)
+    }
+
+    /// Threshold shared by the accepted shares, once known
+    #[must_use]
+    pub fn threshold(&self) -> Option<Threshold> {
+        self.threshold
+    }
+
+    /// Number of additional shares needed, once the threshold is known
+    #[must_use]
+    pub fn remaining(&self) -> Option<usize> {
+        self.threshold
+            .map(|threshold| usize::from(*threshold).saturating_sub(self.shares.len()))
+    }
+
+    /// The accepted shares, in entry order
+    #[must_use]
+    pub fn shares(&self) -> &[Shamir39Mnemonic] {
+        &self.shares
+    }
+}
+
+/// Combine Shamir Secret Shares to reconstruct the original mnemonic
+///
+/// Shares may be given as any string type, including [`Shamir39Mnemonic`]. Each may
+/// have one unreadable word replaced by `?`, see [`codec::resolve_unknown_word`].
+/// Returns the reconstructed BIP39 mnemonic wrapped in `Zeroizing` for automatic memory cleanup.
+/// Given more shares than the threshold, every share must lie on the recovered
+/// polynomial, so a share of another split is caught even when its checksum passes.
+///
+/// # Errors
+/// Returns an error if share decoding fails, share combination fails, a spare share
+/// disagrees with the others, or mnemonic reconstruction fails
+pub fn combine_shares<S: AsRef<str>>(share_strings: &[S]) -> Result<Zeroizing<String>> {
+    let entropy = combine_to_entropy(share_strings)?;
+    entropy_to_mnemonic(&entropy, Language::English)
+}
+
+/// Combine Shamir Secret Shares into the raw secret bytes, without forming a mnemonic
+///
+/// Use this when the secret was not a BIP39 mnemonic, e.g. bytes of a length
+/// `Mnemonic::from_entropy` does not accept (anything but 16, 20, 24, 28 or 32 bytes).
+///
+/// # Errors
+/// Returns an error if share decoding or share combination fails
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{combine_to_entropy, split_mnemonic};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+/// let config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+/// let shares = split_mnemonic(mnemonic, config)?;
+///
+/// let entropy = combine_to_entropy(&shares[1..])?;
+/// assert_eq!(entropy.len(), 16);
+/// # Ok(())
+/// # }
+/// ```
+pub fn combine_to_entropy<S: AsRef<str>>(share_strings: &[S]) -> Result<Zeroizing<Vec<u8>>> {
+    combine_to_entropy_with_options(share_strings, &CombineOptions::default())
+        .map(|(entropy, _warnings)| entropy)
+}
+
+/// Combine Shamir Secret Shares into the raw secret bytes using custom
+/// [`CombineOptions`]
+///
+/// Returns the secret along with any non-fatal warnings, as in
+/// [`RecoveredMnemonic::warnings`].
+///
+/// # Errors
+/// Returns an error under the same conditions as [`combine_shares_with_options`],
+/// except that the secret need not be valid mnemonic entropy
+pub fn combine_to_entropy_with_options<S: AsRef<str>>(
+    share_strings: &[S],
+    options: &CombineOptions,
+) -> Result<(Zeroizing<Vec<u8>>, Vec<String>)> {
+    let (recovered, warnings) = recover_entropy(share_strings, *options)?;
+    Ok((recovered.secret, warnings))
+}
+
+/// Combine Shamir Secret Shares using custom [`CombineOptions`]
+///
+/// # Errors
+/// Returns an error under the same conditions as [`combine_shares`]. In lenient mode,
+/// threshold disagreements are only an error when no clear majority exists or too few
+/// consistent shares remain. In robust mode, unparseable shares are skipped, and an
+/// error is returned only if no threshold-sized subset is confirmed by a spare share.
+pub fn combine_shares_with_options<S: AsRef<str>>(
+    share_strings: &[S],
+    options: &CombineOptions,
+) -> Result<RecoveredMnemonic> {
+    let (recovered, warnings) = recover_entropy(share_strings, *options)?;
+    let mnemonic = entropy_to_mnemonic(&recovered.secret, options.language)?;
+
+    Ok(RecoveredMnemonic {
+        mnemonic,
+        fingerprint: entropy_fingerprint(&recovered.secret),
+        threshold: recovered.threshold,
+        used_indices: recovered.used_indices,
+        warnings,
+        passphrase_reminder: recovered.passphrase_reminder,
+        share_count: recovered.share_count,
+        // Every 4 bytes of entropy gain a checksum bit, and 33 bits make 3 words
+        word_count: recovered.secret.len() * 3 / 4,
+        entropy_len: recovered.secret.len(),
+    })
+}
+
+/// Formats recovered entropy as a mnemonic in `language`
+fn entropy_to_mnemonic(entropy: &[u8], language: Language) -> Result<Zeroizing<String>> {
+    // The intermediate `Mnemonic` zeroizes itself on drop
+    Mnemonic::from_entropy_in(language, entropy)
+        .map(|mnemonic| mnemonic_to_zeroizing_string(&mnemonic))
+        .with_context(|| {
+            format!(
+                "Failed to create mnemonic from {} bytes of recovered entropy; if the secret \
+                 is not a BIP39 mnemonic, recover its raw bytes instead",
+                entropy.len()
+            )
+        })
+}
+
+/// Non-secret description of a share set, meant to be kept alongside the shares
+///
+/// Holds the scheme and a fingerprint of the secret (see [`entropy_fingerprint`]), so
+/// that a later recovery can confirm it reproduced the same secret. No share data or
+/// secret is recorded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareManifest {
+    /// Scheme as `M-of-N`, e.g. `3-of-5`
+    pub scheme: String,
+    /// Number of shares dealt (N)
+    pub share_count: u8,
+    /// Minimum shares required (M)
+    pub threshold: u8,
+    /// When the shares were dealt, in seconds since the Unix epoch
+    pub created_at: u64,
+    /// Payload layout version of the shares (see [`codec::ShareMeta::format_version`])
+    pub format_version: u8,
+    /// Fingerprint of the secret
+    pub fingerprint: String,
+}
+
+impl ShareManifest {
+    /// Checks that a recovered mnemonic came from the share set this manifest describes
+    ///
+    /// # Errors
+    /// Returns an error if the threshold or fingerprint differ from the recorded ones, or
+    /// a share's index lies outside the recorded share count
+    pub fn verify(&self, recovered: &RecoveredMnemonic) -> Result<()> {
+        if *recovered.threshold != self.threshold {
+            bail!(
+                "Shares have threshold {}, but the manifest records a {} scheme",
+                recovered.threshold,
+                self.scheme
+            );
+        }
+
+        if let Some(index) = recovered
+            .used_indices
+            .iter()
+            .find(|&&index| *index >= self.share_count)
+        {
+            bail!(
+                "A share has index {index}, but the manifest records only {} shares (indices 0-{})",
+                self.share_count,
+                self.share_count.saturating_sub(1)
+            );
+        }
+
+        if recovered.fingerprint != self.fingerprint {
+            bail!(
+                "Recovered secret has fingerprint {}, but the manifest records {}: the shares \
+                 do not belong to the share set the manifest describes",
+                recovered.fingerprint,
+                self.fingerprint
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Describes the shares [`split_mnemonic_with_options`] dealt from `mnemonic_str`
+///
+/// `created_at` is a time in seconds since the Unix epoch, usually the current time.
+///
+/// # Errors
+/// Returns an error if the mnemonic cannot be parsed or `shares` is empty or cannot be
+/// decoded
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{SplitOptions, share_manifest, split_mnemonic};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+/// let config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+/// let shares = split_mnemonic(mnemonic, config)?;
+///
+/// let manifest = share_manifest(mnemonic, &shares, config, &SplitOptions::default(), 0)?;
+/// assert_eq!(manifest.scheme, "2-of-3");
+/// assert_eq!(manifest.format_version, 7);
+/// # Ok(())
+/// # }
+/// ```
+pub fn share_manifest<S: AsRef<str>>(
+    mnemonic_str: &str,
+    shares: &[S],
+    config: SplitConfig,
+    options: &SplitOptions,
+    created_at: u64,
+) -> Result<ShareManifest> {
+    let normalized = normalize_mnemonic_input(mnemonic_str);
+    let mnemonic = Mnemonic::parse_in(options.language, normalized.as_str())
+        .map_err(|e| describe_mnemonic_error(&normalized, options.language, &e))
+        .context("Failed to parse input mnemonic")?;
+    let entropy = Zeroizing::new(mnemonic.to_entropy());
+
+    let Some(first) = shares.first() else {
+        bail!("No shares provided");
+    };
+    let codec_options = CodecOptions {
+        language: options.language,
+        ..CodecOptions::default()
+    };
+    let meta = codec::share_metadata_with_options(first.as_ref(), &codec_options)?;
+
+    let (threshold, share_count) = (config.threshold(), config.share_count());
+    Ok(ShareManifest {
+        scheme: format!("{threshold}-of-{share_count}"),
+        share_count: *share_count,
+        threshold: *threshold,
+        created_at,
+        format_version: meta.format_version,
+        fingerprint: entropy_fingerprint(&entropy),
+    })
+}
+
+/// Validates and combines shares into the raw secret entropy
+///
+/// Returns the entropy along with any warnings raised along the way.
+fn recover_entropy<S: AsRef<str>>(
+    share_strings: &[S],
+    options: CombineOptions,
+) -> Result<(RecoveredSecret, Vec<String>)> {
+    if share_strings.is_empty() {
+        bail!("No shares provided");
+    }
+
+    let mut warnings = Vec::new();
+    let decoded_shares = decode_shares(share_strings, options, &mut warnings)?;
+
+    if decoded_shares.is_empty() {
+        bail!("None of the {} shares could be parsed", share_strings.len());
+    }
+
+    if let Some(share) = decoded_shares.iter().find(|share| share.part.is_some()) {
+        bail!(
+            "Share #{} holds {} of a multipart secret; combine it with combine_bytes_multipart",
+            share.position,
+            share
+                .part
+                .unwrap_or_else(|| unreachable!("found by part.is_some()"))
+        );
+    }
+
+    let decoded_shares = dedup_shares(decoded_shares, options.dedup)?;
+    let recovered = recover_secret(decoded_shares, options, &mut warnings)?;
+    Ok((recovered, warnings))
+}
+
+/// Keeps one copy of each share index according to `policy`
+///
+/// Under [`DedupPolicy::Error`] the shares are returned unchanged, and repeated indices
+/// are rejected when the shares are checked against each other.
+fn dedup_shares(shares: Vec<ParsedShare>, policy: DedupPolicy) -> Result<Vec<ParsedShare>> {
+    if policy == DedupPolicy::Error {
+        return Ok(shares);
+    }
+
+    let mut kept: Vec<ParsedShare> = Vec::with_capacity(shares.len());
+    let mut slots: HashMap<ShareIndex, usize> = HashMap::new();
+    for share in shares {
+        let Some(&slot) = slots.get(&share.index) else {
+            slots.insert(share.index, kept.len());
+            kept.push(share);
+            continue;
+        };
+
+        let copy = &kept[slot];
+        if copy.threshold != share.threshold || copy.data != share.data {
+            bail!(
+                "Share #{} has the same index ({}) as share #{} but different contents: \
+                 one of them is corrupt or belongs to another share set",
+                share.position,
+                share.index,
+                copy.position
+            );
+        }
+        debug!(
+            "share #{} duplicates share #{}",
+            share.position, copy.position
+        );
+        if policy == DedupPolicy::Last {
+            kept[slot] = share;
+        }
+    }
+
+    Ok(kept)
+}
+
+/// Checks that decoded shares agree with each other and interpolates their secret
+fn recover_secret(
+    mut decoded_shares: Vec<ParsedShare>,
+    options: CombineOptions,
+    warnings: &mut Vec<String>,
+) -> Result<RecoveredSecret> {
+    let threshold = if options.lenient {
+        let threshold = majority_threshold(&decoded_shares)?;
+        decoded_shares.retain(|share| {
+            if share.threshold == threshold {
+                return true;
+            }
+            warnings.push(format!(
+                "Ignoring share #{}: threshold {} disagrees with majority threshold {threshold}",
+                share.position, share.threshold
+            ));
+            false
+        });
+        threshold
+    } else {
+        // Validate threshold consistency
+        let threshold = decoded_shares[0].threshold;
+        if let Some(share) = decoded_shares
+            .iter()
+            .find(|share| share.threshold != threshold)
+        {
+            bail!(
+                "Share #{} has inconsistent threshold: expected {threshold}, got {}",
+                share.position,
+                share.threshold
+            );
+        }
+        threshold
+    };
+
+    if let Some(expected) = options.expect_threshold
+        && threshold != expected
+    {
+        bail!(
+            "Shares have threshold {threshold}, but threshold {expected} was expected: \
+             they may belong to a different scheme"
+        );
+    }
+
+    check_distinct_indices(&decoded_shares)?;
+    if options.robust {
+        drop_bad_share_data(&mut decoded_shares, warnings);
+    } else {
+        check_share_data(&decoded_shares)?;
+    }
+    warn_index_mismatches(&decoded_shares, warnings);
+
+    // Convert to blahaj Shares
+    let parsed_shares = decoded_shares
+        .iter()
+        .map(|share| {
+            blahaj::Share::try_from(share.data.as_slice())
+                .map_err(|e| anyhow!("Failed to create share from data: {e:?}"))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Check if we have enough shares
+    let threshold_val = *threshold;
+    if parsed_shares.len() < threshold_val as usize {
+        bail!(
+            "Insufficient shares: need at least {}, but only {} provided",
+            threshold_val,
+            parsed_shares.len()
+        );
+    }
+
+    // Combine shares using blahaj
+    info!(
+        "recovering with {} shares (threshold {threshold}{})",
+        parsed_shares.len(),
+        if options.robust { ", robust" } else { "" }
+    );
+    let sharks = Sharks(threshold_val);
+    if options.robust && parsed_shares.len() > threshold_val as usize {
+        let (secret, inconsistent) = recover_verified(&sharks, &parsed_shares)?;
+        for &idx in &inconsistent {
+            warnings.push(format!(
+                "Ignoring share #{}: it is inconsistent with the shares that agree on the secret",
+                decoded_shares[idx].position
+            ));
+        }
+
+        let used = decoded_shares
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| !inconsistent.contains(idx))
+            .map(|(_, share)| share);
+        if options.paranoid_verify {
+            verify_regenerates_shares("Paranoid verification", &secret, threshold, used.clone())?;
+        }
+        return Ok(RecoveredSecret::new(secret, threshold, used));
+    }
+
+    // Robust mode skips mismatched shares itself, so only require equal lengths here
+    check_share_lengths(&decoded_shares)?;
+
+    let secret = Zeroizing::new(
+        sharks
+            .recover(&parsed_shares)
+            .map_err(|e| anyhow!("Failed to recover secret (unexpected error: {e})"))?,
+    );
+    verify_recovered(&secret, threshold, &decoded_shares, options)?;
+
+    Ok(RecoveredSecret::new(
+        secret,
+        threshold,
+        decoded_shares.iter(),
+    ))
+}
+
+/// Runs the checks of a recovered secret against the shares it came from: the
+/// `paranoid_verify` check if requested, or else the spare share check whenever more
+/// shares than the threshold are present
+fn verify_recovered(
+    secret: &[u8],
+    threshold: Threshold,
+    shares: &[ParsedShare],
+    options: CombineOptions,
+) -> Result<()> {
+    if options.paranoid_verify {
+        verify_regenerates_shares("Paranoid verification", secret, threshold, shares.iter())
+    } else if shares.len() > usize::from(*threshold) {
+        // Spare shares, parity or not, are check symbols: every one must lie on the
+        // polynomial the others define
+        verify_regenerates_shares("Spare share check", secret, threshold, shares.iter())
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that `secret` regenerates each of `shares`, see
+/// [`CombineOptions::paranoid_verify`] and [`split_with_parity`]
+///
+/// The polynomial through the secret (at x = 0) and threshold-1 of the other shares is
+/// evaluated at each share's x-coordinate and compared with its y-values. Errors are
+/// prefixed with `check`, naming the check that failed.
+fn verify_regenerates_shares<'a>(
+    check: &str,
+    secret: &[u8],
+    threshold: Threshold,
+    shares: impl Iterator<Item = &'a ParsedShare>,
+) -> Result<()> {
+    let shares: Vec<&ParsedShare> = shares.collect();
+    if let Some(share) = shares
+        .iter()
+        .find(|share| share.data.len() != secret.len() + 1)
+    {
+        bail!(
+            "{check} failed: share #{} holds {} bytes of share data, but the recovered \
+             secret needs {}",
+            share.position,
+            share.data.len(),
+            secret.len() + 1
+        );
+    }
+    for (target_idx, target) in shares.iter().enumerate() {
+        let basis: Vec<&ParsedShare> = shares
+            .iter()
+            .enumerate()
+            .filter(|&(idx, _)| idx != target_idx)
+            .map(|(_, &share)| share)
+            .take(usize::from(*threshold) - 1)
+            .collect();
+        let mut xs = vec![0u8];
+        xs.extend(basis.iter().map(|share| share.data[0]));
+
+        let regenerated = (0..secret.len()).map(|byte| {
+            let mut ys = vec![secret[byte]];
+            ys.extend(basis.iter().map(|share| share.data[1 + byte]));
+            gf256_interpolate_at(&xs, &ys, target.data[0])
+        });
+        if !regenerated.eq(target.data[1..].iter().copied()) {
+            bail!(
+                "{check} failed: the recovered secret does not regenerate share #{} (index \
+                 {}); the shares or the Shamir arithmetic are inconsistent",
+                target.position,
+                target.index
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Multiplies in GF(256) modulo x^8 + x^4 + x^3 + x^2 + 1 (0x11d), the field blahaj uses,
+/// without branching on either operand
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (0x1d & carry);
+        b >>= 1;
+    }
+    product
+}
+
+/// Inverts a nonzero element of GF(256), as `a^254`
+fn gf256_inv(a: u8) -> u8 {
+    let mut result = 1;
+    let mut power = a;
+    let mut exponent = 254u8;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = gf256_mul(result, power);
+        }
+        power = gf256_mul(power, power);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Evaluates at `x` the polynomial through the points `(xs[i], ys[i])`, whose
+/// x-coordinates must be distinct
+fn gf256_interpolate_at(xs: &[u8], ys: &[u8], x: u8) -> u8 {
+    let mut value = 0;
+    for (i, (&xi, &yi)) in xs.iter().zip(ys).enumerate() {
+        // Lagrange basis polynomial for point i at x; subtraction is XOR
+        let mut numerator = 1;
+        let mut denominator = 1;
+        for (j, &xj) in xs.iter().enumerate() {
+            if i != j {
+                numerator = gf256_mul(numerator, x ^ xj);
+                denominator = gf256_mul(denominator, xi ^ xj);
+            }
+        }
+        value ^= gf256_mul(yi, gf256_mul(numerator, gf256_inv(denominator)));
+    }
+    value
+}
+
+/// Rejects distinct shares that claim the same index
+fn check_distinct_indices(shares: &[ParsedShare]) -> Result<()> {
+    let mut seen_indices: HashMap<ShareIndex, usize> = HashMap::new();
+    for share in shares {
+        if let Some(first) = seen_indices.insert(share.index, share.position) {
+            bail!(
+                "Share #{} has the same index ({}) as share #{first}",
+                share.position,
+                share.index
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Rejects share data that blahaj would fail on with an unhelpful error
+///
+/// Each share's data must hold a Sharks x-coordinate and at least one y-value, and
+/// distinct shares must carry distinct x-coordinates; colliding x-coordinates under
+/// different indices mean the data does not match its embedded index.
+fn check_share_data(shares: &[ParsedShare]) -> Result<()> {
+    let mut seen_x: HashMap<u8, usize> = HashMap::new();
+    for share in shares {
+        if let Some(problem) = share_data_problem(share, &mut seen_x) {
+            bail!("{problem}");
+        }
+    }
+    Ok(())
+}
+
+/// Drops the shares [`check_share_data`] would reject, with a warning for each, so
+/// robust mode can recover from the rest
+///
+/// Of two shares with the same x-coordinate, the first is kept; if it is the corrupt
+/// one, robust recovery reports it as inconsistent.
+fn drop_bad_share_data(shares: &mut Vec<ParsedShare>, warnings: &mut Vec<String>) {
+    let mut seen_x: HashMap<u8, usize> = HashMap::new();
+    shares.retain(|share| {
+        let Some(problem) = share_data_problem(share, &mut seen_x) else {
+            return true;
+        };
+        warnings.push(format!("Ignoring share #{}: {problem}", share.position));
+        false
+    });
+}
+
+/// Why `share` cannot be combined, if it cannot: too little data, or an x-coordinate
+/// already taken by one of the shares recorded in `seen_x`, which it is then added to
+fn share_data_problem(share: &ParsedShare, seen_x: &mut HashMap<u8, usize>) -> Option<String> {
+    if share.data.len() < 2 {
+        return Some(format!(
+            "Share #{} holds only {} byte(s) of share data, too few for any secret: it is \
+             truncated or was not created by shameless",
+            share.position,
+            share.data.len()
+        ));
+    }
+
+    if let Some(&first) = seen_x.get(&share.data[0]) {
+        return Some(format!(
+            "Share #{} and share #{first} carry the same Sharks x-coordinate despite \
+             different indices: one of them is corrupt or was not created by shameless",
+            share.position
+        ));
+    }
+    seen_x.insert(share.data[0], share.position);
+    None
+}
+
+/// Warns about shares whose data does not start with the x-coordinate of their index
+///
+/// blahaj recovers from the x-coordinates in the data, not from the embedded index, so
+/// a mismatch does not change the result by itself. It does mean the index printed on
+/// the share is wrong (e.g. it was re-encoded by hand), or the data is corrupt in a way
+/// the checksum missed.
+fn warn_index_mismatches(shares: &[ParsedShare], warnings: &mut Vec<String>) {
+    for share in shares {
+        let x = share.data[0];
+        if x == share.index.x_coordinate() {
+            continue;
+        }
+
+        let owner = match x.checked_sub(1) {
+            Some(index) => format!("which belongs to index {index}"),
+            None => "which no share may have".to_string(),
+        };
+        warnings.push(format!(
+            "Share #{} is labelled index {} but its data has x-coordinate {x}, {owner}: the \
+             label or the data is wrong",
+            share.position, share.index
+        ));
+    }
+}
+
+/// Rejects shares whose data lengths differ, which no single secret can produce
+fn check_share_lengths(shares: &[ParsedShare]) -> Result<()> {
+    let first = &shares[0];
+    if let Some(share) = shares
+        .iter()
+        .find(|share| share.data.len() != first.data.len())
+    {
+        bail!(
+            "Shares have inconsistent lengths — they may come from different secrets: share #{} \
+             holds {} bytes of share data, but share #{} holds {}",
+            first.position,
+            first.data.len(),
+            share.position,
+            share.data.len()
+        );
+    }
+    Ok(())
+}
+
+/// Parses a share with `options`, retrying with the other [`codec::Padding`]
+///
+/// Shares written by tools that align their data words differently fail their checksum
+/// under the wrong alignment, so trying both is safe. Returns the alignment that
+/// worked; a share failing both keeps the error of the first attempt.
+fn parse_either_padding(
+    share: &str,
+    options: &CodecOptions,
+) -> Result<(DecodedShare, codec::Padding)> {
+    codec::parse_share_part(share, options)
+        .map(|parsed| (parsed, options.padding))
+        .or_else(|e| {
+            let other = CodecOptions {
+                padding: options.padding.other(),
+                ..*options
+            };
+            codec::parse_share_part(share, &other)
+                .map(|parsed| (parsed, other.padding))
+                .map_err(|_| e)
+        })
+}
+
+/// Parses every share, rejecting exact duplicates unless `options.dedup` keeps one copy
+///
+/// Words are read in `options.language`. When `options.robust` is set, shares that
+/// fail to parse are skipped with a warning instead of failing the whole batch.
+fn decode_shares<S: AsRef<str>>(
+    share_strings: &[S],
+    options: CombineOptions,
+    warnings: &mut Vec<String>,
+) -> Result<Vec<ParsedShare>> {
+    let codec_options = CodecOptions {
+        language: options.language,
+        ..CodecOptions::default()
+    };
+    let mut decoded_shares = Vec::with_capacity(share_strings.len());
+    let mut seen_shares: HashMap<Shamir39Mnemonic, usize> = HashMap::new();
+    let mut unchecked_shares = 0;
+
+    for (idx, share_str) in share_strings.iter().map(AsRef::as_ref).enumerate() {
+        // Cheaply reject exact duplicates before decoding, unless copies are deduplicated
+        let canonical = Shamir39Mnemonic::canonicalize_unchecked(share_str);
+        if let Some(first) = seen_shares.insert(canonical, idx)
+            && options.dedup == DedupPolicy::Error
+        {
+            bail!(
+                "Share #{} is an exact duplicate of share #{}",
+                idx + 1,
+                first + 1
+            );
+        }
+
+        // Parse shamir39 mnemonic, filling in a word the user marked as unreadable
+        let parsed = codec::resolve_unknown_word(share_str, &codec_options)
+            .and_then(|resolved| {
+                if resolved.is_some() {
+                    warnings.push(format!(
+                        "Share #{}: its unknown word was recovered from the share's checksum",
+                        idx + 1
+                    ));
+                }
+                let text = resolved.as_deref().map_or(share_str, String::as_str);
+                parse_either_padding(text, &codec_options).map(|(parsed, padding)| {
+                    if padding != codec_options.padding {
+                        warnings.push(format!(
+                            "Share #{} has {padding}-padded data words, unlike shameless \
+                             shares; it was probably written by another tool",
+                            idx + 1
+                        ));
+                    }
+                    parsed
+                })
+            })
+            .with_context(|| describe_share(share_str, idx + 1, &codec_options));
+        let DecodedShare {
+            threshold,
+            index,
+            part,
+            flags,
+            share_count,
+            data,
+        } = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) if options.robust => {
+                warnings.push(format!("Ignoring share #{}: {e:#}", idx + 1));
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        debug!(
+            "parsed share #{} index={index} threshold={threshold} len={} bytes",
+            idx + 1,
+            data.len()
+        );
+
+        if ShareEncoding::detect(share_str) == ShareEncoding::StrictSpec {
+            unchecked_shares += 1;
+        }
+
+        decoded_shares.push(ParsedShare {
+            position: idx + 1,
+            threshold,
+            index,
+            part,
+            flags,
+            share_count,
+            data,
+        });
+    }
+
+    if unchecked_shares > 0 {
+        warnings.push(format!(
+            "{unchecked_shares} share(s) use the strict shamir39 layout, which has no checksum; \
+             only the recovered mnemonic's BIP39 checksum guards against transcription errors"
+        ));
+    }
+
+    Ok(decoded_shares)
+}
+
+/// Upper bound on the subsets robust recovery tries before giving up
+const ROBUST_MAX_SUBSETS: usize = 10_000;
+
+/// Recovers a secret from a threshold-sized subset confirmed by a spare share
+///
+/// Any `threshold` shares interpolate to *some* secret, so a subset's result is only
+/// trusted once a share outside it agrees: swapping the subset's first member for a
+/// spare must reproduce the same secret, which means `threshold + 1` shares lie on one
+/// polynomial. Shares are not given a fingerprint of the secret to check against, as
+/// that would let a single share confirm guesses of the secret.
+///
+/// Returns the secret and the positions in `candidates` of spares that disagree with it.
+fn recover_verified(
+    sharks: &Sharks,
+    candidates: &[blahaj::Share],
+) -> Result<(Zeroizing<Vec<u8>>, Vec<usize>)> {
+    let recover = |positions: &[usize]| {
+        sharks
+            .recover(positions.iter().map(|&pos| &candidates[pos]))
+            .ok()
+            .map(Zeroizing::new)
+    };
+
+    let threshold = usize::from(sharks.0);
+    for subset in Combinations::new(candidates.len(), threshold).take(ROBUST_MAX_SUBSETS) {
+        let Some(secret) = recover(&subset) else {
+            continue;
+        };
+
+        let mut swapped = subset.clone();
+        let (agreeing, inconsistent): (Vec<usize>, Vec<usize>) = (0..candidates.len())
+            .filter(|pos| !subset.contains(pos))
+            .partition(|&spare| {
+                swapped[0] = spare;
+                recover(&swapped).is_some_and(|other| *other == *secret)
+            });
+
+        if !agreeing.is_empty() {
+            return Ok((secret, inconsistent));
+        }
+    }
+
+    bail!(
+        "No quorum of {threshold} mutually consistent shares found among {} shares",
+        candidates.len()
+    );
+}
+
+/// Re-split the secret behind `old_shares` with a new configuration
+///
+/// The old shares are combined into entropy that only ever lives in `Zeroizing` memory
+/// and is immediately re-dealt, so the intermediate mnemonic is never rendered. The
+/// entropy is zeroized before this function returns.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`combine_shares`], or if the new
+/// shares cannot be created
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{combine_shares, rotate_shares, split_mnemonic};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+/// let old_config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+/// let old_shares = split_mnemonic(mnemonic, old_config)?;
+///
+/// // 2-of-3 becomes 3-of-5
+/// let new_config = SplitConfig::new(Threshold::new(3)?, ShareCount::new(5)?)?;
+/// let new_shares = rotate_shares(&old_shares[..2], new_config)?;
+///
+/// assert_eq!(new_shares.len(), 5);
+/// assert_eq!(*combine_shares(&new_shares[2..])?, mnemonic);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rotate_shares<S: AsRef<str>>(
+    old_shares: &[S],
+    new_config: SplitConfig,
+) -> Result<Vec<String>> {
+    rotate_shares_with_options(old_shares, new_config, &SplitOptions::default())
+}
+
+/// Re-split the secret behind `old_shares` using custom [`SplitOptions`]
+///
+/// The old shares are read in `options.language`, and the new shares are written in
+/// it with the other options applied.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`rotate_shares`]
+pub fn rotate_shares_with_options<S: AsRef<str>>(
+    old_shares: &[S],
+    new_config: SplitConfig,
+    options: &SplitOptions,
+) -> Result<Vec<String>> {
+    let combine_options = CombineOptions {
+        language: options.language,
+        ..CombineOptions::default()
+    };
+    let (recovered, _warnings) = recover_entropy(old_shares, combine_options)?;
+
+    // Refuse to re-split anything that isn't valid mnemonic entropy
+    Mnemonic::from_entropy(&recovered.secret)
+        .context("Failed to create mnemonic from recovered entropy")?;
+
+    // The new shares must not lose the reminder that the wallet needs a passphrase
+    let options = SplitOptions {
+        passphrase_reminder: options.passphrase_reminder || recovered.passphrase_reminder,
+        ..*options
+    };
+    deal_shares(&recovered.secret, new_config, options, None).map(|shares| into_strings(&shares))
+}
+
+/// Re-deal the secret behind `old_shares` with the same threshold and share count
+///
+/// This is proactive refreshing: the new shares have the same indices and threshold as
+/// the old ones, but are points on a fresh random polynomial, so old shares stop being
+/// useful once destroyed. Shares leaked before the refresh can't be pooled with shares
+/// leaked after it. The share count is the one recorded in the old shares, as
+/// [`split_mnemonic`] does by default; use [`rotate_shares`] for shares that don't
+/// record it.
+///
+/// **Old and new shares must never be mixed.** A quorum drawn from both sets
+/// interpolates a different polynomial and recovers a wrong secret, which a 12- or
+/// 24-word mnemonic can't flag on its own. Robust combining (see
+/// [`CombineOptions::robust`]) only accepts a secret that a spare share confirms, and a
+/// manifest of the set (see [`ShareManifest::verify`]) rejects the wrong secret.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`rotate_shares`], or if the old
+/// shares do not record how many shares were dealt
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{combine_shares, refresh_shares, split_mnemonic};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+/// let config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+/// let old_shares = split_mnemonic(mnemonic, config)?;
+///
+/// let new_shares = refresh_shares(&old_shares[1..])?;
+/// assert_eq!(new_shares.len(), 3);
+/// assert_ne!(new_shares[0], old_shares[0]);
+/// assert_eq!(*combine_shares(&new_shares[..2])?, mnemonic);
+/// # Ok(())
+/// # }
+/// ```
+pub fn refresh_shares<S: AsRef<str>>(old_shares: &[S]) -> Result<Vec<String>> {
+    refresh_shares_with_options(old_shares, &SplitOptions::default())
+}
+
+/// Re-deal the secret behind `old_shares` using custom [`SplitOptions`]
+///
+/// # Errors
+/// Returns an error under the same conditions as [`refresh_shares`]
+pub fn refresh_shares_with_options<S: AsRef<str>>(
+    old_shares: &[S],
+    options: &SplitOptions,
+) -> Result<Vec<String>> {
+    let combine_options = CombineOptions {
+        language: options.language,
+        ..CombineOptions::default()
+    };
+    let (recovered, _warnings) = recover_entropy(old_shares, combine_options)?;
+
+    Mnemonic::from_entropy(&recovered.secret)
+        .context("Failed to create mnemonic from recovered entropy")?;
+
+    let Some(share_count) = recovered.share_count else {
+        bail!(
+            "The shares do not record how many were dealt, so they cannot be refreshed as the \
+             same set; rotate them with an explicit share count instead"
+        );
+    };
+    let config = SplitConfig::new(recovered.threshold, share_count)?;
+
+    let options = SplitOptions {
+        passphrase_reminder: options.passphrase_reminder || recovered.passphrase_reminder,
+        ..*options
+    };
+    deal_shares(&recovered.secret, config, options, None).map(|shares| into_strings(&shares))
+}
+
+/// Size of the chunks a multipart secret is divided into
+pub const MULTIPART_CHUNK_LEN: usize = 512;
+
+/// Split an arbitrary secret into multipart shares
+///
+/// The secret is divided into chunks of [`MULTIPART_CHUNK_LEN`] bytes (the last may be
+/// shorter), and each chunk is split separately with the same `config`. Every share
+/// records its `(part index, part count)` so [`combine_bytes_multipart`] can group
+/// them again.
+///
+/// Returns one list per share holder, containing that holder's share of each part in
+/// part order. A holder must hand over all of their shares when combining.
+///
+/// Any non-empty secret is accepted, but secrets shorter than
+/// [`RECOMMENDED_MIN_SECRET_LEN`] can be brute-forced without any shares.
+///
+/// # Errors
+/// Returns an error if the secret is empty or needs more than `u16::MAX` parts, the
+/// encoding cannot record part tags ([`ShareEncoding::StrictSpec`]), or share
+/// creation fails
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{SplitOptions, combine_bytes_multipart, split_bytes_multipart};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let secret = vec![0x5A; 1500];
+/// let config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+///
+/// let holders = split_bytes_multipart(&secret, config, &SplitOptions::default())?;
+/// assert_eq!(holders.len(), 3);
+/// assert_eq!(holders[0].len(), 3); // 1500 bytes in 512-byte parts
+///
+/// let shares: Vec<&String> = holders[0].iter().chain(&holders[2]).collect();
+/// assert_eq!(*combine_bytes_multipart(&shares)?, secret);
+/// # Ok(())
+/// # }
+/// ```
+pub fn split_bytes_multipart(
+    secret: &[u8],
+    config: SplitConfig,
+    options: &SplitOptions,
+) -> Result<Vec<Vec<String>>> {
+    if secret.is_empty() {
+        bail!("Cannot split an empty secret");
+    }
+
+    let chunks = secret.chunks(MULTIPART_CHUNK_LEN);
+    let part_count = u16::try_from(chunks.len()).map_err(|_| {
+        anyhow!(
+            "Secret too large: {} bytes needs more than {} parts of {MULTIPART_CHUNK_LEN} bytes",
+            secret.len(),
+            u16::MAX
+        )
+    })?;
+
+    let mut holders =
+        vec![Vec::with_capacity(usize::from(part_count)); usize::from(*config.share_count())];
+    for (part_index, chunk) in (0..part_count).zip(chunks) {
+        let part = SharePart::new(part_index, part_count)?;
+        let shares = into_strings(&deal_shares(chunk, config, *options, Some(part))?);
+        for (holder, share) in holders.iter_mut().zip(shares) {
+            holder.push(share);
+        }
+    }
+
+    Ok(holders)
+}
+
+/// Reassemble a secret split with [`split_bytes_multipart`]
+///
+/// Shares may be given in any order and encoding. They are grouped by part, each part
+/// is recovered from its own shares, and the parts are concatenated in order.
+///
+/// # Errors
+/// Returns an error if no shares are given, a share fails to parse or is not part of a
+/// multipart secret, the shares disagree on the number of parts, or any part cannot be
+/// recovered (e.g. it has fewer shares than its threshold)
+pub fn combine_bytes_multipart<S: AsRef<str>>(share_strings: &[S]) -> Result<Zeroizing<Vec<u8>>> {
+    combine_bytes_multipart_with_options(share_strings, &CombineOptions::default())
+}
+
+/// Reassemble a multipart secret, reading the shares' words in `options.language`
+///
+/// The lenient and robust options do not apply to multipart shares and are ignored.
+///
+/// # Errors
+/// Returns an error under the same conditions as [`combine_bytes_multipart`]
+pub fn combine_bytes_multipart_with_options<S: AsRef<str>>(
+    share_strings: &[S],
+    options: &CombineOptions,
+) -> Result<Zeroizing<Vec<u8>>> {
+    if share_strings.is_empty() {
+        bail!("No shares provided");
+    }
+
+    let decode_options = CombineOptions {
+        language: options.language,
+        ..CombineOptions::default()
+    };
+    let mut warnings = Vec::new();
+    let decoded_shares = decode_shares(share_strings, decode_options, &mut warnings)?;
+
+    let Some(part_count) = decoded_shares[0].part.map(SharePart::count) else {
+        bail!("Share #1 is not part of a multipart secret");
+    };
+
+    let mut parts: Vec<Vec<ParsedShare>> = (0..part_count).map(|_| Vec::new()).collect();
+    for share in decoded_shares {
+        let Some(part) = share.part else {
+            bail!(
+                "Share #{} is not part of a multipart secret",
+                share.position
+            );
+        };
+        if part.count() != part_count {
+            bail!(
+                "Share #{} belongs to a secret of {} parts, but share #1 to one of {part_count}",
+                share.position,
+                part.count()
+            );
+        }
+        parts[usize::from(part.index())].push(share);
+    }
+
+    let mut chunks = Vec::with_capacity(parts.len());
+    for (part_index, shares) in (0..part_count).zip(parts) {
+        let part = SharePart::new(part_index, part_count)?;
+        if shares.is_empty() {
+            bail!("No shares provided for {part}");
+        }
+
+        let recovered = recover_secret(shares, CombineOptions::default(), &mut warnings)
+            .with_context(|| format!("Failed to recover {part}"))?;
+        chunks.push(recovered.secret);
+    }
+
+    // Size the output up front so it never reallocates and leaves copies behind
+    let mut secret = Zeroizing::new(Vec::with_capacity(chunks.iter().map(|c| c.len()).sum()));
+    for chunk in &chunks {
+        secret.extend_from_slice(chunk);
+    }
+
+    Ok(secret)
+}
+
+/// Result of a split operation (for JSON serialization)
+///
+/// This is the document `wasm_split` returns; [`shares_from_json`] reads the shares
+/// back out of a saved copy. `Debug` shows how many shares there are, never their text.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitResult {
+    /// The generated shares as shamir39 mnemonics
+    pub shares: Vec<String>,
+    /// Number of shares generated
+    pub share_count: u8,
+    /// Threshold required to reconstruct
+    pub threshold: u8,
+}
+
+impl std::fmt::Debug for SplitResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SplitResult")
+            .field(
+                "shares",
+                &format_args!("<{} shares redacted>", self.shares.len()),
+            )
+            .field("share_count", &self.share_count)
+            .field("threshold", &self.threshold)
+            .finish()
+    }
+}
+
+/// Extracts the shares of a saved [`SplitResult`] JSON document
+///
+/// # Errors
+/// Returns an error if `json` is not a split result (e.g. it has no `shares` array) or
+/// lists no shares
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::shares_from_json;
+///
+/// let json = r#"{"shares": ["shameless a", "shameless b"], "share_count": 2, "threshold": 2}"#;
+/// assert_eq!(shares_from_json(json).unwrap(), ["shameless a", "shameless b"]);
+///
+/// let err = shares_from_json(r#"{"share_count": 2, "threshold": 2}"#).unwrap_err();
+/// assert!(format!("{err:#}").contains("missing field `shares`"));
+/// ```
+pub fn shares_from_json(json: &str) -> Result<Vec<String>> {
+    let result: SplitResult = serde_json::from_str(json).context(
+        "Input is not a JSON split result: expected an object like \
+         {\"shares\": [...], \"share_count\": N, \"threshold\": M}",
+    )?;
+    if result.shares.is_empty() {
+        bail!("The JSON split result lists no shares");
+    }
+    Ok(result.shares)
+}
+
+/// Prefix of a share bundle, see [`bundle_shares`]
+pub const BUNDLE_HEADER: &str = "SHAMELESS-BUNDLE:";
+
+/// Largest decompressed bundle [`unbundle_shares`] accepts (1 MiB)
+///
+/// Far more than 254 shares of any secret need, while bounding what a crafted bundle
+/// can make the decompressor allocate.
+const MAX_BUNDLE_LEN: u64 = 1024 * 1024;
+
+/// Packs shares into a single line, e.g. for one QR code on a master backup sheet
+///
+/// The bundle is [`BUNDLE_HEADER`] followed by URL-safe base64 (no padding) of the
+/// gzip-compressed, newline-separated shares. [`unbundle_shares`] reverses it.
+///
+/// **A bundle holding a quorum of shares is as sensitive as the secret itself:**
+/// anyone who reads it can recover the secret, which defeats the point of distributing
+/// the shares. Only keep one where you would keep the mnemonic.
+///
+/// # Errors
+/// Returns an error if there are no shares or one of them spans several lines
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{bundle_shares, split_mnemonic, unbundle_shares};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+/// let config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+/// let shares = split_mnemonic(mnemonic, config)?;
+///
+/// let bundle = bundle_shares(&shares)?;
+/// assert!(bundle.starts_with("SHAMELESS-BUNDLE:"));
+/// assert_eq!(unbundle_shares(&bundle)?, shares);
+/// # Ok(())
+/// # }
+/// ```
+pub fn bundle_shares<S: AsRef<str>>(shares: &[S]) -> Result<Zeroizing<String>> {
+    if shares.is_empty() {
+        bail!("No shares to bundle");
+    }
+
+    let mut joined = Zeroizing::new(String::new());
+    for (position, share) in shares.iter().map(AsRef::as_ref).enumerate() {
+        let share = share.trim();
+        if share.is_empty() || share.contains(['\n', '\r']) {
+            bail!(
+                "Share #{} cannot be bundled: it must be a single non-empty line",
+                position + 1
+            );
+        }
+        if position > 0 {
+            joined.push('\n');
+        }
+        joined.push_str(share);
+    }
+
+    // Leave room for the gzip framing, so the buffer never reallocates and leaves
+    // copies behind
+    let mut encoder = GzEncoder::new(Vec::with_capacity(joined.len() + 64), Compression::best());
+    encoder
+        .write_all(joined.as_bytes())
+        .context("Failed to compress shares")?;
+    let compressed = Zeroizing::new(encoder.finish().context("Failed to compress shares")?);
+
+    let mut bundle = Zeroizing::new(String::from(BUNDLE_HEADER));
+    URL_SAFE_NO_PAD.encode_string(compressed.as_slice(), &mut bundle);
+    Ok(bundle)
+}
+
+/// Unpacks the shares of a bundle created by [`bundle_shares`]
+///
+/// # Errors
+/// Returns an error if `bundle` lacks the [`BUNDLE_HEADER`], is not valid base64 or
+/// gzip, decompresses to more than 1 MiB or to invalid UTF-8, or holds no shares
+pub fn unbundle_shares(bundle: &str) -> Result<Vec<String>> {
+    let Some(encoded) = bundle.trim().strip_prefix(BUNDLE_HEADER) else {
+        bail!("Not a share bundle: expected it to start with {BUNDLE_HEADER}");
+    };
+    let compressed = Zeroizing::new(
+        URL_SAFE_NO_PAD
+            .decode(encoded)
+            .context("Share bundle is not valid base64")?,
+    );
+
+    let mut joined = Zeroizing::new(Vec::new());
+    GzDecoder::new(compressed.as_slice())
+        .take(MAX_BUNDLE_LEN + 1)
+        .read_to_end(&mut joined)
+        .context("Share bundle is corrupt: it could not be decompressed")?;
+    if joined.len() as u64 > MAX_BUNDLE_LEN {
+        bail!("Share bundle decompresses to more than {MAX_BUNDLE_LEN} bytes");
+    }
+
+    let joined = std::str::from_utf8(&joined).context("Share bundle does not hold text")?;
+    let shares: Vec<String> = joined.lines().map(str::to_string).collect();
+    if shares.is_empty() {
+        bail!("Share bundle holds no shares");
+    }
+    Ok(shares)
+}
+
+/// Replaces any share bundles among `inputs` by the shares they hold, keeping order
+///
+/// # Errors
+/// Returns an error if a bundle cannot be unpacked (see [`unbundle_shares`])
+pub fn expand_bundles<S: AsRef<str>>(inputs: &[S]) -> Result<Vec<String>> {
+    let mut shares = Vec::with_capacity(inputs.len());
+    for input in inputs.iter().map(AsRef::as_ref) {
+        if input.trim_start().starts_with(BUNDLE_HEADER) {
+            shares.extend(unbundle_shares(input)?);
+        } else {
+            shares.push(input.to_string());
+        }
+    }
+    Ok(shares)
+}
+
+/// Renders `text` as an inline SVG QR code
+///
+/// The QR version grows with the text automatically; if the text is too long even for
+/// the largest version at medium error correction, low error correction is used.
+///
+/// # Errors
+/// Returns an error if `text` does not fit in any QR code
+pub(crate) fn qr_svg(text: &str) -> Result<String> {
+    use qrcode::render::svg;
+    use qrcode::{EcLevel, QrCode};
+
+    let code = QrCode::with_error_correction_level(text, EcLevel::M)
+        .or_else(|_| QrCode::with_error_correction_level(text, EcLevel::L))
+        .map_err(|e| anyhow!("QR encoding failed: {e}"))?;
+
+    Ok(code
+        .render::<svg::Color<'_>>()
+        .min_dimensions(256, 256)
+        .build())
+}
+
+/// Stylesheet of the pages produced by [`render_paper_share`]
+const PAPER_CSS: &str = "\
+body { font-family: sans-serif; margin: 2em; color: #000; background: #fff; }
+h1 { font-size: 1.6em; margin-bottom: 0.2em; }
+.scheme { font-size: 1.2em; margin-top: 0; }
+.words { display: grid; grid-template-columns: repeat(4, 1fr); gap: 0.4em 1.2em; \
+font-family: monospace; font-size: 1.4em; margin: 1.5em 0; }
+.words span { white-space: nowrap; }
+.words b { display: inline-block; min-width: 2.2em; color: #555; font-weight: normal; }
+.text { font-family: monospace; font-size: 1.2em; word-break: break-all; margin: 1.5em 0; }
+.qr svg { width: 8cm; height: 8cm; }
+.note { border: 1px solid #000; padding: 0.5em; }
+@media print { body { margin: 1cm; } }
+";
+
+/// Escapes the characters HTML gives a meaning to
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Renders one share as a standalone, printable HTML page for cold storage
+///
+/// The page holds the share's words in a numbered grid, its exact text, a QR code of
+/// the exact share text, and a "Share N of M, threshold T" label.
+/// It needs no network access or external files, and names no other share, so each
+/// page can be printed and handed out on its own. Shares split without a recorded
+/// share count are labeled "Share N, threshold T".
+///
+/// # Errors
+/// Returns an error if the share is invalid or too long for a QR code
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{render_paper_share, split_mnemonic};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+/// let config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+/// let shares = split_mnemonic(mnemonic, config)?;
+///
+/// let page = render_paper_share(&shares[1])?;
+/// assert!(page.contains("Share 2 of 3, threshold 2"));
+/// assert!(!page.contains(&shares[0]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn render_paper_share(share: &str) -> Result<String> {
+    render_paper_share_with_options(share, &CodecOptions::default())
+}
+
+/// Like [`render_paper_share`], parsing the share with custom [`CodecOptions`]
+///
+/// # Errors
+/// Returns an error if the share is invalid or too long for a QR code
+pub fn render_paper_share_with_options(share: &str, options: &CodecOptions) -> Result<String> {
+    use std::fmt::Write as _;
+
+    let share = share.trim();
+    let meta = codec::share_metadata_with_options(share, options)?;
+    let ordinal = usize::from(*meta.index) + 1;
+    let label = match meta.share_count {
+        Some(count) => format!("Share {ordinal} of {count}, threshold {}", meta.threshold),
+        None => format!("Share {ordinal}, threshold {}", meta.threshold),
+    };
+
+    let words: Vec<&str> = share.split_whitespace().collect();
+    let mut body = String::new();
+    if words.len() > 1 {
+        body.push_str("<div class=\"words\">\n");
+        for (position, word) in words.iter().enumerate() {
+            writeln!(
+                body,
+                "<span><b>{}.</b>{}</span>",
+                position + 1,
+                escape_html(word)
+            )?;
+        }
+        body.push_str("</div>\n<p>As one line, for <code>shameless combine</code>:</p>\n");
+    }
+    write!(body, "<p class=\"text\">{}</p>", escape_html(share))?;
+
+    // The renderer emits a standalone SVG document; its XML declaration has no place
+    // inside HTML
+    let qr = qr_svg(share)?;
+    let qr = qr.find("<svg").map_or(qr.as_str(), |start| &qr[start..]);
+
+    let passphrase_note = if meta.flags.passphrase_reminder {
+        "<p class=\"note\">The wallet behind this share also uses a BIP39 passphrase. It is \
+         not stored in any share and must be kept separately.</p>\n"
+    } else {
+        ""
+    };
+
+    Ok(format!(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>shameless {label}</title>
+<style>
+{PAPER_CSS}</style>
+</head>
+<body>
+<h1>shameless share</h1>
+<p class=\"scheme\">{label} (index {index})</p>
+{body}
+<div class=\"qr\">{qr}</div>
+{passphrase_note}<p>Any {threshold} shares of this set recover the secret. Store this page \
+where only its custodian can read it, and never keep it together with other shares.</p>
+</body>
+</html>
+",
+        index = meta.index,
+        threshold = meta.threshold,
+    ))
+}
+
+/// Most bytes [`split_mnemonic_async`] reads as the mnemonic
+#[cfg(feature = "tokio")]
+const MAX_ASYNC_MNEMONIC_LEN: usize = 4096;
+
+/// Most bytes [`combine_shares_async`] reads as shares
+#[cfg(feature = "tokio")]
+const MAX_ASYNC_SHARES_LEN: usize = 256 * 1024;
+
+/// Reads all of `input` as UTF-8 text, refusing more than `max_len` bytes
+///
+/// The buffer is sized up front so it never reallocates, which would leave
+/// un-zeroized copies of the input behind.
+#[cfg(feature = "tokio")]
+async fn read_text_async<R>(input: R, max_len: usize) -> Result<Zeroizing<String>>
+where
+    R: tokio::io::AsyncRead + Unpin,
+{
+    use tokio::io::AsyncReadExt;
+
+    let mut bytes = Zeroizing::new(Vec::with_capacity(max_len + 1));
+    input
+        .take(max_len as u64 + 1)
+        .read_to_end(&mut bytes)
+        .await
+        .context("Failed to read input")?;
+    if bytes.len() > max_len {
+        bail!("Input is larger than the {max_len} bytes allowed");
+    }
+
+    let text = std::str::from_utf8(&bytes).context("Input is not valid UTF-8")?;
+    Ok(Zeroizing::new(text.to_string()))
+}
+
+/// Reads a mnemonic from `input`, splits it and writes the shares to `output`, one per
+/// line
+///
+/// The I/O is asynchronous; the Shamir dealing runs on Tokio's blocking thread pool, so
+/// this must be called from within a Tokio runtime. At most 4 KiB of input is read.
+///
+/// # Errors
+/// Returns an error if reading or writing fails, the input is not UTF-8, or under the
+/// same conditions as [`split_mnemonic_with_options`]
+#[cfg(feature = "tokio")]
+pub async fn split_mnemonic_async<R, W>(
+    input: R,
+    mut output: W,
+    config: SplitConfig,
+    options: &SplitOptions,
+) -> Result<()>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let mnemonic = read_text_async(input, MAX_ASYNC_MNEMONIC_LEN).await?;
+    let options = *options;
+    let shares = tokio::task::spawn_blocking(move || {
+        split_mnemonic_typed_with_options(&mnemonic, config, &options)
+    })
+    .await
+    .context("Share dealing task failed")??;
+
+    for share in &shares {
+        output
+            .write_all(share.as_str().as_bytes())
+            .await
+            .context("Failed to write share")?;
+        output
+            .write_all(b"\n")
+            .await
+            .context("Failed to write share")?;
+    }
+    output.flush().await.context("Failed to write share")?;
+    Ok(())
+}
+
+/// Reads shares from `input`, one per line, combines them and writes the mnemonic to
+/// `output`, followed by a newline
+///
+/// Blank lines are skipped and share bundles (see [`bundle_shares`]) are expanded. The
+/// I/O is asynchronous; combining runs on Tokio's blocking thread pool, so this must be
+/// called from within a Tokio runtime. At most 256 KiB of input is read. The full
+/// result is returned as well, for its warnings and share metadata.
+///
+/// # Errors
+/// Returns an error if reading or writing fails, the input is not UTF-8, or under the
+/// same conditions as [`combine_shares_with_options`]
+#[cfg(feature = "tokio")]
+pub async fn combine_shares_async<R, W>(
+    input: R,
+    mut output: W,
+    options: &CombineOptions,
+) -> Result<RecoveredMnemonic>
+where
+    R: tokio::io::AsyncRead + Unpin,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let text = read_text_async(input, MAX_ASYNC_SHARES_LEN).await?;
+    let lines: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    let shares = expand_bundles(&lines)?;
+    let options = *options;
+    let recovered =
+        tokio::task::spawn_blocking(move || combine_shares_with_options(&shares, &options))
+            .await
+            .context("Share combining task failed")??;
+
+    output
+        .write_all(recovered.mnemonic.as_bytes())
+        .await
+        .context("Failed to write mnemonic")?;
+    output
+        .write_all(b"\n")
+        .await
+        .context("Failed to write mnemonic")?;
+    output.flush().await.context("Failed to write mnemonic")?;
+    Ok(recovered)
+}
+
+/// Enumerates every minimal set of share indices that can reconstruct the secret
+///
+/// Only share metadata is used: the shares are parsed and checked for a common
+/// threshold and distinct indices, but never combined. The result yields each
+/// threshold-sized subset of the available indices in lexicographic order. There are
+/// `C(n, threshold)` of them, so it is an iterator rather than a `Vec`; use
+/// [`Iterator::take`] to bound the output for large share counts.
+///
+/// # Errors
+/// Returns an error if no shares are given, a share fails to parse, the thresholds
+/// disagree, or two shares have the same index
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{minimal_quorums, split_mnemonic};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = SplitConfig::new(Threshold::new(3)?, ShareCount::new(5)?)?;
+/// let shares = split_mnemonic(
+///     "army van defense carry jealous true garbage claim echo media make crunch",
+///     config,
+/// )?;
+///
+/// // Any 3 of the 5 share-holders can recover: C(5, 3) = 10 quorums
+/// let quorums: Vec<_> = minimal_quorums(&shares)?.collect();
+/// assert_eq!(quorums.len(), 10);
+/// assert!(quorums.iter().all(|quorum| quorum.len() == 3));
+/// # Ok(())
+/// # }
+/// ```
+pub fn minimal_quorums<S: AsRef<str>>(share_strings: &[S]) -> Result<Quorums> {
+    if share_strings.is_empty() {
+        bail!("No shares provided");
+    }
+
+    let mut threshold = None;
+    let mut indices: Vec<ShareIndex> = Vec::with_capacity(share_strings.len());
+    for (idx, share_str) in share_strings.iter().map(AsRef::as_ref).enumerate() {
+        let (share_threshold, index, _data) = codec::parse_share(share_str)
+            .with_context(|| describe_share(share_str, idx + 1, &CodecOptions::default()))?;
+
+        let expected = *threshold.get_or_insert(share_threshold);
+        if share_threshold != expected {
+            bail!(
+                "Share #{} has inconsistent threshold: expected {expected}, got {share_threshold}",
+                idx + 1
+            );
+        }
+
+        if let Some(first) = indices.iter().position(|&seen| seen == index) {
+            bail!(
+                "Share #{} has the same index ({index}) as share #{}",
+                idx + 1,
+                first + 1
+            );
+        }
+        indices.push(index);
+    }
+
+    indices.sort_unstable();
+    let size = threshold.map_or(0, |threshold| usize::from(*threshold));
+    Ok(Quorums::new(indices, size))
+}
+
+/// What kind of problem a [`HealthProblem`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProblemKind {
+    /// The share does not start with a known version word or prefix
+    BadVersion,
+    /// A word is not in the BIP39 wordlist
+    UnknownWord,
+    /// The parameters are readable, but the share data fails its checksum or layout checks
+    Checksum,
+    /// The share cannot be decoded at all
+    Malformed,
+    /// The same share was given more than once
+    DuplicateShare,
+    /// Two different shares claim the same index
+    DuplicateIndex,
+    /// The share's threshold disagrees with the other shares
+    ThresholdMismatch,
+    /// Too few usable shares to reconstruct
+    NoQuorum,
+}
+
+impl ProblemKind {
+    /// Every kind of problem, in declaration order
+    pub const ALL: [Self; 8] = [
+        Self::BadVersion,
+        Self::UnknownWord,
+        Self::Checksum,
+        Self::Malformed,
+        Self::DuplicateShare,
+        Self::DuplicateIndex,
+        Self::ThresholdMismatch,
+        Self::NoQuorum,
+    ];
+}
+
+/// One problem found by [`health_report`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthProblem {
+    /// 1-based position of the share in the input, `None` for problems of the whole set
+    pub position: Option<usize>,
+    /// Category of the problem
+    pub kind: ProblemKind,
+    /// Human-readable description, including suggestions where there are any
+    pub message: String,
+}
+
+/// Diagnosis of a set of shares, see [`health_report`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthReport {
+    /// Number of shares given
+    pub total: usize,
+    /// Shares that decoded and passed their checksum (strict shares, which have no
+    /// checksum, count once they decode)
+    pub passed_checksum: usize,
+    /// Distinct indices among the valid shares with the threshold below, ascending
+    pub distinct_indices: Vec<ShareIndex>,
+    /// Threshold held by most valid shares (`None` if no share is valid)
+    pub threshold: Option<Threshold>,
+    /// Whether every valid share has that threshold
+    pub threshold_agreed: bool,
+    /// Whether enough distinct valid shares are present to reconstruct
+    pub quorum: bool,
+    /// Number of shares originally dealt, as recorded by the valid shares (`None` if
+    /// none records it)
+    pub share_count: Option<ShareCount>,
+    /// Everything that is wrong with the shares, in input order
+    pub problems: Vec<HealthProblem>,
+}
+
+/// Diagnose a set of shares without reconstructing the secret
+///
+/// Every share is checked on its own (version word, wordlist, parameters and
+/// checksum), then the valid shares are checked against each other (duplicates,
+/// threshold agreement, quorum). Unlike [`combine_shares`], the report lists every
+/// problem found rather than stopping at the first. Share data is decoded only to
+/// verify checksums and is zeroized right after.
+///
+/// # Examples
+///
+/// ```rust
+/// use shameless::commands::{ProblemKind, health_report, split_mnemonic};
+/// use shameless::domain::{ShareCount, SplitConfig, Threshold};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = SplitConfig::new(Threshold::new(2)?, ShareCount::new(3)?)?;
+/// let shares = split_mnemonic(
+///     "army van defense carry jealous true garbage claim echo media make crunch",
+///     config,
+/// )?;
+///
+/// let report = health_report(&[shares[0].clone(), shares[0].clone()]);
+/// assert_eq!(report.passed_checksum, 1);
+/// assert!(!report.quorum);
+/// assert_eq!(report.problems[0].kind, ProblemKind::DuplicateShare);
+/// assert_eq!(report.problems[1].kind, ProblemKind::NoQuorum);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use]
+pub fn health_report<S: AsRef<str>>(share_strings: &[S]) -> HealthReport {
+    health_report_with_options(share_strings, &CodecOptions::default())
+}
+
+/// Diagnose a set of shares, reading their words with custom [`CodecOptions`]
+#[must_use]
+pub fn health_report_with_options<S: AsRef<str>>(
+    share_strings: &[S],
+    options: &CodecOptions,
+) -> HealthReport {
+    let mut problems = Vec::new();
+    let mut valid: Vec<(usize, Threshold, ShareIndex)> = Vec::new();
+    let mut seen_shares: HashMap<Shamir39Mnemonic, usize> = HashMap::new();
+    let mut share_count = None;
+
+    for (idx, share_str) in share_strings.iter().map(AsRef::as_ref).enumerate() {
+        let position = idx + 1;
+        let normalized = codec::normalize_share_text(share_str);
+        let share_str = normalized.as_str();
+        let mut problem = |kind, message| {
+            problems.push(HealthProblem {
+                position: Some(position),
+                kind,
+                message,
+            });
+        };
+
+        let canonical = Shamir39Mnemonic::canonicalize_unchecked(share_str);
+        if let Some(first) = seen_shares.insert(canonical, position) {
+            problem(
+                ProblemKind::DuplicateShare,
+                format!("share #{position} is an exact duplicate of share #{first}"),
+            );
+            continue;
+        }
+
+        let word_problems = check_share_words(share_str, options.language);
+        if !word_problems.is_empty() {
+            for (kind, message) in word_problems {
+                problem(kind, message);
+            }
+            continue;
+        }
+
+        match codec::parse_share_with_options(share_str, options) {
+            Ok((threshold, index, _data)) => {
+                valid.push((position, threshold, index));
+                share_count = share_count.max(recorded_share_count(share_str, options));
+            }
+            Err(e) => {
+                let (kind, message) = decode_problem(share_str, options, position, &e);
+                problem(kind, message);
+            }
+        }
+    }
+
+    let passed_checksum = valid.len();
+    let threshold = most_common_threshold(&valid);
+    let mut threshold_agreed = true;
+    let mut indices: HashMap<ShareIndex, usize> = HashMap::new();
+    for &(position, share_threshold, index) in &valid {
+        if Some(share_threshold) != threshold {
+            threshold_agreed = false;
+            problems.push(HealthProblem {
+                position: Some(position),
+                kind: ProblemKind::ThresholdMismatch,
+                message: format!(
+                    "share #{position} has threshold {share_threshold}, but most shares have {}",
+                    threshold.map_or(0, |threshold| *threshold)
+                ),
+            });
+            continue;
+        }
+
+        if let Some(first) = indices.insert(index, position) {
+            problems.push(HealthProblem {
+                position: Some(position),
+                kind: ProblemKind::DuplicateIndex,
+                message: format!(
+                    "share #{position} has the same index ({index}) as share #{first}, but \
+                     different data"
+                ),
+            });
+        }
+    }
+
+    let mut distinct_indices: Vec<ShareIndex> = indices.into_keys().collect();
+    distinct_indices.sort_unstable();
+
+    let need = threshold.map_or(usize::from(Threshold::MIN), |threshold| {
+        usize::from(*threshold)
+    });
+    let quorum = threshold.is_some() && distinct_indices.len() >= need;
+    if !quorum {
+        problems.push(HealthProblem {
+            position: None,
+            kind: ProblemKind::NoQuorum,
+            message: format!(
+                "{} usable share(s) with distinct indices, but {need} are needed",
+                distinct_indices.len()
+            ),
+        });
+    }
+
+    HealthReport {
+        total: share_strings.len(),
+        passed_checksum,
+        distinct_indices,
+        threshold,
+        threshold_agreed,
+        quorum,
+        share_count,
+        problems,
+    }
+}
+
+/// Number of shares dealt as recorded by a valid share, if it records it
+fn recorded_share_count(share_str: &str, options: &CodecOptions) -> Option<ShareCount> {
+    codec::share_metadata_with_options(share_str, options)
+        .ok()
+        .and_then(|meta| meta.share_count)
+}
+
+/// Checks the version word and wordlist membership of a word-encoded share
+///
+/// Compact shares have no words and are left to the full parse.
+fn check_share_words(share_str: &str, language: Language) -> Vec<(ProblemKind, String)> {
+    let encoding = ShareEncoding::detect(share_str);
+    if encoding == ShareEncoding::Base64 {
+        return Vec::new();
+    }
+
+    let mut words = share_str.split_whitespace();
+    let Some(version) = words.next() else {
+        return vec![(ProblemKind::Malformed, "the share is empty".to_string())];
+    };
+
+    let mut problems = Vec::new();
+    let expected_version = match encoding {
+        ShareEncoding::StrictSpec => codec::strict::STRICT_VERSION_WORD,
+        _ => codec::VERSION_WORD,
+    };
+    if !version.eq_ignore_ascii_case(expected_version) {
+        // An unsplit share would be one long "word": never echo its data into the report
+        let shown: String = version.chars().take(expected_version.len() + 4).collect();
+        let ellipsis = if shown.len() < version.len() {
+            "..."
+        } else {
+            ""
+        };
+        problems.push((
+            ProblemKind::BadVersion,
+            format!(
+                "the share starts with '{shown}{ellipsis}' instead of the version word '{}' \
+                 (or the '{}' prefix of a base64 share)",
+                codec::VERSION_WORD,
+                codec::compact::COMPACT_PREFIX
+            ),
+        ));
+    }
+
+    for (idx, word) in words.enumerate() {
+        if codec::word_to_index(word, language).is_ok() {
+            continue;
+        }
+
+        let suggestions = codec::suggest_words(word, language);
+        let hint = if suggestions.is_empty() {
+            String::new()
+        } else {
+            format!("; did you mean '{}'?", suggestions.join("', '"))
+        };
+        problems.push((
+            ProblemKind::UnknownWord,
+            format!(
+                "word {} ('{word}') is not in the BIP39 {language} wordlist{hint}",
+                idx + 2
+            ),
+        ));
+    }
+
+    problems
+}
+
+/// Classifies why a share whose words are all known failed to decode
+fn decode_problem(
+    share_str: &str,
+    options: &CodecOptions,
+    position: usize,
+    e: &anyhow::Error,
+) -> (ProblemKind, String) {
+    match codec::parse_share_parameters_with_options(share_str, options) {
+        Ok((_, index)) => (
+            ProblemKind::Checksum,
+            format!("share #{position} (index {index}) has corrupt share data: {e:#}"),
+        ),
+        Err(_) => (
+            ProblemKind::Malformed,
+            format!("share #{position} cannot be decoded: {e:#}"),
+        ),
+    }
+}
+
+/// The threshold held by the most shares, preferring the earliest on a tie
+fn most_common_threshold(shares: &[(usize, Threshold, ShareIndex)]) -> Option<Threshold> {
+    let mut counts: Vec<(Threshold, usize)> = Vec::new();
+    for &(_, threshold, _) in shares {
+        match counts.iter_mut().find(|(seen, _)| *seen == threshold) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((threshold, 1)),
+        }
+    }
+
+    // `max_by_key` keeps the last maximum, so scan in reverse to prefer the earliest
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|&(_, count)| count)
+        .map(|(threshold, _)| threshold)
+}
+
+/// Iterator over threshold-sized subsets of share indices, see [`minimal_quorums`]
+#[derive(Debug, Clone)]
+pub struct Quorums {
+    indices: Vec<ShareIndex>,
+    positions: Combinations,
+}
+
+impl Quorums {
+    fn new(indices: Vec<ShareIndex>, size: usize) -> Self {
+        let positions = Combinations::new(indices.len(), size);
+        Self { indices, positions }
+    }
+}
+
+impl Iterator for Quorums {
+    type Item = Vec<ShareIndex>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let positions = self.positions.next()?;
+        Some(positions.iter().map(|&pos| self.indices[pos]).collect())
+    }
+}
+
+/// Iterator over `k`-element subsets of `0..n` in lexicographic order
+#[derive(Debug, Clone)]
+struct Combinations {
+    n: usize,
+    /// The next subset, or `None` once exhausted
+    next: Option<Vec<usize>>,
+}
+
+impl Combinations {
+    fn new(n: usize, k: usize) -> Self {
+        let next = (k <= n).then(|| (0..k).collect());
+        Self { n, next }
+    }
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let positions = self.next.as_mut()?;
+        let current = positions.clone();
+
+        // Advance to the next combination: bump the rightmost position that has room,
+        // then reset everything after it to consecutive positions
+        let k = positions.len();
+        match (0..k).rev().find(|&i| positions[i] < self.n - k + i) {
+            Some(i) => {
+                positions[i] += 1;
+                for j in i + 1..k {
+                    positions[j] = positions[j - 1] + 1;
+                }
+            }
+            None => self.next = None,
+        }
+
+        Some(current)
+    }
+}
+
+/// Renders a mnemonic as space-separated words into a `Zeroizing` string
+///
+/// The buffer is sized up front so it never reallocates, which would leave
+/// un-zeroized copies of the words behind.
+fn mnemonic_to_zeroizing_string(mnemonic: &Mnemonic) -> Zeroizing<String> {
+    // Words of other wordlists run to dozens of UTF-8 bytes, so measure the real ones
+    let len = mnemonic.words().map(str::len).sum::<usize>() + mnemonic.word_count() - 1;
+    let mut rendered = Zeroizing::new(String::with_capacity(len));
+    for (idx, word) in mnemonic.words().enumerate() {
+        if idx > 0 {
+            rendered.push(' ');
+        }
+        rendered.push_str(word);
+    }
+    rendered
+}
+
+/// Finds the threshold shared by more than half of the shares
+fn majority_threshold(shares: &[ParsedShare]) -> Result<Threshold> {
+    let mut counts: HashMap<Threshold, usize> = HashMap::new();
+    for share in shares {
+        *counts.entry(share.threshold).or_default() += 1;
+    }
+
+    counts
+        .into_iter()
+        .find(|&(_, count)| count * 2 > shares.len())
+        .map(|(threshold, _)| threshold)
+        .ok_or_else(|| anyhow!("No clear majority threshold among {} shares", shares.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC_12: &str =
+        "army van defense carry jealous true garbage claim echo media make crunch";
+
+    #[test]
+    fn test_generate_mnemonic_from_entropy_round_trips() {
+        for words in [12, 15, 18, 21, 24] {
+            let entropy: Vec<u8> = (0..words * 4 / 3)
+                .map(|i| u8::try_from(i * 97 % 256).unwrap() ^ 0x5a)
+                .collect();
+            let mnemonic = generate_mnemonic_from_entropy(&entropy).unwrap();
+
+            assert_eq!(mnemonic.split_whitespace().count(), words);
+            assert_eq!(
+                Mnemonic::parse(mnemonic.as_str()).unwrap().to_entropy(),
+                entropy
+            );
+        }
+
+        let entropy = Mnemonic::parse(MNEMONIC_12).unwrap().to_entropy();
+        assert_eq!(
+            *generate_mnemonic_from_entropy(&entropy).unwrap(),
+            MNEMONIC_12
+        );
+    }
+
+    #[test]
+    fn test_generate_mnemonic_from_entropy_rejects_bad_input() {
+        for (entropy, expected) in [
+            (vec![7u8; 15], "got 15 bytes"),
+            (vec![0u8; 33], "got 33 bytes"),
+            (vec![0u8; 16], "every byte is 0x00"),
+            (vec![0xffu8; 32], "every byte is 0xff"),
+            ((0..16).collect(), "counting sequence"),
+            ((0..32).rev().collect(), "counting sequence"),
+            (b"3141526535897932".to_vec(), "between 0x31 and 0x39"),
+            (b"9f86d081884c7d65".to_vec(), "passed as text"),
+            (
+                [0xde, 0xad, 0xbe, 0xef].repeat(5),
+                "only 4 distinct byte values",
+            ),
+        ] {
+            let err = generate_mnemonic_from_entropy(&entropy).unwrap_err();
+            assert!(err.to_string().contains(expected), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_paranoid_verify_regenerates_shares() {
+        for a in 1..=u8::MAX {
+            assert_eq!(gf256_mul(a, gf256_inv(a)), 1, "{a}");
+        }
+
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        for robust in [false, true] {
+            let options = CombineOptions {
+                robust,
+                paranoid_verify: true,
+                ..CombineOptions::default()
+            };
+            let recovered = combine_shares_with_options(&shares, &options).unwrap();
+            assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        }
+
+        // A wrong secret does not regenerate the shares
+        let decoded =
+            decode_shares(&shares[..3], CombineOptions::default(), &mut Vec::new()).unwrap();
+        let threshold = Threshold::new(3).unwrap();
+        let entropy = Mnemonic::parse(MNEMONIC_12).unwrap().to_entropy();
+        verify_regenerates_shares("Check", &entropy, threshold, decoded.iter()).unwrap();
+        let mut wrong = entropy.clone();
+        wrong[0] ^= 1;
+        let err =
+            verify_regenerates_shares("Check", &wrong, threshold, decoded.iter()).unwrap_err();
+        assert!(
+            err.to_string().contains("does not regenerate share #1"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_split_stops_at_the_gf256_share_limit() {
+        ensure_shares_fit_field(usize::from(ShareCount::MAX)).unwrap();
+        let err = ensure_shares_fit_field(255).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cannot create 255 shares: GF256 supports at most 254 distinct shares"
+        );
+
+        let config = SplitConfig::new(
+            Threshold::new(2).unwrap(),
+            ShareCount::new(ShareCount::MAX).unwrap(),
+        )
+        .unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        assert_eq!(shares.len(), 254);
+        let (_, last_index) = codec::parse_share_parameters(shares.last().unwrap()).unwrap();
+        assert_eq!(*last_index, ShareIndex::MAX - 1);
+    }
+
+    #[test]
+    fn test_parity_shares_survive_losses_beyond_the_share_count() {
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let shares = split_with_parity(MNEMONIC_12, config, 2).unwrap();
+        assert_eq!(shares.len(), 7);
+        for (position, share) in shares.iter().enumerate() {
+            let meta = codec::share_metadata(share).unwrap();
+            assert_eq!(meta.flags.parity, position >= 5, "share {position}");
+            assert_eq!(meta.share_count, Some(ShareCount::new(7).unwrap()));
+        }
+
+        // A plain 3-of-5 split survives losing 2 shares; with parity, losing 4 (all but
+        // one original share and both parity shares) still recovers the secret
+        let survivors = [&shares[1], &shares[5], &shares[6]];
+        let recovered =
+            combine_shares_with_options(&survivors, &CombineOptions::default()).unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        assert_eq!(recovered.missing_shares(), Some(4));
+
+        // Spare shares are checked against the recovered polynomial: a parity share of
+        // another split of the same secret passes its checksum but not the spare share check
+        let other = split_with_parity(MNEMONIC_12, config, 2).unwrap();
+        let mixed = [&shares[0], &shares[1], &shares[2], &other[5]];
+        let err = combine_shares(&mixed).unwrap_err();
+        assert!(
+            err.to_string().starts_with("Spare share check failed"),
+            "{err}"
+        );
+        // Robust mode skips it, given another spare share to confirm the secret
+        let options = CombineOptions {
+            robust: true,
+            ..CombineOptions::default()
+        };
+        let mixed = [&shares[0], &shares[1], &shares[2], &shares[3], &other[5]];
+        let recovered = combine_shares_with_options(&mixed, &options).unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        assert_eq!(recovered.warnings.len(), 1);
+
+        let strict = SplitOptions {
+            encoding: ShareEncoding::StrictSpec,
+            parity_shares: 1,
+            ..SplitOptions::default()
+        };
+        assert!(split_mnemonic_with_options(MNEMONIC_12, config, &strict).is_err());
+        let err = split_with_parity(MNEMONIC_12, config, 250).unwrap_err();
+        assert!(
+            err.to_string().starts_with("cannot create 255 shares"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_spare_shares_of_a_plain_split_are_checked() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let other = split_mnemonic(MNEMONIC_12, config).unwrap();
+
+        let recovered = combine_shares(&shares).unwrap();
+        assert_eq!(*recovered, MNEMONIC_12);
+
+        // Share index 2 of another split passes its checksum, but is not on this
+        // split's polynomial
+        let mixed = [&shares[0], &shares[1], &other[2]];
+        let err = combine_shares(&mixed).unwrap_err();
+        assert!(
+            err.to_string().starts_with("Spare share check failed"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_split_mnemonic_invalid_input() {
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let result = split_mnemonic("invalid mnemonic words here", config);
+
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Failed to parse input mnemonic")
+        );
+    }
+
+    #[test]
+    fn test_split_mnemonic_reports_unknown_word() {
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let result = split_mnemonic(
+            "army van defense carry jealous true foo claim echo media make crunch",
+            config,
+        );
+
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(err.contains("word 7 ('foo') is not in the BIP39 English wordlist"));
+    }
+
+    #[test]
+    fn test_split_mnemonic_reports_invalid_checksum() {
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        // Valid words, but the last word's checksum bits don't match
+        let result = split_mnemonic(
+            "army van defense carry jealous true garbage claim echo media make army",
+            config,
+        );
+
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(err.contains("BIP39 checksum is invalid"));
+        assert!(!err.contains("not in the BIP39 English wordlist"));
+    }
+
+    #[test]
+    fn test_split_mnemonic_accepts_pasted_separators() {
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let pasted = "\u{FEFF}army\u{00A0}van defense\tcarry  jealous\ntrue\u{200B}garbage \
+                      claim echo\u{2003}media make crunch\u{00A0}";
+
+        let shares = split_mnemonic(pasted, config).unwrap();
+        assert_eq!(*combine_shares(&shares[..2]).unwrap(), MNEMONIC_12);
+    }
+
+    #[test]
+    fn test_split_mnemonic_position_matches_embedded_index() {
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(10).unwrap()).unwrap();
+
+        for encoding in [ShareEncoding::Words, ShareEncoding::Base64] {
+            let options = SplitOptions {
+                encoding,
+                ..SplitOptions::default()
+            };
+            let shares = split_mnemonic_with_options(MNEMONIC_12, config, &options).unwrap();
+            for (position, share) in shares.iter().enumerate() {
+                let (_, index, _) = codec::parse_share(share).unwrap();
+                assert_eq!(
+                    index,
+                    ShareIndex::new(u8::try_from(position).unwrap()).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_deal_shares_rejects_empty_secret() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+
+        let err = deal_shares(&[], config, SplitOptions::default(), None).unwrap_err();
+        assert!(err.to_string().contains("Cannot split an empty secret"));
+    }
+
+    #[test]
+    fn test_split_mnemonic_threshold_too_low() {
+        use crate::domain::Threshold;
+        // Threshold::new(1) will fail, so we test the constructor
+        let result = Threshold::new(1);
+
+        assert!(result.is_err());
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("Threshold must be at least 2"));
+    }
+
+    #[test]
+    fn test_split_mnemonic_threshold_zero() {
+        use crate::domain::Threshold;
+        // Threshold::new(0) will fail, so we test the constructor
+        let result = Threshold::new(0);
+
+        assert!(result.is_err());
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("Threshold must be at least 2"));
+    }
+
+    #[test]
+    fn test_split_mnemonic_12_word() {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let result = split_mnemonic(mnemonic_str, config);
+        assert!(result.is_ok());
+        let shares = result.unwrap();
+        assert_eq!(shares.len(), 3);
+    }
+
+    #[test]
+    fn test_split_mnemonic_24_word() {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str = "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold";
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let result = split_mnemonic(mnemonic_str, config);
+        assert!(result.is_ok());
+        let shares = result.unwrap();
+        assert_eq!(shares.len(), 5);
+    }
+
+    #[test]
+    fn test_full_split_and_combine_round_trip() {
+        use crate::domain::{ShareCount, Threshold};
+        // This tests the full flow through the command functions
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+
+        // Use the split_mnemonic function directly
+        let threshold = Threshold::new(2).unwrap();
+        let share_count = ShareCount::new(3).unwrap();
+        let config = SplitConfig::new(threshold, share_count).unwrap();
+
+        let share_strings = split_mnemonic(mnemonic_str, config).unwrap();
+        assert_eq!(share_strings.len(), 3);
+
+        // Take 2 shares (threshold is 2)
+        let selected_shares = vec![share_strings[0].clone(), share_strings[1].clone()];
+
+        // Use the combine_shares function directly
+        let recovered_mnemonic = combine_shares(&selected_shares).unwrap();
+
+        assert_eq!(mnemonic_str, *recovered_mnemonic);
+    }
+
+    #[test]
+    fn test_combine_base64_and_mixed_encodings() {
+        use crate::domain::ShareCount;
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let options = SplitOptions {
+            encoding: ShareEncoding::Base64,
+            ..SplitOptions::default()
+        };
+
+        let compact = split_mnemonic_with_options(mnemonic_str, config, &options).unwrap();
+        assert!(compact.iter().all(|share| share.starts_with("shameless:")));
+        let recovered = combine_shares(&compact[1..]).unwrap();
+        assert_eq!(mnemonic_str, *recovered);
+
+        // Encodings are auto-detected per share, so they can be mixed
+        let words = split_mnemonic(mnemonic_str, config).unwrap();
+        assert_ne!(words, compact);
+        let (_, index, data) = codec::parse_share(&compact[2]).unwrap();
+        let (_, _, words_data) = codec::parse_share(&words[2]).unwrap();
+        assert_eq!(*index, 2);
+        assert_eq!(data.len(), words_data.len());
+
+        // Re-encode shares of one split so a single set holds words, base64 and strict
+        let reencode = |share: &str, encode: fn(&[u8], Threshold, ShareIndex) -> String| {
+            let (threshold, index, data) = codec::parse_share(share).unwrap();
+            encode(&data, threshold, index)
+        };
+        let mixed = [
+            words[0].clone(),
+            reencode(&words[1], |data, threshold, index| {
+                codec::compact::create_share_compact(data, threshold, index).unwrap()
+            }),
+            reencode(&words[2], |data, threshold, index| {
+                codec::strict::create_share_strict(data, threshold, index)
+                    .unwrap()
+                    .to_string()
+            }),
+        ];
+        assert!(mixed[1].starts_with("shameless:"));
+        assert!(mixed[2].starts_with("shamir39-p1 "));
+        assert_eq!(mnemonic_str, *combine_shares(&mixed).unwrap());
+        assert_eq!(mnemonic_str, *combine_shares(&mixed[1..]).unwrap());
+    }
+
+    #[test]
+    fn test_strict_spec_shares_round_trip_with_warning() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let options = SplitOptions {
+            encoding: ShareEncoding::StrictSpec,
+            ..SplitOptions::default()
+        };
+
+        let shares = split_mnemonic_with_options(MNEMONIC_12, config, &options).unwrap();
+        assert!(shares.iter().all(|share| share.starts_with("shamir39-p1 ")));
+
+        let recovered =
+            combine_shares_with_options(&shares[2..], &CombineOptions::default()).unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        assert_eq!(recovered.warnings.len(), 1);
+        assert!(recovered.warnings[0].contains("3 share(s) use the strict shamir39 layout"));
+    }
+
+    #[test]
+    fn test_padded_and_unpadded_shares_round_trip() {
+        use crate::domain::ShareCount;
+        const MNEMONIC_24: &str = "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+
+        for encoding in [ShareEncoding::Words, ShareEncoding::Base64] {
+            let padded = SplitOptions {
+                encoding,
+                pad_to: Some(33),
+                ..SplitOptions::default()
+            };
+            let short = split_mnemonic_with_options(MNEMONIC_12, config, &padded).unwrap();
+            let long = split_mnemonic_with_options(MNEMONIC_24, config, &padded).unwrap();
+            let unpadded = split_mnemonic_with_options(
+                MNEMONIC_12,
+                config,
+                &SplitOptions {
+                    encoding,
+                    pad_to: None,
+                    ..SplitOptions::default()
+                },
+            )
+            .unwrap();
+
+            // 12- and 24-word backups are indistinguishable by share size
+            let size = |share: &str| match encoding {
+                ShareEncoding::Base64 => share.len(),
+                _ => share.split_whitespace().count(),
+            };
+            assert_eq!(size(&short[0]), size(&long[0]));
+            assert!(size(&unpadded[0]) < size(&short[0]));
+
+            assert_eq!(*combine_shares(&short[..2]).unwrap(), MNEMONIC_12);
+            assert_eq!(*combine_shares(&long[1..]).unwrap(), MNEMONIC_24);
+            assert_eq!(*combine_shares(&unpadded[..2]).unwrap(), MNEMONIC_12);
+
+            // The recovered size reflects the secret, not the padding
+            let options = CombineOptions::default();
+            let recovered = combine_shares_with_options(&short[..2], &options).unwrap();
+            assert_eq!((recovered.word_count, recovered.entropy_len), (12, 16));
+            let recovered = combine_shares_with_options(&long[1..], &options).unwrap();
+            assert_eq!((recovered.word_count, recovered.entropy_len), (24, 32));
+        }
+    }
+
+    #[test]
+    fn test_padding_target_must_fit_share_data() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let options = SplitOptions {
+            pad_to: Some(16),
+            ..SplitOptions::default()
+        };
+
+        let err = split_mnemonic_with_options(MNEMONIC_12, config, &options).unwrap_err();
+        assert!(err.to_string().contains("longer than the padding target"));
+    }
+
+    #[test]
+    fn test_multipart_round_trip_1kb_3_of_5() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let secret: Vec<u8> = (0..1024u16)
+            .map(|i| u8::try_from(i % 251).unwrap())
+            .collect();
+
+        let holders = split_bytes_multipart(&secret, config, &SplitOptions::default()).unwrap();
+        assert_eq!(holders.len(), 5);
+        assert!(holders.iter().all(|shares| shares.len() == 2));
+
+        // Any three holders, with their shares in any order
+        let mut shares: Vec<&String> = [&holders[4], &holders[1], &holders[3]]
+            .into_iter()
+            .flatten()
+            .collect();
+        shares.reverse();
+        assert_eq!(*combine_bytes_multipart(&shares).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_multipart_errors_when_a_part_lacks_quorum() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let holders = split_bytes_multipart(&[7; 1000], config, &SplitOptions::default()).unwrap();
+
+        // The second holder lost their share of part 2
+        let shares = [&holders[0][0], &holders[0][1], &holders[1][0]];
+        let err = format!("{:#}", combine_bytes_multipart(&shares).unwrap_err());
+        assert!(err.contains("Failed to recover part 2 of 2"));
+        assert!(err.contains("Insufficient shares"));
+
+        // Nobody handed over a share of part 2
+        let err = combine_bytes_multipart(&[&holders[0][0], &holders[1][0]]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("No shares provided for part 2 of 2")
+        );
+    }
+
+    #[test]
+    fn test_combine_shares_rejects_multipart_shares() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let holders = split_bytes_multipart(&[7; 16], config, &SplitOptions::default()).unwrap();
+
+        let err = combine_shares(&[&holders[0][0], &holders[1][0]]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("part 1 of 1 of a multipart secret")
+        );
+
+        let err =
+            combine_bytes_multipart(&split_mnemonic(MNEMONIC_12, config).unwrap()).unwrap_err();
+        assert!(err.to_string().contains("not part of a multipart secret"));
+    }
+
+    #[test]
+    fn test_mnemonic_to_zeroizing_string_does_not_reallocate() {
+        let mnemonic_str = "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold";
+        let mnemonic = Mnemonic::parse_in(Language::English, mnemonic_str).unwrap();
+
+        let rendered = mnemonic_to_zeroizing_string(&mnemonic);
+        assert_eq!(*rendered, mnemonic_str);
+        assert_eq!(rendered.capacity(), mnemonic_str.len());
+
+        // Japanese and Korean words take far more UTF-8 bytes than English ones
+        for language in [Language::Japanese, Language::Korean] {
+            let mnemonic = Mnemonic::from_entropy_in(language, &[0xA5; 32]).unwrap();
+            let rendered = mnemonic_to_zeroizing_string(&mnemonic);
+            let words: Vec<&str> = mnemonic.words().collect();
+            assert_eq!(*rendered, words.join(" "));
+            assert!(rendered.len() > 24 * 9, "{language:?}");
+            assert_eq!(rendered.capacity(), rendered.len(), "{language:?}");
+        }
+    }
+
+    #[test]
+    fn test_combine_shares_accepts_typed_mnemonics() {
+        use std::collections::HashSet;
+
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let typed: Vec<Shamir39Mnemonic> = split_mnemonic(MNEMONIC_12, config)
+            .unwrap()
+            .iter()
+            .map(|share| Shamir39Mnemonic::canonicalize_unchecked(share))
+            .collect();
+
+        assert_eq!(*combine_shares(&typed[1..]).unwrap(), MNEMONIC_12);
+
+        // Borrow<str> allows set lookups by plain string
+        let set: HashSet<Shamir39Mnemonic> = typed.iter().cloned().collect();
+        assert!(set.contains(typed[0].as_ref()));
+    }
+
+    #[test]
+    fn test_split_mnemonic_typed_keeps_order_and_encoding() {
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+
+        let shares = split_mnemonic_typed(MNEMONIC_12, config).unwrap();
+        for (position, share) in shares.iter().enumerate() {
+            let (_, index, _) = codec::parse_share(share.as_str()).unwrap();
+            assert_eq!(usize::from(*index), position);
+        }
+        assert_eq!(*combine_shares(&shares[..2]).unwrap(), MNEMONIC_12);
+
+        let options = SplitOptions {
+            encoding: ShareEncoding::Base64,
+            ..SplitOptions::default()
+        };
+        let compact = split_mnemonic_typed_with_options(MNEMONIC_12, config, &options).unwrap();
+        assert!(
+            compact[0]
+                .as_str()
+                .starts_with(codec::compact::COMPACT_PREFIX)
+        );
+        assert_eq!(*combine_shares(&compact[1..]).unwrap(), MNEMONIC_12);
+    }
+
+    #[test]
+    fn test_passphrase_reminder_survives_split_combine_and_rotate() {
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+
+        for encoding in [ShareEncoding::Words, ShareEncoding::Base64] {
+            let options = SplitOptions {
+                encoding,
+                passphrase_reminder: true,
+                ..SplitOptions::default()
+            };
+            let shares = split_mnemonic_with_options(MNEMONIC_12, config, &options).unwrap();
+            assert!(
+                codec::share_metadata(&shares[0])
+                    .unwrap()
+                    .flags
+                    .passphrase_reminder
+            );
+
+            let recovered =
+                combine_shares_with_options(&shares[1..], &CombineOptions::default()).unwrap();
+            assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+            assert!(recovered.passphrase_reminder);
+
+            // Rotated shares keep the reminder without being asked to
+            let rotated = rotate_shares(&shares[..2], config).unwrap();
+            let recovered =
+                combine_shares_with_options(&rotated[..2], &CombineOptions::default()).unwrap();
+            assert!(recovered.passphrase_reminder);
+        }
+
+        let plain = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let recovered = combine_shares_with_options(&plain, &CombineOptions::default()).unwrap();
+        assert!(!recovered.passphrase_reminder);
+
+        let strict = SplitOptions {
+            encoding: ShareEncoding::StrictSpec,
+            passphrase_reminder: true,
+            ..SplitOptions::default()
+        };
+        assert!(split_mnemonic_with_options(MNEMONIC_12, config, &strict).is_err());
+    }
+
+    #[test]
+    fn test_split_mnemonic_insufficient_shares() {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+
+        // Create shares with threshold 3
+        let threshold = Threshold::new(3).unwrap();
+        let share_count = ShareCount::new(5).unwrap();
+        let config = SplitConfig::new(threshold, share_count).unwrap();
+
+        let share_strings = split_mnemonic(mnemonic_str, config).unwrap();
+        assert_eq!(share_strings.len(), 5);
+
+        // Try to combine with only 2 shares (threshold is 3)
+        let insufficient_shares = vec![share_strings[0].clone(), share_strings[1].clone()];
+        let result = combine_shares(&insufficient_shares);
+
+        // Should error with insufficient shares
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Insufficient shares")
+        );
+    }
+
+    #[test]
+    fn test_combine_shares_empty_input() {
+        let empty_shares: Vec<String> = vec![];
+        let result = combine_shares(&empty_shares);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_combine_shares_invalid_shamir39() {
+        // Invalid version word
+        let invalid_shares = vec!["invalid word word word".to_string()];
+        let result = combine_shares(&invalid_shares);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_combine_shares_reports_index_of_corrupt_share() {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let mut shares = split_mnemonic(mnemonic_str, config).unwrap();
+
+        // Swap the last data word so only the checksum fails
+        let mut words: Vec<&str> = shares[2].split(' ').collect();
+        let last = words.len() - 1;
+        words[last] = if words[last] == "abandon" {
+            "ability"
+        } else {
+            "abandon"
+        };
+        shares[2] = words.join(" ");
+
+        let err = format!("{:#}", combine_shares(&shares[1..]).unwrap_err());
+        assert!(err.contains("Failed to parse share #2 (index 2): Checksum verification failed"));
+    }
+
+    #[test]
+    fn test_combine_shares_unreadable_share_reports_position_only() {
+        let shares = vec!["shameless notaword abandon".to_string()];
+        let err = format!("{:#}", combine_shares(&shares).unwrap_err());
+        assert!(err.starts_with("Failed to parse share #1: "));
+    }
+
+    #[test]
+    fn test_rotate_shares_changes_parameters() {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let old_config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let old_shares = split_mnemonic(mnemonic_str, old_config).unwrap();
+
+        let new_config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let new_shares = rotate_shares(&old_shares[1..], new_config).unwrap();
+        assert_eq!(new_shares.len(), 5);
+
+        for share in &new_shares {
+            let (threshold, _, _) = codec::parse_share(share).unwrap();
+            assert_eq!(*threshold, 3);
+        }
+
+        let recovered = combine_shares(&new_shares[..3]).unwrap();
+        assert_eq!(mnemonic_str, *recovered);
+        assert!(combine_shares(&new_shares[..2]).is_err());
+    }
+
+    #[test]
+    fn test_rotate_shares_requires_a_quorum() {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let old_shares = split_mnemonic(mnemonic_str, config).unwrap();
+
+        let err = rotate_shares(&old_shares[..1], config)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Insufficient shares"));
+    }
+
+    #[test]
+    fn test_minimal_quorums_enumerates_subsets_in_order() {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(4).unwrap()).unwrap();
+        let shares = split_mnemonic(mnemonic_str, config).unwrap();
+
+        // Input order doesn't matter; indices are reported sorted
+        let subset = vec![shares[3].clone(), shares[0].clone(), shares[2].clone()];
+        let quorums: Vec<Vec<u8>> = minimal_quorums(&subset)
+            .unwrap()
+            .map(|quorum| quorum.into_iter().map(|index| *index).collect())
+            .collect();
+        assert_eq!(quorums, vec![vec![0, 2], vec![0, 3], vec![2, 3]]);
+
+        // Too few shares: no quorum exists
+        assert_eq!(minimal_quorums(&shares[..1]).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_minimal_quorums_is_lazy_for_large_share_counts() {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(10).unwrap(), ShareCount::new(40).unwrap()).unwrap();
+        let shares = split_mnemonic(mnemonic_str, config).unwrap();
+
+        // C(40, 10) is ~847 million; only the requested prefix is generated
+        let first: Vec<_> = minimal_quorums(&shares).unwrap().take(2).collect();
+        assert_eq!(first.len(), 2);
+        assert_eq!(*first[1][9], 10);
+    }
+
+    #[test]
+    fn test_minimal_quorums_rejects_inconsistent_threshold() {
+        let shares = shares_with_threshold_outlier();
+        let err = minimal_quorums(&shares).unwrap_err().to_string();
+        assert!(err.contains("Share #3 has inconsistent threshold"));
+    }
+
+    /// Splits the test mnemonic into 3 shares with threshold 2, then builds a fourth share
+    /// carrying the same data as share #3 but claiming threshold 3
+    fn shares_with_threshold_outlier() -> Vec<String> {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let mut shares = split_mnemonic(mnemonic_str, config).unwrap();
+
+        let (_, index, data) = codec::parse_share(&shares[2]).unwrap();
+        shares[2] = codec::create_share(&data, Threshold::new(3).unwrap(), index)
+            .unwrap()
+            .to_string();
+        shares
+    }
+
+    #[test]
+    fn test_combine_reports_used_indices_and_threshold() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+
+        let supplied = [&shares[4], &shares[0], &shares[2]];
+        let recovered = combine_shares_with_options(&supplied, &CombineOptions::default()).unwrap();
+
+        let expected: Vec<ShareIndex> = supplied
+            .iter()
+            .map(|share| codec::parse_share_parameters(share).unwrap().1)
+            .collect();
+        assert_eq!(recovered.used_indices, expected);
+        assert_eq!(
+            recovered
+                .used_indices
+                .iter()
+                .map(|i| **i)
+                .collect::<Vec<_>>(),
+            [4, 0, 2]
+        );
+        assert_eq!(*recovered.threshold, 3);
+    }
+
+    #[test]
+    fn test_combine_shares_strict_rejects_threshold_outlier() {
+        let shares = shares_with_threshold_outlier();
+        let err = combine_shares(&shares).unwrap_err().to_string();
+        assert!(err.contains("Share #3 has inconsistent threshold: expected 2, got 3"));
+    }
+
+    #[test]
+    fn test_combine_shares_lenient_uses_majority_threshold() {
+        let shares = shares_with_threshold_outlier();
+        let options = CombineOptions {
+            lenient: true,
+            ..CombineOptions::default()
+        };
+
+        let recovered = combine_shares_with_options(&shares, &options).unwrap();
+        assert_eq!(
+            *recovered.mnemonic,
+            "army van defense carry jealous true garbage claim echo media make crunch"
+        );
+        assert_eq!(recovered.warnings.len(), 1);
+        assert!(recovered.warnings[0].contains("Ignoring share #3"));
+    }
+
+    #[test]
+    fn test_combine_shares_lenient_requires_clear_majority() {
+        let shares = shares_with_threshold_outlier();
+        let options = CombineOptions {
+            lenient: true,
+            ..CombineOptions::default()
+        };
+
+        // One share per threshold: no majority
+        let err = combine_shares_with_options(&shares[1..], &options)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("No clear majority threshold"));
+    }
+
+    #[test]
+    fn test_combine_shares_rejects_unexpected_threshold() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+
+        let options = CombineOptions {
+            expect_threshold: Some(Threshold::new(3).unwrap()),
+            ..CombineOptions::default()
+        };
+        let err = combine_shares_with_options(&shares, &options)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Shares have threshold 2, but threshold 3 was expected"));
+
+        let options = CombineOptions {
+            expect_threshold: Some(Threshold::new(2).unwrap()),
+            ..CombineOptions::default()
+        };
+        let recovered = combine_shares_with_options(&shares, &options).unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+    }
+
+    #[test]
+    fn test_share_manifest_round_trips_and_verifies() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let options = SplitOptions::default();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let manifest =
+            share_manifest(MNEMONIC_12, &shares, config, &options, 1_700_000_000).unwrap();
+
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert!(!json.contains(shares[0].as_str()));
+        let parsed: ShareManifest = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, manifest);
+        assert_eq!(
+            (parsed.scheme.as_str(), parsed.share_count, parsed.threshold),
+            ("2-of-3", 3, 2)
+        );
+        assert_eq!(parsed.created_at, 1_700_000_000);
+
+        let recovered =
+            combine_shares_with_options(&shares[1..], &CombineOptions::default()).unwrap();
+        parsed.verify(&recovered).unwrap();
+    }
+
+    #[test]
+    fn test_share_manifest_flags_other_share_set() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let manifest =
+            share_manifest(MNEMONIC_12, &shares, config, &SplitOptions::default(), 0).unwrap();
+
+        // Same scheme, different secret
+        let other = "legal winner thank year wave sausage worth useful legal winner thank yellow";
+        let recovered = combine_shares_with_options(
+            &split_mnemonic(other, config).unwrap()[..2],
+            &CombineOptions::default(),
+        )
+        .unwrap();
+        let err = manifest.verify(&recovered).unwrap_err().to_string();
+        assert!(err.contains("but the manifest records"));
+        assert!(err.contains("do not belong to the share set"));
+
+        // Different scheme
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let recovered = combine_shares_with_options(
+            &split_mnemonic(MNEMONIC_12, config).unwrap()[2..],
+            &CombineOptions::default(),
+        )
+        .unwrap();
+        let err = manifest.verify(&recovered).unwrap_err().to_string();
+        assert!(err.contains("Shares have threshold 3, but the manifest records a 2-of-3 scheme"));
+    }
+
+    /// Splits the test mnemonic 3-of-5 and replaces share #2 with one that carries a
+    /// valid checksum over wrong data
+    fn shares_with_forged_share() -> Vec<String> {
+        use crate::domain::{ShareCount, Threshold};
+        let mnemonic_str =
+            "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let mut shares = split_mnemonic(mnemonic_str, config).unwrap();
+
+        let (threshold, index, data) = codec::parse_share(&shares[1]).unwrap();
+        let mut forged = data.to_vec();
+        forged[5] ^= 0x5A;
+        shares[1] = codec::create_share(&forged, threshold, index)
+            .unwrap()
+            .to_string();
+        shares
+    }
+
+    #[test]
+    fn test_combine_shares_robust_skips_forged_share() {
+        let shares = shares_with_forged_share();
+
+        // Interpolating over all five shares silently yields the wrong secret
+        assert!(!combine_shares(&shares).is_ok_and(|m| *m == *MNEMONIC_12));
+
+        let options = CombineOptions {
+            robust: true,
+            ..CombineOptions::default()
+        };
+        let recovered = combine_shares_with_options(&shares, &options).unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        assert_eq!(
+            recovered.warnings,
+            vec![
+                "Ignoring share #2: it is inconsistent with the shares that agree on the secret"
+                    .to_string()
+            ]
+        );
+        let (_, forged_index) = codec::parse_share_parameters(&shares[1]).unwrap();
+        assert_eq!(recovered.used_indices.len(), shares.len() - 1);
+        assert!(!recovered.used_indices.contains(&forged_index));
+    }
+
+    #[test]
+    fn test_combine_shares_robust_skips_checksum_failure() {
+        let mut shares = shares_with_forged_share();
+        shares.remove(1);
+
+        // Corrupt a data word so the checksum fails
+        let mut words: Vec<&str> = shares[3].split(' ').collect();
+        let last = words.len() - 1;
+        words[last] = if words[last] == "abandon" {
+            "ability"
+        } else {
+            "abandon"
+        };
+        shares[3] = words.join(" ");
 
-        // Convert to blahaj Share
-        let share = blahaj::Share::try_from(share_data.as_slice())
-            .map_err(|e| anyhow!("Failed to create share from data: {e:?}"))?;
+        assert!(combine_shares(&shares).is_err());
 
-        parsed_shares.push(share);
+        let options = CombineOptions {
+            robust: true,
+            ..CombineOptions::default()
+        };
+        let recovered = combine_shares_with_options(&shares, &options).unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        assert_eq!(recovered.warnings.len(), 1);
+        assert!(recovered.warnings[0].starts_with("Ignoring share #4: Failed to parse share #4"));
     }
 
-    let threshold = threshold_from_shares.ok_or_else(|| anyhow!("No valid shares found"))?;
+    #[test]
+    fn test_combine_shares_robust_skips_unusable_share_data() {
+        let mut shares = shares_with_forged_share();
+        shares.remove(1);
+        // Share #5 reuses the x-coordinate of share #1 under another index, share #6
+        // holds a single byte of data
+        let (threshold, _, data) = codec::parse_share(&shares[0]).unwrap();
+        shares.push(
+            codec::create_share(&data, threshold, ShareIndex::new(7).unwrap())
+                .unwrap()
+                .to_string(),
+        );
+        shares.push(
+            codec::create_share(&[9], threshold, ShareIndex::new(8).unwrap())
+                .unwrap()
+                .to_string(),
+        );
 
-    // Check if we have enough shares
-    let threshold_val = *threshold;
-    if parsed_shares.len() < threshold_val as usize {
-        bail!(
-            "Insufficient shares: need at least {}, but only {} provided",
-            threshold_val,
-            parsed_shares.len()
+        assert!(combine_shares(&shares).is_err());
+
+        let options = CombineOptions {
+            robust: true,
+            ..CombineOptions::default()
+        };
+        let recovered = combine_shares_with_options(&shares, &options).unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        assert_eq!(recovered.used_indices.len(), 4);
+        assert!(recovered.warnings[0].starts_with(
+            "Ignoring share #5: Share #5 and share #1 carry the same Sharks x-coordinate"
+        ));
+        assert!(
+            recovered.warnings[1].starts_with("Ignoring share #6: Share #6 holds only 1 byte(s)")
         );
     }
 
-    // Combine shares using blahaj
-    let sharks = Sharks(threshold_val);
-    let recovered = Zeroizing::new(
-        sharks
-            .recover(&parsed_shares)
-            .map_err(|e| anyhow!("Failed to recover secret: {e:?}"))?,
-    );
+    #[test]
+    fn test_combine_shares_robust_needs_a_confirming_spare() {
+        let mut shares = shares_with_forged_share();
+        shares.truncate(4);
+        // Two forged shares among four leave no 3 clean shares plus a spare
+        let (threshold, index, data) = codec::parse_share(&shares[3]).unwrap();
+        let mut forged = data.to_vec();
+        forged[2] ^= 0x11;
+        shares[3] = codec::create_share(&forged, threshold, index)
+            .unwrap()
+            .to_string();
 
-    // Convert back to mnemonic
-    let mnemonic = Mnemonic::from_entropy(&recovered)
-        .context("Failed to create mnemonic from recovered entropy")?;
+        let options = CombineOptions {
+            robust: true,
+            ..CombineOptions::default()
+        };
+        let err = combine_shares_with_options(&shares, &options)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("No quorum of 3 mutually consistent shares found among 4 shares"));
+    }
 
-    Ok(mnemonic.to_string())
-}
+    #[test]
+    fn test_combine_shares_reports_inconsistent_lengths() {
+        use crate::domain::ShareCount;
+        const MNEMONIC_24: &str = "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let short = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let long = split_mnemonic(MNEMONIC_24, config).unwrap();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let err = combine_shares(&[&short[0], &long[1]])
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("Shares have inconsistent lengths — they may come from different secrets")
+        );
+        assert!(err.contains("share #1 holds 17 bytes of share data, but share #2 holds 33"));
+    }
 
     #[test]
-    fn test_split_mnemonic_invalid_input() {
-        use crate::domain::{ShareCount, Threshold};
+    fn test_combine_shares_reports_colliding_x_coordinates() {
+        let threshold = Threshold::new(2).unwrap();
+        let shares = [
+            codec::create_share(&[1; 17], threshold, ShareIndex::new(0).unwrap()).unwrap(),
+            codec::create_share(&[1; 17], threshold, ShareIndex::new(1).unwrap()).unwrap(),
+        ];
+
+        let err = combine_shares(&shares).unwrap_err().to_string();
+        assert!(err.contains("Share #2 and share #1 carry the same Sharks x-coordinate"));
+    }
+
+    #[test]
+    fn test_combine_shares_warns_on_index_x_coordinate_mismatch() {
+        use crate::domain::ShareCount;
         let config =
             SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
-        let result = split_mnemonic("invalid mnemonic words here", config);
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
 
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Failed to parse input mnemonic")
+        // Relabel share 1's data (x-coordinate 2) as index 2
+        let (threshold, _, data) = codec::parse_share(&shares[1]).unwrap();
+        let relabelled = codec::create_share(&data, threshold, ShareIndex::new(2).unwrap())
+            .unwrap()
+            .to_string();
+
+        let recovered =
+            combine_shares_with_options(&[&shares[0], &relabelled], &CombineOptions::default())
+                .unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        assert_eq!(
+            recovered.warnings,
+            [
+                "Share #2 is labelled index 2 but its data has x-coordinate 2, which belongs to \
+              index 1: the label or the data is wrong"
+            ]
         );
     }
 
     #[test]
-    fn test_split_mnemonic_threshold_too_low() {
-        use crate::domain::Threshold;
-        // Threshold::new(1) will fail, so we test the constructor
-        let result = Threshold::new(1);
+    fn test_combine_shares_reports_share_data_too_short() {
+        let threshold = Threshold::new(2).unwrap();
+        let shares = [
+            codec::create_share(&[1], threshold, ShareIndex::new(0).unwrap()).unwrap(),
+            codec::create_share(&[2], threshold, ShareIndex::new(1).unwrap()).unwrap(),
+        ];
 
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(err_msg.contains("Threshold must be at least 2"));
+        let err = combine_shares(&shares).unwrap_err().to_string();
+        assert!(err.contains("Share #1 holds only 1 byte(s) of share data"));
     }
 
     #[test]
-    fn test_split_mnemonic_threshold_zero() {
-        use crate::domain::Threshold;
-        // Threshold::new(0) will fail, so we test the constructor
-        let result = Threshold::new(0);
+    fn test_health_report_healthy_shares() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
 
-        assert!(result.is_err());
-        let err_msg = result.unwrap_err().to_string();
-        assert!(err_msg.contains("Threshold must be at least 2"));
+        let report = health_report(&shares[1..4]);
+        assert_eq!(report.total, 3);
+        assert_eq!(report.passed_checksum, 3);
+        assert_eq!(report.threshold, Some(Threshold::new(3).unwrap()));
+        assert!(report.threshold_agreed);
+        assert!(report.quorum);
+        assert_eq!(
+            report.distinct_indices,
+            [1, 2, 3].map(|index| ShareIndex::new(index).unwrap())
+        );
+        assert!(report.problems.is_empty());
     }
 
     #[test]
-    fn test_split_mnemonic_12_word() {
-        use crate::domain::{ShareCount, Threshold};
-        let mnemonic_str =
-            "army van defense carry jealous true garbage claim echo media make crunch";
+    fn test_health_report_reads_joined_and_bom_prefixed_shares() {
+        use crate::domain::ShareCount;
         let config =
             SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
-        let result = split_mnemonic(mnemonic_str, config);
-        assert!(result.is_ok());
-        let shares = result.unwrap();
-        assert_eq!(shares.len(), 3);
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let input = [
+            shares[0].replace(' ', "_"),
+            format!("\u{FEFF}{}", shares[1]),
+            shares[2].replace(' ', "-"),
+        ];
+
+        let report = health_report(&input);
+        assert!(report.problems.is_empty(), "{:?}", report.problems);
+        assert_eq!(report.passed_checksum, 3);
+        assert!(report.quorum);
+
+        // A joined share with a misspelled version word is reported without its data
+        let garbled = shares[0]
+            .replacen("shameless", "shamless", 1)
+            .replace(' ', "_");
+        let report = health_report(&[garbled.as_str()]);
+        let problem = &report.problems[0];
+        assert_eq!(problem.kind, ProblemKind::BadVersion);
+        let shown = format!("'{}...'", &garbled[..13]);
+        assert!(problem.message.contains(&shown), "{}", problem.message);
+        let data_word = shares[0].split(' ').nth(3).unwrap();
+        assert!(!problem.message.contains(data_word), "{}", problem.message);
     }
 
     #[test]
-    fn test_split_mnemonic_24_word() {
-        use crate::domain::{ShareCount, Threshold};
-        let mnemonic_str = "void come effort suffer camp survey warrior heavy shoot primary clutch crush open amazing screen patrol group space point ten exist slush involve unfold";
+    fn test_problem_kind_all_lists_every_kind() {
+        // Adding a variant breaks this match, as a reminder to list it in `ALL`
+        let position = |kind: ProblemKind| match kind {
+            ProblemKind::BadVersion => 0,
+            ProblemKind::UnknownWord => 1,
+            ProblemKind::Checksum => 2,
+            ProblemKind::Malformed => 3,
+            ProblemKind::DuplicateShare => 4,
+            ProblemKind::DuplicateIndex => 5,
+            ProblemKind::ThresholdMismatch => 6,
+            ProblemKind::NoQuorum => 7,
+        };
+        for (expected, kind) in ProblemKind::ALL.into_iter().enumerate() {
+            assert_eq!(position(kind), expected);
+        }
+    }
+
+    #[test]
+    fn test_health_report_lists_every_problem() {
+        use crate::domain::ShareCount;
         let config =
             SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
-        let result = split_mnemonic(mnemonic_str, config);
-        assert!(result.is_ok());
-        let shares = result.unwrap();
-        assert_eq!(shares.len(), 5);
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let other = split_mnemonic(
+            MNEMONIC_12,
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap(),
+        )
+        .unwrap();
+        let resplit = split_mnemonic(MNEMONIC_12, config).unwrap();
+
+        let mut words: Vec<String> = shares[1].split(' ').map(str::to_string).collect();
+        let misspelled = format!("{}q", words[4]);
+        let original = std::mem::replace(&mut words[4], misspelled);
+        let typo = words.join(" ");
+
+        let mut words: Vec<&str> = shares[2].split(' ').collect();
+        let last = words.len() - 1;
+        words.swap(3, last);
+        let corrupt = words.join(" ");
+
+        let input = [
+            shares[0].clone(),
+            typo,
+            corrupt,
+            shares[0].replacen("shameless", "shamless", 1),
+            other[1].clone(),
+            resplit[0].clone(),
+            shares[0].clone(),
+        ];
+        let report = health_report(&input);
+
+        let kinds: Vec<(Option<usize>, ProblemKind)> = report
+            .problems
+            .iter()
+            .map(|problem| (problem.position, problem.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                (Some(2), ProblemKind::UnknownWord),
+                (Some(3), ProblemKind::Checksum),
+                (Some(4), ProblemKind::BadVersion),
+                (Some(7), ProblemKind::DuplicateShare),
+                (Some(5), ProblemKind::ThresholdMismatch),
+                (Some(6), ProblemKind::DuplicateIndex),
+                (None, ProblemKind::NoQuorum),
+            ]
+        );
+        assert!(
+            report.problems[0]
+                .message
+                .ends_with(&format!("did you mean '{original}'?"))
+        );
+
+        assert_eq!(report.total, 7);
+        assert_eq!(report.passed_checksum, 3);
+        assert_eq!(report.threshold, Some(Threshold::new(3).unwrap()));
+        assert!(!report.threshold_agreed);
+        assert_eq!(report.distinct_indices, [ShareIndex::new(0).unwrap()]);
+        assert!(!report.quorum);
     }
 
     #[test]
-    fn test_full_split_and_combine_round_trip() {
+    fn test_combine_shares_rejects_exact_duplicate() {
         use crate::domain::{ShareCount, Threshold};
-        // This tests the full flow through the command functions
         let mnemonic_str =
             "army van defense carry jealous true garbage claim echo media make crunch";
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let shares = split_mnemonic(mnemonic_str, config).unwrap();
 
-        // Use the split_mnemonic function directly
-        let threshold = Threshold::new(2).unwrap();
-        let share_count = ShareCount::new(3).unwrap();
-        let config = SplitConfig::new(threshold, share_count).unwrap();
-
-        let share_strings = split_mnemonic(mnemonic_str, config).unwrap();
-        assert_eq!(share_strings.len(), 3);
+        // Same share pasted twice, once in uppercase
+        let duplicated = vec![shares[0].clone(), shares[0].to_uppercase()];
+        let err = combine_shares(&duplicated).unwrap_err().to_string();
+        assert!(err.contains("Share #2 is an exact duplicate of share #1"));
+    }
 
-        // Take 2 shares (threshold is 2)
-        let selected_shares = vec![share_strings[0].clone(), share_strings[1].clone()];
+    #[test]
+    fn test_combine_to_entropy_recovers_non_mnemonic_lengths() {
+        use crate::domain::{ShareCount, Threshold};
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let secret = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77];
+        let shares = deal_shares(&secret, config, SplitOptions::default(), None).unwrap();
 
-        // Use the combine_shares function directly
-        let recovered_mnemonic = combine_shares(&selected_shares).unwrap();
+        assert_eq!(*combine_to_entropy(&shares[1..]).unwrap(), secret);
 
-        assert_eq!(mnemonic_str, recovered_mnemonic);
+        // 7 bytes is not a BIP39 entropy length
+        let err = format!("{:#}", combine_shares(&shares[1..]).unwrap_err());
+        assert!(err.contains("Failed to create mnemonic from 7 bytes of recovered entropy"));
     }
 
     #[test]
-    fn test_split_mnemonic_insufficient_shares() {
+    fn test_combine_shares_dedups_identical_copies() {
         use crate::domain::{ShareCount, Threshold};
-        let mnemonic_str =
-            "army van defense carry jealous true garbage claim echo media make crunch";
-
-        // Create shares with threshold 3
-        let threshold = Threshold::new(3).unwrap();
-        let share_count = ShareCount::new(5).unwrap();
-        let config = SplitConfig::new(threshold, share_count).unwrap();
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
 
-        let share_strings = split_mnemonic(mnemonic_str, config).unwrap();
-        assert_eq!(share_strings.len(), 5);
+        // The same share transcribed as words and as base64
+        let (threshold, index, data) = codec::parse_share(&shares[0]).unwrap();
+        let copy = codec::compact::create_share_compact(&data, threshold, index).unwrap();
+        let input = [
+            shares[0].clone(),
+            copy,
+            shares[0].clone(),
+            shares[1].clone(),
+        ];
 
-        // Try to combine with only 2 shares (threshold is 3)
-        let insufficient_shares = vec![share_strings[0].clone(), share_strings[1].clone()];
-        let result = combine_shares(&insufficient_shares);
+        let err = combine_shares(&input).unwrap_err().to_string();
+        assert!(err.contains("exact duplicate"));
 
-        // Should error with insufficient shares
-        assert!(result.is_err());
-        assert!(
-            result
-                .unwrap_err()
-                .to_string()
-                .contains("Insufficient shares")
-        );
+        for dedup in [DedupPolicy::First, DedupPolicy::Last] {
+            let options = CombineOptions {
+                dedup,
+                ..CombineOptions::default()
+            };
+            let recovered = combine_shares_with_options(&input, &options).unwrap();
+            assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+            assert_eq!(
+                recovered.used_indices,
+                [ShareIndex::new(0).unwrap(), ShareIndex::new(1).unwrap()]
+            );
+        }
     }
 
     #[test]
-    fn test_combine_shares_empty_input() {
-        let empty_shares: Vec<String> = vec![];
-        let result = combine_shares(&empty_shares);
-        assert!(result.is_err());
+    fn test_combine_shares_dedup_rejects_conflicting_copies() {
+        use crate::domain::Threshold;
+        let threshold = Threshold::new(2).unwrap();
+        let index = ShareIndex::new(0).unwrap();
+        let share1 = codec::create_share(&[1, 2, 3], threshold, index)
+            .unwrap()
+            .to_string();
+        let share2 = codec::create_share(&[1, 4, 5], threshold, index)
+            .unwrap()
+            .to_string();
+
+        for dedup in [DedupPolicy::First, DedupPolicy::Last] {
+            let options = CombineOptions {
+                dedup,
+                ..CombineOptions::default()
+            };
+            let err = combine_shares_with_options(&[share1.clone(), share2.clone()], &options)
+                .unwrap_err()
+                .to_string();
+            assert!(
+                err.contains("Share #2 has the same index (0) as share #1 but different contents")
+            );
+        }
     }
 
     #[test]
-    fn test_combine_shares_invalid_shamir39() {
-        // Invalid version word
-        let invalid_shares = vec!["invalid word word word".to_string()];
-        let result = combine_shares(&invalid_shares);
-        assert!(result.is_err());
+    fn test_combine_shares_rejects_index_collision() {
+        use crate::domain::Threshold;
+        let threshold = Threshold::new(2).unwrap();
+        let index = ShareIndex::new(0).unwrap();
+        let share1 = codec::create_share(&[1, 2, 3], threshold, index)
+            .unwrap()
+            .to_string();
+        let share2 = codec::create_share(&[1, 4, 5], threshold, index)
+            .unwrap()
+            .to_string();
+
+        let err = combine_shares(&[share1, share2]).unwrap_err().to_string();
+        assert!(err.contains("same index (0)"));
     }
 
     #[test]
@@ -280,4 +4426,333 @@ mod tests {
                 .contains("inconsistent threshold")
         );
     }
+
+    #[test]
+    fn test_bundle_round_trip_and_errors() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let bundle = bundle_shares(&shares).unwrap();
+
+        assert!(!bundle.contains(char::is_whitespace));
+        assert_eq!(unbundle_shares(&bundle).unwrap(), shares);
+        assert_eq!(
+            *combine_shares(&expand_bundles(&[bundle.as_str()]).unwrap()).unwrap(),
+            MNEMONIC_12
+        );
+
+        // Bundles and loose shares can be mixed
+        let mixed = expand_bundles(&[shares[0].as_str(), bundle.as_str()]).unwrap();
+        assert_eq!(mixed.len(), 6);
+        assert_eq!(mixed[0], shares[0]);
+
+        let err = unbundle_shares(&shares[0]).unwrap_err().to_string();
+        assert!(err.contains("Not a share bundle"), "{err}");
+
+        let corrupt = format!("{BUNDLE_HEADER}AAAA{}", &bundle[BUNDLE_HEADER.len() + 4..]);
+        let err = unbundle_shares(&corrupt).unwrap_err().to_string();
+        assert!(err.contains("corrupt"), "{err}");
+
+        let err = bundle_shares::<&str>(&[]).unwrap_err().to_string();
+        assert!(err.contains("No shares to bundle"), "{err}");
+    }
+
+    #[test]
+    fn test_combine_fills_in_one_unknown_word_per_share() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let mask = |share: &str, position: usize| {
+            let mut words: Vec<&str> = share.split_whitespace().collect();
+            words[position] = codec::UNKNOWN_WORD;
+            words.join(" ")
+        };
+
+        let masked = [mask(&shares[0], 3), mask(&shares[2], 7)];
+        let mut warnings = Vec::new();
+        let parsed = decode_shares(&masked, CombineOptions::default(), &mut warnings).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(*combine_shares(&masked).unwrap(), MNEMONIC_12);
+
+        let mut collector = ShareCollector::new();
+        collector.add(&mask(&shares[1], 5)).unwrap();
+        assert_eq!(collector.shares().len(), 1);
+        assert_eq!(collector.shares()[0], shares[1].as_str());
+
+        let twice = mask(&mask(&shares[1], 3), 4);
+        let err = combine_shares(&[masked[0].clone(), twice]).unwrap_err();
+        assert!(format!("{err:#}").contains("2 unknown words"), "{err:#}");
+    }
+
+    #[test]
+    fn test_refreshed_shares_do_not_mix_with_old_ones() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let old = split_mnemonic(MNEMONIC_12, config).unwrap();
+        let new = refresh_shares(&old[..2]).unwrap();
+
+        let meta = |share: &str| codec::share_metadata(share).unwrap();
+        for (old, new) in old.iter().zip(&new) {
+            assert_ne!(old, new);
+            assert_eq!(meta(old).index, meta(new).index);
+            assert_eq!(meta(old).threshold, meta(new).threshold);
+        }
+        assert_eq!(*combine_shares(&new[1..]).unwrap(), MNEMONIC_12);
+
+        // A quorum mixing the two sets recovers a wrong secret, which a manifest rejects
+        let mixed = [old[0].clone(), new[1].clone()];
+        assert_ne!(*combine_shares(&mixed).unwrap(), MNEMONIC_12);
+        let manifest =
+            share_manifest(MNEMONIC_12, &new, config, &SplitOptions::default(), 0).unwrap();
+        let recovered = combine_shares_with_options(&mixed, &CombineOptions::default()).unwrap();
+        assert!(manifest.verify(&recovered).is_err());
+
+        // Robust combining wants a spare to confirm the secret, which the stale share can't
+        let options = CombineOptions {
+            robust: true,
+            ..CombineOptions::default()
+        };
+        let mixed = [old[0].clone(), new[1].clone(), new[2].clone()];
+        assert!(combine_shares_with_options(&mixed, &options).is_err());
+
+        let options = SplitOptions {
+            omit_share_count: true,
+            ..SplitOptions::default()
+        };
+        let uncounted = split_mnemonic_with_options(MNEMONIC_12, config, &options).unwrap();
+        assert!(refresh_shares(&uncounted).is_err());
+    }
+
+    #[test]
+    fn test_combine_accepts_right_padded_shares() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let right = CodecOptions {
+            padding: codec::Padding::Right,
+            ..CodecOptions::default()
+        };
+        let shares: Vec<String> = split_mnemonic(MNEMONIC_12, config)
+            .unwrap()
+            .iter()
+            .map(|share| {
+                let parsed = codec::parse_share_part(share, &CodecOptions::default()).unwrap();
+                codec::create_share_with_options(
+                    &parsed.data,
+                    parsed.threshold,
+                    parsed.index,
+                    &right,
+                )
+                .unwrap()
+                .to_string()
+            })
+            .collect();
+
+        let recovered =
+            combine_shares_with_options(&shares[..2], &CombineOptions::default()).unwrap();
+        assert_eq!(*recovered.mnemonic, MNEMONIC_12);
+        assert_eq!(recovered.warnings.len(), 2);
+        assert!(
+            recovered.warnings[0].contains("right-padded"),
+            "{:?}",
+            recovered.warnings
+        );
+    }
+
+    #[test]
+    fn test_paper_share_holds_only_its_share() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+
+        let page = render_paper_share(&shares[3]).unwrap();
+        assert!(page.starts_with("<!DOCTYPE html>"));
+        assert!(page.contains(&shares[3]));
+        assert!(
+            page.contains("Share 4 of 5, threshold 3 (index 3)"),
+            "{page}"
+        );
+        assert!(page.contains("<svg"));
+        assert!(!page.contains("<?xml"));
+        for other in [0, 1, 2, 4] {
+            assert!(!page.contains(&shares[other]));
+        }
+
+        // Without a recorded share count, the page does not guess one
+        let options = SplitOptions {
+            omit_share_count: true,
+            ..SplitOptions::default()
+        };
+        let shares = split_mnemonic_with_options(MNEMONIC_12, config, &options).unwrap();
+        let page = render_paper_share(&shares[0]).unwrap();
+        assert!(page.contains("Share 1, threshold 3 (index 0)"), "{page}");
+
+        assert!(render_paper_share("not a share").is_err());
+    }
+
+    #[test]
+    fn test_share_set_validates_members() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        let shares = split_mnemonic_typed(MNEMONIC_12, config).unwrap();
+
+        let mut set = ShareSet::new();
+        assert!(set.is_empty());
+        assert!(set.threshold().is_err());
+        assert!(!set.is_recoverable());
+
+        set.push(shares[1].clone());
+        set.push(shares[1].clone());
+        assert_eq!(set.len(), 2);
+        assert_eq!(
+            set.distinct_indices().unwrap(),
+            [ShareIndex::new(1).unwrap()]
+        );
+        assert!(!set.is_recoverable(), "a duplicate is not a second share");
+
+        set.push(shares[0].clone());
+        assert!(set.is_recoverable());
+        assert_eq!(set.iter().count(), 3);
+        assert_eq!((&set).into_iter().count(), 3);
+
+        let other =
+            SplitConfig::new(Threshold::new(3).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        set.extend(split_mnemonic_typed(MNEMONIC_12, other).unwrap());
+        let err = set.threshold().unwrap_err().to_string();
+        assert!(err.contains("Share #4 has inconsistent threshold"), "{err}");
+        assert!(!set.is_recoverable());
+    }
+
+    #[test]
+    fn test_recovery_reports_missing_shares() {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(4).unwrap()).unwrap();
+        let shares = split_mnemonic(MNEMONIC_12, config).unwrap();
+
+        let recovered =
+            combine_shares_with_options(&shares[1..3], &CombineOptions::default()).unwrap();
+        assert_eq!(recovered.share_count, Some(ShareCount::new(4).unwrap()));
+        assert_eq!(recovered.missing_shares(), Some(2));
+        assert_eq!(
+            recovered.missing_shares_message().unwrap(),
+            "recovered using 2 of 4 original shares; 2 shares are missing"
+        );
+
+        let recovered = combine_shares_with_options(&shares, &CombineOptions::default()).unwrap();
+        assert_eq!(recovered.missing_shares(), Some(0));
+        assert_eq!(recovered.missing_shares_message(), None);
+
+        // Shares that omit the count, and rotated shares, which record the new count
+        let options = SplitOptions {
+            omit_share_count: true,
+            ..SplitOptions::default()
+        };
+        let unrecorded = split_mnemonic_with_options(MNEMONIC_12, config, &options).unwrap();
+        let recovered =
+            combine_shares_with_options(&unrecorded[..2], &CombineOptions::default()).unwrap();
+        assert_eq!(recovered.missing_shares(), None);
+
+        let new_config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(5).unwrap()).unwrap();
+        let rotated = rotate_shares(&unrecorded[..2], new_config).unwrap();
+        let recovered =
+            combine_shares_with_options(&rotated[..2], &CombineOptions::default()).unwrap();
+        assert_eq!(recovered.missing_shares(), Some(3));
+    }
+
+    #[test]
+    fn test_recovered_mnemonic_debug_redacts_the_mnemonic() {
+        let shares = collector_shares();
+        let recovered =
+            combine_shares_with_options(&shares[..2], &CombineOptions::default()).unwrap();
+
+        let debug = format!("{recovered:?}");
+        assert!(debug.contains(r#"mnemonic: "<redacted>""#));
+        assert!(debug.contains(&recovered.fingerprint));
+        assert!(!debug.contains("army van defense"), "{debug}");
+    }
+
+    #[test]
+    fn test_split_result_debug_redacts_shares() {
+        let shares = collector_shares();
+        let result = SplitResult {
+            share_count: 3,
+            threshold: 2,
+            shares,
+        };
+
+        assert_eq!(
+            format!("{result:?}"),
+            "SplitResult { shares: <3 shares redacted>, share_count: 3, threshold: 2 }"
+        );
+    }
+
+    fn collector_shares() -> Vec<String> {
+        use crate::domain::ShareCount;
+        let config =
+            SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+        split_mnemonic(MNEMONIC_12, config).unwrap()
+    }
+
+    #[test]
+    fn test_collector_counts_down_to_threshold() {
+        let shares = collector_shares();
+        let mut collector = ShareCollector::new();
+        assert_eq!(collector.remaining(), None);
+
+        assert_eq!(*collector.add(&shares[2]).unwrap(), 2);
+        assert_eq!(collector.remaining(), Some(1));
+
+        assert_eq!(*collector.add(&shares[0]).unwrap(), 0);
+        assert_eq!(collector.remaining(), Some(0));
+    }
+
+    #[test]
+    fn test_collector_rejects_invalid_and_repeated_shares() {
+        let shares = collector_shares();
+        let mut collector = ShareCollector::new();
+
+        assert!(collector.add("shameless not a share").is_err());
+        collector.add(&shares[0]).unwrap();
+
+        let err = collector.add(&shares[0]).unwrap_err().to_string();
+        assert!(err.contains("index 0 was already entered as share #1"));
+
+        // Rejected shares leave the collector unchanged
+        assert_eq!(collector.shares().len(), 1);
+    }
+
+    #[test]
+    fn test_collector_rejects_threshold_mismatch() {
+        let mut collector = ShareCollector::new();
+        collector.add(&collector_shares()[0]).unwrap();
+
+        let other = codec::create_share(
+            &[1, 2, 3],
+            Threshold::new(3).unwrap(),
+            ShareIndex::new(1).unwrap(),
+        )
+        .unwrap();
+        let err = collector.add(other.as_str()).unwrap_err().to_string();
+        assert!(err.contains("threshold 3 disagrees with threshold 2"));
+    }
+
+    #[test]
+    fn test_collector_debug_redacts_shares() {
+        let shares = collector_shares();
+        let mut collector = ShareCollector::new();
+        collector.add(&shares[1]).unwrap();
+
+        let debug = format!("{collector:?}");
+        assert!(debug.contains("<index 1, threshold 2, data redacted>"));
+        let data_words = shares[1].split_whitespace().skip(2).collect::<Vec<_>>();
+        assert!(!debug.contains(&data_words.join(" ")), "{debug}");
+    }
 }
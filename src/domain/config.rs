@@ -44,11 +44,7 @@ impl SplitConfig {
     /// ```
     pub fn new(threshold: Threshold, share_count: ShareCount) -> Result<Self> {
         if *threshold > *share_count {
-            bail!(
-                "Threshold {} cannot exceed share count {}",
-                *threshold,
-                *share_count
-            );
+            bail!("Threshold {threshold} cannot exceed share count {share_count}");
         }
         Ok(Self {
             threshold,
@@ -56,6 +52,22 @@ impl SplitConfig {
         })
     }
 
+    /// Starts building a configuration from plain numbers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::SplitConfig;
+    ///
+    /// let config = SplitConfig::builder().threshold(3).share_count(5).build().unwrap();
+    /// assert_eq!(*config.threshold(), 3);
+    /// assert_eq!(*config.share_count(), 5);
+    /// ```
+    #[must_use]
+    pub fn builder() -> SplitConfigBuilder {
+        SplitConfigBuilder::default()
+    }
+
     /// Gets the threshold value
     #[must_use]
     pub fn threshold(&self) -> Threshold {
@@ -67,4 +79,166 @@ impl SplitConfig {
     pub fn share_count(&self) -> ShareCount {
         self.share_count
     }
+
+    /// Enforces a caller-imposed minimum threshold policy
+    ///
+    /// This is distinct from the `threshold >= 2` invariant of [`Threshold`]: it lets
+    /// operators mandate a stricter floor (e.g. "never split below 3-of-N").
+    ///
+    /// # Errors
+    /// Returns an error if the configured threshold is below `min_threshold`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::{SplitConfig, Threshold, ShareCount};
+    ///
+    /// let config = SplitConfig::new(
+    ///     Threshold::new(2).unwrap(),
+    ///     ShareCount::new(5).unwrap()
+    /// ).unwrap();
+    ///
+    /// // 2-of-5 violates a minimum threshold policy of 3
+    /// assert!(config.enforce_min_threshold(3).is_err());
+    ///
+    /// let config = SplitConfig::new(
+    ///     Threshold::new(3).unwrap(),
+    ///     ShareCount::new(5).unwrap()
+    /// ).unwrap();
+    ///
+    /// // 3-of-5 satisfies it
+    /// assert!(config.enforce_min_threshold(3).is_ok());
+    /// ```
+    pub fn enforce_min_threshold(&self, min_threshold: u8) -> Result<()> {
+        if *self.threshold < min_threshold {
+            bail!(
+                "Policy violation: threshold {} is below the required minimum of {min_threshold}",
+                self.threshold
+            );
+        }
+        Ok(())
+    }
+
+    /// Advisories about a valid but possibly unintended scheme
+    ///
+    /// These never prevent a split; they point out schemes that are usually a mistake,
+    /// such as one with no spare shares.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::{SplitConfig, Threshold, ShareCount};
+    ///
+    /// let config = SplitConfig::new(
+    ///     Threshold::new(5).unwrap(),
+    ///     ShareCount::new(5).unwrap()
+    /// ).unwrap();
+    ///
+    /// // 5-of-5 leaves no room to lose a share
+    /// let warnings = config.warnings();
+    /// assert_eq!(warnings.len(), 1);
+    /// assert!(warnings[0].contains("threshold equals share count"));
+    ///
+    /// let config = SplitConfig::new(
+    ///     Threshold::new(3).unwrap(),
+    ///     ShareCount::new(5).unwrap()
+    /// ).unwrap();
+    /// assert!(config.warnings().is_empty());
+    /// ```
+    #[must_use]
+    pub fn warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if *self.threshold == *self.share_count {
+            warnings.push(format!(
+                "threshold equals share count ({}-of-{}): no redundancy, any lost share is unrecoverable",
+                self.threshold, self.share_count
+            ));
+        }
+        warnings
+    }
+}
+
+/// Builder for [`SplitConfig`] taking plain numbers, see [`SplitConfig::builder`]
+///
+/// All validations run in [`build`](Self::build), which reports every problem found
+/// rather than only the first.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SplitConfigBuilder {
+    threshold: Option<u8>,
+    share_count: Option<u8>,
+}
+
+impl SplitConfigBuilder {
+    /// Sets the minimum number of shares needed to reconstruct the secret
+    #[must_use]
+    pub fn threshold(mut self, threshold: u8) -> Self {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the total number of shares to create
+    #[must_use]
+    pub fn share_count(mut self, share_count: u8) -> Self {
+        self.share_count = Some(share_count);
+        self
+    }
+
+    /// Validates the threshold and share count and builds the configuration
+    ///
+    /// # Errors
+    /// Returns an error listing every problem found: a missing value, a threshold
+    /// below 2, a share count of 0 or above 254, or a threshold exceeding the share
+    /// count
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::SplitConfig;
+    ///
+    /// let err = SplitConfig::builder()
+    ///     .threshold(1)
+    ///     .share_count(255)
+    ///     .build()
+    ///     .unwrap_err()
+    ///     .to_string();
+    /// assert!(err.contains("Threshold must be at least 2"));
+    /// assert!(err.contains("Share count maximum is 254"));
+    ///
+    /// assert!(SplitConfig::builder().threshold(3).share_count(2).build().is_err());
+    /// assert!(SplitConfig::builder().threshold(3).build().is_err());
+    /// ```
+    pub fn build(self) -> Result<SplitConfig> {
+        let mut errors = Vec::new();
+        let threshold = match self.threshold.map(Threshold::new) {
+            Some(Ok(threshold)) => Some(threshold),
+            Some(Err(e)) => {
+                errors.push(e.to_string());
+                None
+            }
+            None => {
+                errors.push("Threshold is not set".to_string());
+                None
+            }
+        };
+        let share_count = match self.share_count.map(ShareCount::new) {
+            Some(Ok(share_count)) => Some(share_count),
+            Some(Err(e)) => {
+                errors.push(e.to_string());
+                None
+            }
+            None => {
+                errors.push("Share count is not set".to_string());
+                None
+            }
+        };
+
+        if let (Some(threshold), Some(share_count)) = (threshold, share_count) {
+            match SplitConfig::new(threshold, share_count) {
+                Ok(config) => return Ok(config),
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+
+        bail!("Invalid split configuration: {}", errors.join("; "))
+    }
 }
@@ -4,14 +4,15 @@
 //! - [`Threshold`] - Minimum shares required for reconstruction (2..=255)
 //! - [`ShareIndex`] - Share identifier (0..=254)
 //! - [`ShareCount`] - Total number of shares to create (1..=254)
-//! - [`SplitConfig`] - Validated threshold and share count pair
+//! - [`SplitConfig`] - Validated threshold and share count pair, also built from plain
+//!   numbers with [`SplitConfigBuilder`]
 
 mod config;
 mod share_count;
 mod share_index;
 mod threshold;
 
-pub use config::SplitConfig;
+pub use config::{SplitConfig, SplitConfigBuilder};
 pub use share_count::ShareCount;
 pub use share_index::ShareIndex;
 pub use threshold::Threshold;
@@ -1,12 +1,17 @@
 //! `ShareCount` newtype for Shamir Secret Sharing
 
+use std::ops::RangeInclusive;
+
 use anyhow::{Result, bail};
+use serde::Serialize;
+
+use super::ShareIndex;
 
 /// Number of shares to create (1..=254)
 ///
 /// Represents the total number of shares that will be created.
 /// The maximum is 254 due to GF256 limitations in the blahaj crate.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 pub struct ShareCount(u8);
 
 impl ShareCount {
@@ -46,6 +51,42 @@ impl ShareCount {
         }
         Ok(Self(value))
     }
+
+    /// Range of valid share counts
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::ShareCount;
+    ///
+    /// assert!(ShareCount::valid_range().all(|count| ShareCount::new(count).is_ok()));
+    /// assert!(!ShareCount::valid_range().contains(&0));
+    /// assert!(!ShareCount::valid_range().contains(&255));
+    /// ```
+    #[must_use]
+    pub const fn valid_range() -> RangeInclusive<u8> {
+        Self::MIN..=Self::MAX
+    }
+
+    /// Iterates over the index of every share, from 0 to `count - 1`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::ShareCount;
+    ///
+    /// let indices: Vec<u8> = ShareCount::new(3).unwrap().iter_indices().map(|i| *i).collect();
+    /// assert_eq!(indices, [0, 1, 2]);
+    ///
+    /// // The largest count stops short of the reserved index 255
+    /// let max = ShareCount::new(ShareCount::MAX).unwrap();
+    /// assert_eq!(max.iter_indices().len(), 254);
+    /// assert_eq!(max.iter_indices().last().map(|i| *i), Some(253));
+    /// ```
+    #[must_use]
+    pub fn iter_indices(self) -> impl ExactSizeIterator<Item = ShareIndex> {
+        ShareIndex::range(self)
+    }
 }
 
 impl std::ops::Deref for ShareCount {
@@ -56,3 +97,16 @@ impl std::ops::Deref for ShareCount {
         &self.0
     }
 }
+
+/// Formats as the inner number, so it can appear directly in messages
+///
+/// ```rust
+/// use shameless::domain::ShareCount;
+///
+/// assert_eq!(ShareCount::new(5).unwrap().to_string(), "5");
+/// ```
+impl std::fmt::Display for ShareCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
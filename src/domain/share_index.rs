@@ -1,12 +1,22 @@
 //! `ShareIndex` newtype for Shamir Secret Sharing
 
+use std::ops::RangeInclusive;
+
 use anyhow::{Result, bail};
+use serde::Serialize;
+
+use super::ShareCount;
 
 /// Share index (0..=254)
 ///
 /// Represents the index of a share in Shamir Secret Sharing.
 /// Index 255 is reserved by the blahaj crate for GF256 operations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// The shamir39 index `O` embedded in a share is 0-based, while the blahaj x-coordinate
+/// stored as the first byte of the share data is 1-based (x = 0 would be the secret
+/// itself). The share dealt at index `O` therefore always has x-coordinate `O + 1`;
+/// see [`ShareIndex::x_coordinate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 pub struct ShareIndex(u8);
 
 impl ShareIndex {
@@ -39,6 +49,67 @@ impl ShareIndex {
         }
         Ok(Self(value))
     }
+
+    /// The blahaj x-coordinate of the share dealt at this index, `index + 1`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::ShareIndex;
+    ///
+    /// assert_eq!(ShareIndex::new(0).unwrap().x_coordinate(), 1);
+    /// assert_eq!(ShareIndex::new(ShareIndex::MAX).unwrap().x_coordinate(), 255);
+    /// ```
+    #[must_use]
+    pub const fn x_coordinate(self) -> u8 {
+        // MAX is 254, so this never overflows
+        self.0 + 1
+    }
+
+    /// The index after this one, or `None` after [`ShareIndex::MAX`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::ShareIndex;
+    ///
+    /// assert_eq!(ShareIndex::new(0).unwrap().next(), Some(ShareIndex::new(1).unwrap()));
+    /// assert_eq!(ShareIndex::new(ShareIndex::MAX).unwrap().next(), None);
+    /// ```
+    #[must_use]
+    pub const fn next(self) -> Option<Self> {
+        if self.0 < Self::MAX {
+            Some(Self(self.0 + 1))
+        } else {
+            None
+        }
+    }
+
+    /// The indices of `count` shares, `0..count`, in dealing order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::{ShareCount, ShareIndex};
+    ///
+    /// let indices: Vec<u8> = ShareIndex::range(ShareCount::new(3).unwrap()).map(|i| *i).collect();
+    /// assert_eq!(indices, [0, 1, 2]);
+    ///
+    /// // The largest count stops short of the reserved index 255
+    /// let max = ShareIndex::range(ShareCount::new(254).unwrap());
+    /// assert_eq!(max.len(), 254);
+    /// assert_eq!(max.last().map(|i| *i), Some(253));
+    /// ```
+    pub fn range(count: ShareCount) -> impl ExactSizeIterator<Item = Self> {
+        // count <= ShareCount::MAX (254), so every index is at most 253
+        (0..*count).map(Self)
+    }
+
+    /// Range of valid share indices
+    #[must_use]
+    pub const fn valid_range() -> RangeInclusive<u8> {
+        0..=Self::MAX
+    }
 }
 
 impl std::ops::Deref for ShareIndex {
@@ -49,3 +120,16 @@ impl std::ops::Deref for ShareIndex {
         &self.0
     }
 }
+
+/// Formats as the inner number, so it can appear directly in messages
+///
+/// ```rust
+/// use shameless::domain::ShareIndex;
+///
+/// assert_eq!(ShareIndex::new(4).unwrap().to_string(), "4");
+/// ```
+impl std::fmt::Display for ShareIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -1,15 +1,24 @@
 //! Threshold newtype for Shamir Secret Sharing
 
+use std::ops::RangeInclusive;
+
 use anyhow::Result;
+use serde::Serialize;
 
 /// Threshold for Shamir Secret Sharing (2..=255)
 ///
 /// Invariant: threshold >= 2 (enforced at construction)
 /// A threshold of 1 provides no security benefit since any single share can recover the entire secret.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 pub struct Threshold(u8);
 
 impl Threshold {
+    /// Minimum valid threshold
+    pub const MIN: u8 = 2;
+
+    /// Maximum valid threshold
+    pub const MAX: u8 = u8::MAX;
+
     /// Creates a new threshold, returning an error if value < 2
     ///
     /// # Errors
@@ -29,11 +38,26 @@ impl Threshold {
     /// assert!(Threshold::new(0).is_err());
     /// ```
     pub fn new(value: u8) -> Result<Self> {
-        if value < 2 {
+        if value < Self::MIN {
             anyhow::bail!("Threshold must be at least 2 (got {value})");
         }
         Ok(Self(value))
     }
+
+    /// Range of valid thresholds
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use shameless::domain::Threshold;
+    ///
+    /// assert!(Threshold::valid_range().all(|value| Threshold::new(value).is_ok()));
+    /// assert!(!Threshold::valid_range().contains(&1));
+    /// ```
+    #[must_use]
+    pub const fn valid_range() -> RangeInclusive<u8> {
+        Self::MIN..=Self::MAX
+    }
 }
 
 impl std::ops::Deref for Threshold {
@@ -44,3 +68,16 @@ impl std::ops::Deref for Threshold {
         &self.0
     }
 }
+
+/// Formats as the inner number, so it can appear directly in messages
+///
+/// ```rust
+/// use shameless::domain::Threshold;
+///
+/// assert_eq!(Threshold::new(3).unwrap().to_string(), "3");
+/// ```
+impl std::fmt::Display for Threshold {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
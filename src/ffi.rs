@@ -0,0 +1,260 @@
+//! C-compatible bindings for embedding shameless in other languages
+//!
+//! Every function writes a newly allocated, null-terminated string to `*out` and returns
+//! [`SHAMELESS_OK`] or a negative error code. On an error, `*out` holds a JSON object
+//! `{"error": "..."}` describing it. Either way the string belongs to the caller, who
+//! must release it with [`shameless_free`]. Results mirror the WASM bindings:
+//! `shameless_split` returns the same JSON as `wasm_split`, `shameless_combine` the
+//! plain mnemonic like `wasm_combine`.
+//!
+//! Run `just ffi-header` to regenerate `include/shameless.h` with cbindgen.
+
+use std::ffi::{CStr, CString, c_char};
+
+use serde::Serialize;
+use zeroize::Zeroizing;
+
+use crate::commands::{self, SplitResult};
+use crate::domain::{ShareCount, SplitConfig, Threshold};
+
+/// The call succeeded
+pub const SHAMELESS_OK: i32 = 0;
+/// A required pointer argument was null
+pub const SHAMELESS_ERR_NULL: i32 = -1;
+/// A string argument was not valid UTF-8
+pub const SHAMELESS_ERR_UTF8: i32 = -2;
+/// The split parameters were invalid
+pub const SHAMELESS_ERR_PARAMS: i32 = -3;
+/// Splitting failed, e.g. the mnemonic is invalid
+pub const SHAMELESS_ERR_SPLIT: i32 = -4;
+/// Combining failed, e.g. too few or corrupted shares
+pub const SHAMELESS_ERR_COMBINE: i32 = -5;
+
+/// Error document written to `*out` when a call fails
+#[derive(Serialize)]
+struct FfiError<'a> {
+    error: &'a str,
+}
+
+/// Reads a C string argument
+///
+/// # Safety
+/// `ptr` must be null or point to a null-terminated string valid for the call
+unsafe fn read_str<'a>(ptr: *const c_char) -> Result<&'a str, (i32, String)> {
+    if ptr.is_null() {
+        return Err((SHAMELESS_ERR_NULL, "Null string argument".to_string()));
+    }
+    // SAFETY: non-null, and the caller guarantees a valid null-terminated string
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|e| (SHAMELESS_ERR_UTF8, format!("Invalid UTF-8: {e}")))
+}
+
+/// Writes `result` to `*out` as a C string and returns its error code
+///
+/// # Safety
+/// `out` must be non-null and valid for writes
+unsafe fn write_out(
+    out: *mut *mut c_char,
+    result: Result<Zeroizing<String>, (i32, String)>,
+) -> i32 {
+    let (code, text) = match result {
+        Ok(text) => (SHAMELESS_OK, text),
+        Err((code, message)) => {
+            let json = serde_json::to_string(&FfiError { error: &message })
+                .unwrap_or_else(|_| String::from("{\"error\":\"unknown\"}"));
+            (code, Zeroizing::new(json))
+        }
+    };
+    // Interior nul bytes cannot occur in mnemonics or serde_json output
+    let c_string = CString::new(text.as_bytes()).unwrap_or_default();
+    // SAFETY: the caller guarantees `out` is valid for writes
+    unsafe { *out = c_string.into_raw() };
+    code
+}
+
+/// Split a BIP39 mnemonic into Shamir Secret Shares
+///
+/// On success `*out` receives the split result JSON,
+/// `{"shares": [...], "share_count": N, "threshold": M}`.
+///
+/// # Safety
+/// `mnemonic` must be a valid null-terminated string and `out` must be valid for
+/// writes. The string written to `*out` must be released with [`shameless_free`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn shameless_split(
+    mnemonic: *const c_char,
+    shares: u8,
+    threshold: u8,
+    out: *mut *mut c_char,
+) -> i32 {
+    if out.is_null() {
+        return SHAMELESS_ERR_NULL;
+    }
+    // SAFETY: the caller upholds this function's contract
+    let result =
+        unsafe { read_str(mnemonic) }.and_then(|mnemonic| split(mnemonic, shares, threshold));
+    // SAFETY: `out` is non-null and the caller guarantees it is valid for writes
+    unsafe { write_out(out, result) }
+}
+
+fn split(mnemonic: &str, shares: u8, threshold: u8) -> Result<Zeroizing<String>, (i32, String)> {
+    let params_error = |e: anyhow::Error| (SHAMELESS_ERR_PARAMS, e.to_string());
+    let config = SplitConfig::new(
+        Threshold::new(threshold).map_err(params_error)?,
+        ShareCount::new(shares).map_err(params_error)?,
+    )
+    .map_err(params_error)?;
+    let share_mnemonics = commands::split_mnemonic(mnemonic, config)
+        .map_err(|e| (SHAMELESS_ERR_SPLIT, format!("Split failed: {e}")))?;
+    let result = SplitResult {
+        shares: share_mnemonics,
+        share_count: shares,
+        threshold,
+    };
+    serde_json::to_string(&result)
+        .map(Zeroizing::new)
+        .map_err(|e| (SHAMELESS_ERR_SPLIT, format!("Serialization failed: {e}")))
+}
+
+/// Combine Shamir Secret Shares to reconstruct the original mnemonic
+///
+/// On success `*out` receives the reconstructed mnemonic.
+///
+/// # Safety
+/// `shares` must point to `len` valid null-terminated strings (it may be null when `len`
+/// is 0) and `out` must be valid for writes. The string written to `*out` must be
+/// released with [`shameless_free`].
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn shameless_combine(
+    shares: *const *const c_char,
+    len: usize,
+    out: *mut *mut c_char,
+) -> i32 {
+    if out.is_null() {
+        return SHAMELESS_ERR_NULL;
+    }
+    let result = if shares.is_null() && len > 0 {
+        Err((SHAMELESS_ERR_NULL, "Null shares array".to_string()))
+    } else {
+        let pointers = if len == 0 {
+            &[][..]
+        } else {
+            // SAFETY: non-null, and the caller guarantees `len` readable pointers
+            unsafe { std::slice::from_raw_parts(shares, len) }
+        };
+        pointers
+            .iter()
+            // SAFETY: the caller guarantees each pointer is a valid string
+            .map(|&share| unsafe { read_str(share) })
+            .collect::<Result<Vec<_>, _>>()
+            .and_then(|shares| {
+                commands::combine_shares(&shares)
+                    .map_err(|e| (SHAMELESS_ERR_COMBINE, format!("Combine failed: {e}")))
+            })
+    };
+    // SAFETY: `out` is non-null and the caller guarantees it is valid for writes
+    unsafe { write_out(out, result) }
+}
+
+/// Release a string returned by a `shameless_*` function
+///
+/// The string is zeroed before it is freed, since it may hold a mnemonic. Passing null
+/// is a no-op.
+///
+/// # Safety
+/// `ptr` must be null or a string returned through `out` by this library that has not
+/// been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn shameless_free(ptr: *mut c_char) {
+    if ptr.is_null() {
+        return;
+    }
+    // SAFETY: the caller guarantees `ptr` came from `CString::into_raw` in this library
+    let c_string = unsafe { CString::from_raw(ptr) };
+    drop(Zeroizing::new(c_string.into_bytes()));
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    const MNEMONIC: &str =
+        "army van defense carry jealous true garbage claim echo media make crunch";
+
+    /// Takes ownership of a string written to `out`, freeing it through the FFI
+    fn take(ptr: *mut c_char) -> String {
+        assert!(!ptr.is_null());
+        // SAFETY: `ptr` was just written by the library and is not yet freed
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_owned();
+        // SAFETY: as above
+        unsafe { shameless_free(ptr) };
+        text
+    }
+
+    #[test]
+    fn test_split_and_combine_round_trip() {
+        let mnemonic = CString::new(MNEMONIC).unwrap();
+        let mut out = ptr::null_mut();
+        // SAFETY: valid string and out pointer
+        let code = unsafe { shameless_split(mnemonic.as_ptr(), 5, 3, &raw mut out) };
+        assert_eq!(code, SHAMELESS_OK);
+        let result: SplitResult = serde_json::from_str(&take(out)).unwrap();
+        assert_eq!((result.share_count, result.threshold), (5, 3));
+
+        let shares: Vec<CString> = result.shares[1..4]
+            .iter()
+            .map(|share| CString::new(share.as_str()).unwrap())
+            .collect();
+        let pointers: Vec<*const c_char> = shares.iter().map(|share| share.as_ptr()).collect();
+        let mut out = ptr::null_mut();
+        // SAFETY: `pointers` holds `len` valid strings
+        let code = unsafe { shameless_combine(pointers.as_ptr(), pointers.len(), &raw mut out) };
+        assert_eq!(code, SHAMELESS_OK);
+        assert_eq!(take(out), MNEMONIC);
+    }
+
+    #[test]
+    fn test_errors_are_reported_as_codes_and_json() {
+        let mnemonic = CString::new(MNEMONIC).unwrap();
+        let mut out = ptr::null_mut();
+        // SAFETY: valid string and out pointer
+        let code = unsafe { shameless_split(mnemonic.as_ptr(), 3, 5, &raw mut out) };
+        assert_eq!(code, SHAMELESS_ERR_PARAMS);
+        let error: serde_json::Value = serde_json::from_str(&take(out)).unwrap();
+        assert!(error["error"].as_str().unwrap().contains("Threshold"));
+
+        let mut out = ptr::null_mut();
+        // SAFETY: an empty share list may be null
+        let code = unsafe { shameless_combine(ptr::null(), 0, &raw mut out) };
+        assert_eq!(code, SHAMELESS_ERR_COMBINE);
+        take(out);
+
+        // SAFETY: a null out pointer is rejected before anything is written
+        let code = unsafe { shameless_split(mnemonic.as_ptr(), 5, 3, ptr::null_mut()) };
+        assert_eq!(code, SHAMELESS_ERR_NULL);
+        // SAFETY: null is a no-op
+        unsafe { shameless_free(ptr::null_mut()) };
+    }
+
+    #[test]
+    fn test_header_declares_every_export() {
+        // A cheap drift check for builds without cbindgen; CI runs `cbindgen --verify`
+        let header = include_str!("../include/shameless.h");
+        let source = include_str!("ffi.rs");
+
+        for line in source.lines() {
+            if let Some(rest) = line.strip_prefix("pub unsafe extern \"C\" fn ") {
+                let name = rest.split('(').next().unwrap();
+                assert!(header.contains(&format!(" {name}(")), "{name} missing");
+            }
+            if let Some(rest) = line.strip_prefix("pub const ") {
+                let (name, value) = rest.split_once(": i32 = ").unwrap();
+                let define = format!("#define {name} {}", value.trim_end_matches(';'));
+                assert!(header.contains(&define), "{define} missing");
+            }
+        }
+    }
+}
@@ -8,6 +8,13 @@ pub mod codec;
 pub mod commands;
 pub mod domain;
 
+#[cfg(feature = "tui")]
+pub mod tui;
+
+// C bindings for embedding in other languages
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 // WASM bindings (only for wasm32 target)
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
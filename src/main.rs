@@ -1,12 +1,113 @@
-use std::io::{self, BufRead};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
-use clap::Parser;
+use bip39::{Language, Mnemonic};
+use clap::{CommandFactory, Parser};
 use zeroize::Zeroizing;
 
-use shameless::cli::{Cli, Commands};
-use shameless::commands::{combine_shares, split_mnemonic};
-use shameless::shamir39::{ShareCount, SplitConfig};
+use shameless::cli::{
+    Cli, Commands, ErrorFormat, OutputTemplate, ReportFormat, SchemeSpec, SplitArgs,
+};
+use shameless::codec::{
+    CodecOptions, ShareEncoding, ShareMeta, WordRole, share_metadata_with_options, share_words,
+};
+use shameless::commands::{
+    CombineOptions, HealthReport, ShareCollector, ShareManifest, SplitOptions, bundle_shares,
+    combine_shares_with_options, combine_to_entropy_with_options, entropy_fingerprint,
+    expand_bundles, health_report_with_options, low_entropy_reason,
+    render_paper_share_with_options, rotate_shares_with_options, share_manifest, shares_from_json,
+    split_mnemonic_with_options,
+};
+use shameless::shamir39::{ShareCount, SplitConfig, Threshold};
+
+/// Environment variable consulted for the mnemonic when no file is given
+const MNEMONIC_ENV_VAR: &str = "SHAMELESS_MNEMONIC";
+
+/// Length of the seed BIP39 derives from a mnemonic and passphrase (512 bits)
+const BIP39_SEED_LEN: usize = 64;
+
+/// Trims a line of input, including the byte order mark and `\r` that files saved on
+/// Windows often carry
+fn clean_line(line: &str) -> &str {
+    line.trim_start_matches('\u{FEFF}').trim()
+}
+
+/// Read the mnemonic to split, in order of precedence: file, environment, stdin
+fn read_mnemonic_from_sources(mnemonic_file: Option<&Path>) -> Result<Zeroizing<String>> {
+    if let Some(path) = mnemonic_file {
+        let contents = Zeroizing::new(
+            std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read mnemonic file {}", path.display()))?,
+        );
+        return Ok(Zeroizing::new(clean_line(&contents).to_string()));
+    }
+
+    if let Some(value) = std::env::var_os(MNEMONIC_ENV_VAR) {
+        // SAFETY: called from the single-threaded CLI entry point, before any other
+        // threads exist that could read the environment concurrently
+        unsafe { std::env::remove_var(MNEMONIC_ENV_VAR) };
+
+        eprintln!(
+            "Warning: reading the mnemonic from {MNEMONIC_ENV_VAR}; environment variables may be visible to other processes (e.g. via /proc or process listings)"
+        );
+        let value = Zeroizing::new(
+            value
+                .into_string()
+                .map_err(|_| anyhow::anyhow!("{MNEMONIC_ENV_VAR} is not valid UTF-8"))?,
+        );
+        return Ok(Zeroizing::new(clean_line(&value).to_string()));
+    }
+
+    read_mnemonic().map(Zeroizing::new)
+}
+
+/// Decodes hex-encoded secret bytes given on the command line
+fn decode_hex_arg(flag: &str, hex: &str) -> Result<Zeroizing<Vec<u8>>> {
+    hex::decode(hex.trim())
+        .map(Zeroizing::new)
+        .with_context(|| format!("{flag} is not valid hex"))
+}
+
+/// Formats BIP39 entropy given as hex as a mnemonic in `language`
+///
+/// Entropy that is obviously not random (see [`low_entropy_reason`]) is refused unless
+/// `force_weak` is set.
+fn mnemonic_from_entropy_hex(
+    hex: &str,
+    language: Language,
+    force_weak: bool,
+) -> Result<Zeroizing<String>> {
+    let entropy = decode_hex_arg("--entropy-hex", hex)?;
+
+    // `Mnemonic::to_seed` output is easily mistaken for entropy, but there is no way back
+    // from a seed to the words, so splitting one could never yield a mnemonic again
+    if entropy.len() == BIP39_SEED_LEN {
+        anyhow::bail!(
+            "--entropy-hex got {BIP39_SEED_LEN} bytes: this looks like a BIP39 seed, not \
+             entropy. Combining its shares would not yield a word mnemonic; split the \
+             mnemonic itself, or its 16 to 32 bytes of entropy"
+        );
+    }
+
+    let mnemonic = Mnemonic::from_entropy_in(language, &entropy).with_context(|| {
+        format!(
+            "--entropy-hex must be 16, 20, 24, 28 or 32 bytes of BIP39 entropy, got {} bytes",
+            entropy.len()
+        )
+    })?;
+
+    if !force_weak && let Some(reason) = low_entropy_reason(&entropy) {
+        anyhow::bail!(
+            "--entropy-hex looks too regular to be random: {reason}; pass --force-weak to \
+             split it anyway"
+        );
+    }
+    Ok(Zeroizing::new(mnemonic.to_string()))
+}
 
 /// Read a mnemonic securely from stdin (hidden input when TTY available)
 fn read_mnemonic() -> Result<String> {
@@ -22,28 +123,53 @@ fn read_mnemonic() -> Result<String> {
         handle
             .read_line(&mut mnemonic)
             .context("Failed to read mnemonic from stdin")?;
-        Ok(mnemonic.trim().to_string())
+        Ok(clean_line(&mnemonic).to_string())
     }
 }
 
 /// Read shares securely from stdin (hidden input when TTY available)
-/// User should input shares one per line, followed by an empty line to finish
+/// User should input shares one per line, followed by an empty line to finish; piped
+/// input is read to the end instead, skipping blank lines
 fn read_shares() -> Result<Vec<String>> {
-    let mut shares = Vec::new();
+    Ok(read_labelled_shares()?
+        .into_iter()
+        .map(|labelled| labelled.share)
+        .collect())
+}
+
+/// A share read from stdin, with the comment that preceded it
+struct LabelledShare {
+    share: String,
+    label: Option<String>,
+}
+
+/// The text of a `#` comment line, e.g. `# Alice's share, stored in safe`
+fn comment_text(line: &str) -> Option<&str> {
+    line.strip_prefix('#').map(str::trim)
+}
+
+/// Read shares as [`read_shares`] does, keeping the last comment line before each one
+/// as its label
+///
+/// Lines starting with `#` are comments, so an annotated backup file can be piped in
+/// as it is. Every share a bundle holds gets the bundle's label.
+fn read_labelled_shares() -> Result<Vec<LabelledShare>> {
+    let mut lines = Vec::new();
 
     if atty::is(atty::Stream::Stdin) {
         // Interactive mode - use rpassword for hidden input
         eprintln!("Enter shameless shares (one per line, empty line to finish):");
 
         loop {
-            let share = rpassword::read_password().context("Failed to read share from stdin")?;
+            let line = rpassword::read_password().context("Failed to read share from stdin")?;
 
             // Empty line signals we're done
-            if share.trim().is_empty() {
+            let line = clean_line(&line);
+            if line.is_empty() {
                 break;
             }
 
-            shares.push(share.trim().to_string());
+            lines.push(line.to_string());
         }
     } else {
         // Non-interactive mode - read from stdin
@@ -52,70 +178,961 @@ fn read_shares() -> Result<Vec<String>> {
 
         for line in handle.lines() {
             let line = line.context("Failed to read line from stdin")?;
-            let trimmed = line.trim();
+            let trimmed = clean_line(&line);
 
-            // Empty line signals we're done
+            // Piped input is read to the end, so blank lines may separate shares
             if trimmed.is_empty() {
-                break;
+                continue;
             }
 
-            shares.push(trimmed.to_string());
+            lines.push(trimmed.to_string());
+        }
+    }
+
+    let mut shares = Vec::new();
+    let mut label = None;
+    for line in lines {
+        if let Some(comment) = comment_text(&line) {
+            label = Some(comment.to_string()).filter(|comment| !comment.is_empty());
+            continue;
+        }
+
+        // A bundle written by `split --bundle` stands for the shares it holds
+        let label = label.take();
+        for share in expand_bundles(&[line])? {
+            shares.push(LabelledShare {
+                share,
+                label: label.clone(),
+            });
         }
     }
 
     if shares.is_empty() {
         anyhow::bail!("No shares provided");
     }
-
     Ok(shares)
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+/// Read the shares of a JSON split result (as returned by `wasm_split`) from stdin
+fn read_shares_json() -> Result<Vec<String>> {
+    let mut json = Zeroizing::new(String::new());
+    io::stdin()
+        .lock()
+        .read_to_string(&mut json)
+        .context("Failed to read JSON from stdin")?;
+    shares_from_json(&json)
+}
 
-    match cli.command {
-        Commands::Split { shares, threshold } => {
-            // Read mnemonic securely from stdin
-            let mnemonic = Zeroizing::new(read_mnemonic()?);
-
-            // Validate share count and create config
-            let share_count = ShareCount::new(shares)?;
-            let config = SplitConfig::new(threshold, share_count)?;
-
-            // Calculate entropy size from word count for info message
-            // 12 words = 16 bytes (128 bits), 24 words = 32 bytes (256 bits)
-            let word_count = mnemonic.split_whitespace().count();
-            let entropy_bytes = if word_count == 12 { 16 } else { 32 };
-
-            // Split the mnemonic and get the shares
-            let share_mnemonics = split_mnemonic(&mnemonic, config)?;
-
-            // Print informational header
-            let threshold_val = *threshold;
-            println!("Original mnemonic entropy: {entropy_bytes} bytes");
-            println!("\nCreated {shares} shares (threshold: {threshold_val})");
-            println!("You need at least {threshold_val} shares to reconstruct the secret.\n");
-
-            // Print each share
-            for (idx, share) in share_mnemonics.iter().enumerate() {
-                println!("Share #{}:", idx + 1);
-                println!("{share}");
-                println!();
+/// Read shares from stdin, checking each one as it is typed when stdin is a TTY
+///
+/// Every share is parsed on entry and reported as accepted or rejected; rejected shares
+/// are not kept, so they can simply be re-entered. Once the threshold of the first
+/// accepted share is reached, entry stops unless the user asks to add more. Piped input
+/// is read as by [`read_shares`].
+fn read_shares_guided(language: Language) -> Result<Vec<String>> {
+    if !atty::is(atty::Stream::Stdin) {
+        return read_shares();
+    }
+
+    let mut collector = ShareCollector::with_options(CodecOptions {
+        language,
+        ..CodecOptions::default()
+    });
+    eprintln!("Enter shameless shares (one per line, empty line to finish):");
+
+    loop {
+        let line = rpassword::read_password().context("Failed to read share from stdin")?;
+        let line = clean_line(&line);
+        if line.is_empty() {
+            break;
+        }
+        if comment_text(line).is_some() {
+            continue;
+        }
+
+        for share in expand_bundles(&[line.to_string()])? {
+            match collector.add(&share) {
+                Ok(index) => {
+                    let threshold = collector.threshold().map_or(0, |t| *t);
+                    eprintln!(
+                        "\u{2713} share index {index} accepted ({}/{threshold})",
+                        collector.shares().len()
+                    );
+                }
+                Err(e) => eprintln!("\u{2717} invalid share, not counted \u{2014} re-enter ({e})"),
             }
         }
-        Commands::Combine => {
-            // Read shares securely from stdin
-            let shares = read_shares()?;
 
-            // Print progress information
-            println!("Parsing {} share(s)...", shares.len());
+        match collector.remaining() {
+            Some(0) => {
+                if !confirm("Threshold reached. Enter more shares?")? {
+                    break;
+                }
+                eprintln!("Enter another share (empty line to finish):");
+            }
+            Some(more) => eprintln!("{more} more share(s) needed:"),
+            None => {}
+        }
+    }
+
+    if collector.shares().is_empty() {
+        anyhow::bail!("No shares provided");
+    }
 
-            // Combine the shares and get the recovered mnemonic
-            let recovered_mnemonic = combine_shares(&shares)?;
+    Ok(collector.shares().iter().map(ToString::to_string).collect())
+}
+
+/// Ask the user a yes/no question, defaulting to "no"
+///
+/// The answer is read from stdin when it is a TTY, otherwise from the controlling
+/// terminal (stdin may already be carrying piped secret input).
+fn confirm(prompt: &str) -> Result<bool> {
+    eprint!("{prompt} [y/N] ");
+    io::stderr().flush().context("Failed to flush stderr")?;
+
+    let mut answer = String::new();
+    if atty::is(atty::Stream::Stdin) {
+        io::stdin()
+            .lock()
+            .read_line(&mut answer)
+            .context("Failed to read confirmation from stdin")?;
+    } else {
+        let tty = File::open("/dev/tty")
+            .context("Cannot prompt for confirmation without a terminal (use --yes to skip)")?;
+        BufReader::new(tty)
+            .read_line(&mut answer)
+            .context("Failed to read confirmation from terminal")?;
+    }
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Guard against dumping shares into terminal scrollback or session logs
+fn confirm_printing_shares(yes: bool) -> Result<()> {
+    if !yes
+        && atty::is(atty::Stream::Stdout)
+        && !confirm("Shares will be printed to your terminal. Continue?")?
+    {
+        anyhow::bail!("Aborted: shares were not printed");
+    }
+    Ok(())
+}
+
+/// Print each share under a numbered heading, with its fingerprint when given
+fn print_shares(share_mnemonics: &[String], fingerprints: &[String]) {
+    for (idx, share) in share_mnemonics.iter().enumerate() {
+        match fingerprints.get(idx) {
+            Some(fingerprint) => println!("Share #{} (fingerprint {fingerprint}):", idx + 1),
+            None => println!("Share #{}:", idx + 1),
+        }
+        println!("{share}");
+        println!();
+    }
+}
+
+/// Write each share to its own file under `dir`, named by `template`
+///
+/// Files are created exclusively (an existing file is never overwritten) and, on Unix,
+/// readable only by the owner.
+fn write_share_files(
+    dir: &Path,
+    template: &OutputTemplate,
+    share_mnemonics: &[String],
+    fingerprints: &[String],
+    config: SplitConfig,
+) -> Result<()> {
+    for (index, share) in config.share_count().iter_indices().zip(share_mnemonics) {
+        let path = dir.join(template.render(index, config.threshold(), config.share_count()));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+        }
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options
+            .open(&path)
+            .with_context(|| format!("Failed to create share file {}", path.display()))?;
+        writeln!(file, "{share}")
+            .with_context(|| format!("Failed to write share file {}", path.display()))?;
+
+        match fingerprints.get(usize::from(*index)) {
+            Some(fingerprint) => println!(
+                "Wrote share #{} to {} (fingerprint {fingerprint})",
+                *index + 1,
+                path.display()
+            ),
+            None => println!("Wrote share #{} to {}", *index + 1, path.display()),
+        }
+    }
+    Ok(())
+}
+
+/// Write each share as a printable HTML page named `share-{index}.html` in `dir`
+fn write_paper_shares(dir: &Path, share_mnemonics: &[String], language: Language) -> Result<()> {
+    fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+
+    let options = CodecOptions {
+        language,
+        ..CodecOptions::default()
+    };
+    for (position, share) in share_mnemonics.iter().enumerate() {
+        let page = render_paper_share_with_options(share, &options)?;
+        let path = dir.join(format!("share-{position}.html"));
+        write_secret_file(&path, &page, false)?;
+        println!(
+            "Wrote printable share #{} to {}",
+            position + 1,
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// Write a recovered secret to `path`, readable only by the owner on Unix
+///
+/// An existing file is only replaced when `force` is set. The file is synced to disk
+/// before returning, so the secret is not lost if the machine goes down right after.
+fn write_secret_file(path: &Path, secret: &str, force: bool) -> Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true);
+    if force {
+        options.create(true).truncate(true);
+    } else {
+        options.create_new(true);
+    }
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path).with_context(|| {
+        if path.exists() && !force {
+            format!(
+                "Refusing to overwrite {} (use --force to replace it)",
+                path.display()
+            )
+        } else {
+            format!("Failed to create output file {}", path.display())
+        }
+    })?;
+
+    // The mode above only applies to newly created files
+    #[cfg(unix)]
+    fs::set_permissions(path, std::os::unix::fs::PermissionsExt::from_mode(0o600))
+        .with_context(|| format!("Failed to restrict permissions of {}", path.display()))?;
+
+    // Assemble the line in a zeroized buffer rather than a temporary `format!` string
+    let mut contents = Zeroizing::new(String::with_capacity(secret.len() + 1));
+    contents.push_str(secret);
+    contents.push('\n');
+
+    file.write_all(contents.as_bytes())
+        .and_then(|()| file.sync_all())
+        .with_context(|| format!("Failed to write output file {}", path.display()))
+}
+
+/// Current time in seconds since the Unix epoch, for share manifests
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Write a share manifest as pretty-printed JSON
+fn write_manifest(path: &Path, manifest: &ShareManifest) -> Result<()> {
+    let json =
+        serde_json::to_string_pretty(manifest).context("Failed to serialize the manifest")?;
+    fs::write(path, json + "\n")
+        .with_context(|| format!("Failed to write manifest {}", path.display()))?;
+    eprintln!("Wrote share manifest to {}", path.display());
+    Ok(())
+}
+
+/// Read a share manifest written by `split --manifest`
+fn read_manifest(path: &Path) -> Result<ShareManifest> {
+    let json = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read manifest {}", path.display()))?;
+    serde_json::from_str(&json)
+        .with_context(|| format!("{} is not a valid share manifest", path.display()))
+}
+
+/// Combine shares from stdin and print the recovered mnemonic, or write it to
+/// `output_file`
+fn combine(
+    options: CombineOptions,
+    shares: &[String],
+    check: bool,
+    output_file: Option<&Path>,
+    force: bool,
+    manifest: Option<&ShareManifest>,
+    require_all_shares: bool,
+) -> Result<()> {
+    // Print progress information, keeping stdout empty when writing to a file
+    if output_file.is_none() {
+        println!("Parsing {} share(s)...", shares.len());
+    }
+
+    // Combine the shares and get the recovered mnemonic
+    let recovered = combine_shares_with_options(shares, &options)?;
+
+    for warning in &recovered.warnings {
+        eprintln!("Warning: {warning}");
+    }
+    report_paranoid_verify(options);
+    if recovered.passphrase_reminder {
+        eprintln!(
+            "Reminder: these shares are marked as belonging to a wallet that also uses a \
+             BIP39 passphrase; the mnemonic alone restores a different, empty wallet"
+        );
+    }
+
+    // Refuse to hand out a secret that doesn't match the recorded share set
+    if let Some(manifest) = manifest {
+        manifest.verify(&recovered)?;
+        eprintln!("Shares match the {} manifest", manifest.scheme);
+    }
+
+    // Shares missing now may be lost for good, so say so even when recovery works
+    match recovered.missing_shares_message() {
+        Some(message) if require_all_shares => {
+            anyhow::bail!("Refusing to output the mnemonic: {message}")
+        }
+        Some(message) => {
+            eprintln!("Warning: {message}; consider `rotate` to deal a fresh set of shares");
+        }
+        None if require_all_shares && recovered.share_count.is_none() => anyhow::bail!(
+            "--require-all-shares needs shares that record how many were dealt, and these do not"
+        ),
+        None => {}
+    }
+
+    if check {
+        // Dry run: confirm recoverability without revealing the secret
+        println!(
+            "OK: shares reconstruct a valid {}-word mnemonic (fingerprint: {})",
+            recovered.word_count, recovered.fingerprint
+        );
+        return Ok(());
+    }
+
+    if let Some(path) = output_file {
+        write_secret_file(path, &recovered.mnemonic, force)?;
+        eprintln!(
+            "Wrote recovered {}-word mnemonic to {}",
+            recovered.word_count,
+            path.display()
+        );
+        return Ok(());
+    }
+
+    // Print success message, with the size so a wrong one stands out
+    println!(
+        "\nSuccessfully reconstructed {}-word mnemonic ({} bytes of entropy):",
+        recovered.word_count, recovered.entropy_len
+    );
+    println!("{}", *recovered.mnemonic);
+
+    Ok(())
+}
+
+/// Confirm a `--paranoid-verify` check, which fails the combine if it does not pass
+fn report_paranoid_verify(options: CombineOptions) {
+    if options.paranoid_verify {
+        eprintln!("verified: recovered secret regenerates the provided shares");
+    }
+}
+
+/// Combine shares from stdin and print or write the secret as hex
+fn combine_raw(
+    options: CombineOptions,
+    shares: &[String],
+    output_file: Option<&Path>,
+    force: bool,
+) -> Result<()> {
+    let (entropy, warnings) = combine_to_entropy_with_options(shares, &options)?;
+
+    for warning in &warnings {
+        eprintln!("Warning: {warning}");
+    }
+    report_paranoid_verify(options);
 
-            // Print success message
-            println!("\nSuccessfully reconstructed mnemonic:");
-            println!("{recovered_mnemonic}");
+    let secret = Zeroizing::new(hex::encode(&*entropy));
+    if let Some(path) = output_file {
+        write_secret_file(path, &secret, force)?;
+        eprintln!("Wrote recovered secret to {}", path.display());
+        return Ok(());
+    }
+
+    println!("Recovered {} byte secret (hex):", entropy.len());
+    println!("{}", *secret);
+
+    Ok(())
+}
+
+/// Combine shares from stdin and check the secret against `expected_hex`, printing
+/// neither
+fn combine_assert_entropy(
+    options: CombineOptions,
+    shares: &[String],
+    expected: &[u8],
+) -> Result<()> {
+    let (entropy, warnings) = combine_to_entropy_with_options(shares, &options)?;
+
+    for warning in &warnings {
+        eprintln!("Warning: {warning}");
+    }
+    report_paranoid_verify(options);
+
+    // Fingerprints identify the secrets without revealing them
+    if *entropy != expected {
+        anyhow::bail!(
+            "Recovered secret (fingerprint {}) does not match the expected secret (fingerprint {})",
+            entropy_fingerprint(&entropy),
+            entropy_fingerprint(expected)
+        );
+    }
+
+    println!(
+        "OK: shares reconstruct the expected secret (fingerprint: {})",
+        entropy_fingerprint(&entropy)
+    );
+    Ok(())
+}
+
+/// Diagnose shares from stdin and print the report in `format`
+fn health(format: ReportFormat, language: Language) -> Result<()> {
+    let shares = read_shares()?;
+    let options = CodecOptions {
+        language,
+        ..CodecOptions::default()
+    };
+    let report = health_report_with_options(&shares, &options);
+
+    match format {
+        ReportFormat::Text => print_health_report(&report),
+        ReportFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&report)
+                .context("Failed to serialize the health report")?
+        ),
+    }
+    Ok(())
+}
+
+/// Print a share health report in human-readable form
+fn print_health_report(report: &HealthReport) {
+    println!(
+        "Shares: {} given, {} passed their checksum",
+        report.total, report.passed_checksum
+    );
+    match report.threshold {
+        Some(threshold) if report.threshold_agreed => println!("Threshold: {threshold}"),
+        Some(threshold) => println!("Threshold: {threshold} (shares disagree)"),
+        None => println!("Threshold: unknown"),
+    }
+    let indices: Vec<String> = report
+        .distinct_indices
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    println!("Distinct indices: {}", indices.join(", "));
+    println!("Quorum: {}", if report.quorum { "yes" } else { "no" });
+    if let Some(share_count) = report.share_count {
+        let missing = usize::from(*share_count).saturating_sub(report.distinct_indices.len());
+        println!("Share count: {share_count} dealt, {missing} missing");
+    }
+
+    if !report.problems.is_empty() {
+        println!("\nProblems:");
+        for problem in &report.problems {
+            match problem.position {
+                Some(position) => println!("  share #{position}: {}", problem.message),
+                None => println!("  {}", problem.message),
+            }
+        }
+    }
+}
+
+/// Print a table of the words of each share from stdin, with their wordlist indices
+fn list_words(language: Language) -> Result<()> {
+    let shares = read_shares()?;
+    let options = CodecOptions {
+        language,
+        ..CodecOptions::default()
+    };
+
+    for (position, share) in shares.iter().enumerate() {
+        let words = share_words(share, &options)
+            .with_context(|| format!("Failed to list the words of share #{}", position + 1))?;
+
+        println!("Share #{}:", position + 1);
+        println!("  {:>3}  {:<12} {:>5}  role", "pos", "word", "index");
+        for word in words {
+            let index = word
+                .index
+                .map_or_else(|| "-".to_string(), |index| index.to_string());
+            let role = match word.role {
+                WordRole::Version => "version",
+                WordRole::Parameter => "parameter",
+                WordRole::Data => "data",
+            };
+            let note = if word.index.is_none() && word.role != WordRole::Version {
+                " (not in the wordlist)"
+            } else {
+                ""
+            };
+            println!(
+                "  {:>3}  {:<12} {index:>5}  {role}{note}",
+                word.position, word.word
+            );
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Fully validate each share and describe it, naming the first that fails
+fn share_metas(shares: &[String], language: Language) -> Result<Vec<ShareMeta>> {
+    let options = CodecOptions {
+        language,
+        ..CodecOptions::default()
+    };
+    shares
+        .iter()
+        .enumerate()
+        .map(|(position, share)| {
+            share_metadata_with_options(share, &options)
+                .with_context(|| format!("Failed to parse share #{}", position + 1))
+        })
+        .collect()
+}
+
+/// Print the index and fingerprint of each share from stdin
+///
+/// A share preceded by a `#` comment line is printed with the comment as its label.
+fn fingerprint(language: Language) -> Result<()> {
+    let (shares, labels): (Vec<String>, Vec<Option<String>>) = read_labelled_shares()?
+        .into_iter()
+        .map(|labelled| (labelled.share, labelled.label))
+        .unzip();
+    for (position, (meta, label)) in share_metas(&shares, language)?
+        .iter()
+        .zip(labels)
+        .enumerate()
+    {
+        let label = label.map_or_else(String::new, |label| format!("  # {label}"));
+        println!(
+            "Share #{} (index {}): fingerprint {}{label}",
+            position + 1,
+            meta.index,
+            meta.fingerprint_hex()
+        );
+    }
+    Ok(())
+}
+
+/// Validate the split parameters, given either as `--scheme` or as `--threshold` and
+/// `--shares`
+fn split_config(
+    scheme: Option<SchemeSpec>,
+    shares: Option<u8>,
+    threshold: Option<Threshold>,
+) -> Result<SplitConfig> {
+    match (scheme, shares, threshold) {
+        (Some(scheme), _, _) => Ok(scheme.config()),
+        (None, Some(shares), Some(threshold)) => {
+            SplitConfig::new(threshold, ShareCount::new(shares)?)
+        }
+        _ => unreachable!("clap enforces --shares and --threshold without --scheme"),
+    }
+}
+
+/// Route `log` output to stderr, at the level chosen by `--verbose` or else `RUST_LOG`
+fn init_logging(verbose: u8) {
+    let mut builder =
+        env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"));
+    match verbose {
+        0 => {}
+        1 => {
+            builder.filter_level(log::LevelFilter::Info);
+        }
+        _ => {
+            builder.filter_level(log::LevelFilter::Debug);
+        }
+    }
+    builder.format_timestamp(None).init();
+}
+
+/// Split the mnemonic from a file, the environment or stdin and print or write the shares
+fn split(args: SplitArgs, language: Language, quiet: bool) -> Result<()> {
+    let SplitArgs {
+        shares,
+        threshold,
+        scheme,
+        min_threshold,
+        encoding,
+        strict_spec,
+        pad_to,
+        with_passphrase_reminder,
+        no_share_count,
+        mnemonic_file,
+        entropy_hex,
+        force_weak,
+        output_dir,
+        output_template,
+        manifest,
+        bundle,
+        paper,
+        show_fingerprints,
+        yes,
+    } = args;
+
+    let config = split_config(scheme, shares, threshold)?;
+
+    // Enforce caller-imposed threshold policy before touching the secret
+    if let Some(min_threshold) = min_threshold {
+        config.enforce_min_threshold(min_threshold)?;
+    }
+
+    if !quiet {
+        for warning in config.warnings() {
+            eprintln!("Warning: {warning}");
+        }
+    }
+
+    let encoding = if strict_spec {
+        eprintln!(
+            "Warning: --strict-spec shares carry no length prefix or checksum; a mistyped word is only caught by the recovered mnemonic's BIP39 checksum, if at all"
+        );
+        ShareEncoding::StrictSpec
+    } else {
+        encoding
+    };
+
+    // Shares written to files never reach the terminal
+    if output_dir.is_none() {
+        confirm_printing_shares(yes)?;
+    }
+
+    // Read mnemonic securely from a file, the environment, or stdin, unless given
+    // entropy directly
+    let mnemonic = match entropy_hex {
+        Some(hex) => mnemonic_from_entropy_hex(&hex, language, force_weak)?,
+        None => read_mnemonic_from_sources(mnemonic_file.as_deref())?,
+    };
+
+    // Calculate entropy size from word count for info message: every 3 words hold
+    // 32 bits of entropy and 1 checksum bit (12 words = 16 bytes, 24 words = 32 bytes)
+    let word_count = mnemonic.split_whitespace().count();
+    let entropy_bytes = word_count * 4 / 3;
+
+    // Split the mnemonic and get the shares
+    let options = SplitOptions {
+        encoding,
+        pad_to: pad_to.map(usize::from),
+        language,
+        passphrase_reminder: with_passphrase_reminder,
+        omit_share_count: no_share_count,
+        parity_shares: 0,
+    };
+    let share_mnemonics = split_mnemonic_with_options(&mnemonic, config, &options)?;
+
+    // Print informational header
+    let (threshold, shares) = (config.threshold(), config.share_count());
+    println!("Original mnemonic entropy: {entropy_bytes} bytes");
+    println!("\nCreated {shares} shares (threshold: {threshold})");
+    println!("You need at least {threshold} shares to reconstruct the secret.");
+    if with_passphrase_reminder {
+        println!("Shares are marked as needing a BIP39 passphrase; store it separately.");
+    }
+    println!();
+
+    if let Some(path) = &manifest {
+        let manifest = share_manifest(&mnemonic, &share_mnemonics, config, &options, unix_now())?;
+        write_manifest(path, &manifest)?;
+    }
+
+    if let Some(path) = &bundle {
+        write_secret_file(path, &bundle_shares(&share_mnemonics)?, false)?;
+        eprintln!(
+            "Warning: {} holds every share; anyone who reads it can recover the secret, so \
+             keep it as safe as the mnemonic itself",
+            path.display()
+        );
+    }
+
+    if let Some(dir) = &paper {
+        write_paper_shares(dir, &share_mnemonics, language)?;
+    }
+
+    let fingerprints = if show_fingerprints {
+        share_metas(&share_mnemonics, language)?
+            .iter()
+            .map(ShareMeta::fingerprint_hex)
+            .collect()
+    } else {
+        Vec::new()
+    };
+    match output_dir {
+        Some(dir) => write_share_files(
+            &dir,
+            &output_template.unwrap_or_default(),
+            &share_mnemonics,
+            &fingerprints,
+            config,
+        )?,
+        None => print_shares(&share_mnemonics, &fingerprints),
+    }
+
+    Ok(())
+}
+
+/// Splits the mnemonic from the environment or stdin into a recovery kit in `dir`
+///
+/// The kit holds `share-{index}.html` for every share (see [`write_paper_shares`]),
+/// `manifest.json` and `README.txt`. Nothing secret reaches the terminal.
+fn kit(
+    shares: u8,
+    threshold: Threshold,
+    dir: &Path,
+    language: Language,
+    quiet: bool,
+) -> Result<()> {
+    let config = SplitConfig::new(threshold, ShareCount::new(shares)?)?;
+    if !quiet {
+        for warning in config.warnings() {
+            eprintln!("Warning: {warning}");
+        }
+    }
+
+    // Check the directory before touching the secret, so a mistyped path costs nothing
+    if dir.exists() {
+        let mut entries = fs::read_dir(dir)
+            .with_context(|| format!("{} is not a usable directory", dir.display()))?;
+        if entries.next().is_some() {
+            anyhow::bail!(
+                "Refusing to write a recovery kit into {}: it is not empty",
+                dir.display()
+            );
+        }
+    }
+
+    let mnemonic = read_mnemonic_from_sources(None)?;
+    let options = SplitOptions {
+        language,
+        ..SplitOptions::default()
+    };
+    let share_mnemonics = split_mnemonic_with_options(&mnemonic, config, &options)?;
+    let manifest = share_manifest(&mnemonic, &share_mnemonics, config, &options, unix_now())?;
+
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+    builder
+        .create(dir)
+        .with_context(|| format!("Failed to create directory {}", dir.display()))?;
+
+    write_paper_shares(dir, &share_mnemonics, language)?;
+    write_manifest(&dir.join("manifest.json"), &manifest)?;
+    let readme = dir.join("README.txt");
+    fs::write(&readme, kit_readme(&manifest))
+        .with_context(|| format!("Failed to write {}", readme.display()))?;
+
+    println!(
+        "Wrote a {} recovery kit to {}. Print each share-N.html, hand the pages to \
+         separate custodians, then delete them from this computer.",
+        manifest.scheme,
+        dir.display()
+    );
+    Ok(())
+}
+
+/// Recovery instructions for a kit, naming its scheme and secret fingerprint
+fn kit_readme(manifest: &ShareManifest) -> String {
+    let ShareManifest {
+        scheme,
+        share_count,
+        threshold,
+        fingerprint,
+        ..
+    } = manifest;
+    let last = share_count - 1;
+    format!(
+        "SHAMELESS RECOVERY KIT ({scheme})
+
+This kit holds {share_count} shares of a BIP39 mnemonic, split with shameless.
+Any {threshold} of them recover the mnemonic; fewer reveal nothing about it.
+
+Files:
+  share-0.html .. share-{last}.html  One printable share each, with its words and a QR code
+  manifest.json  The scheme and a fingerprint of the secret; holds no share data
+  README.txt     This file
+
+Before storing the kit:
+  1. Print each share page and give each to a different custodian or location.
+  2. Delete the share pages from this computer.
+  3. Keep a copy of manifest.json and this README with every share; they are
+     not secret.
+
+To recover:
+  1. Gather at least {threshold} shares.
+  2. Run: shameless combine --manifest manifest.json
+  3. Enter each share's words as one line (or scan its QR code), then an
+     empty line.
+  The manifest confirms the mnemonic is the one these shares were dealt from
+  (fingerprint {fingerprint}).
+"
+    )
+}
+
+/// Re-splits the secret behind the shares read from stdin and prints the new shares
+fn rotate(shares: u8, threshold: Threshold, yes: bool, language: Language) -> Result<()> {
+    let config = SplitConfig::new(threshold, ShareCount::new(shares)?)?;
+
+    confirm_printing_shares(yes)?;
+
+    // Read the existing shares; the secret they protect is never printed
+    let old_shares = read_shares_guided(language)?;
+    let options = SplitOptions {
+        language,
+        ..SplitOptions::default()
+    };
+    let share_mnemonics = rotate_shares_with_options(&old_shares, config, &options)?;
+
+    println!(
+        "Rotated {} share(s) into {shares} new shares (threshold: {threshold})",
+        old_shares.len()
+    );
+    println!("You need at least {threshold} new shares to reconstruct the secret.");
+    println!("The old shares remain valid; destroy them once the new ones are distributed.\n");
+
+    print_shares(&share_mnemonics, &[]);
+
+    Ok(())
+}
+
+/// Print the error that ended the command to stderr, in the format chosen by
+/// `--error-format`
+///
+/// Text matches what returning the error from `main` would print. JSON lists every
+/// message in `chain`, outermost first, so `chain[0]` repeats `error`.
+fn report_error(error: &anyhow::Error, format: ErrorFormat) {
+    match format {
+        ErrorFormat::Text => eprintln!("Error: {error:?}"),
+        ErrorFormat::Json => {
+            let report = serde_json::json!({
+                "error": error.to_string(),
+                "chain": error.chain().map(ToString::to_string).collect::<Vec<_>>(),
+            });
+            eprintln!("{report}");
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let error_format = cli.error_format;
+
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            report_error(&error, error_format);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Run the command chosen on the command line
+fn run(cli: Cli) -> Result<()> {
+    init_logging(cli.verbose);
+    let language = cli.language.into();
+
+    match cli.command {
+        Commands::Split(args) => split(args, language, cli.quiet)?,
+        Commands::Rotate {
+            shares,
+            threshold,
+            yes,
+        } => rotate(shares, threshold, yes, language)?,
+        Commands::Kit {
+            shares,
+            threshold,
+            output_dir,
+        } => kit(shares, threshold, &output_dir, language, cli.quiet)?,
+        Commands::Combine {
+            lenient,
+            robust,
+            expect_threshold,
+            dedup,
+            manifest,
+            check,
+            raw,
+            assert_entropy_hex,
+            interactive,
+            output_file,
+            force,
+            require_all_shares,
+            paranoid_verify,
+            json,
+        } => {
+            let options = CombineOptions {
+                lenient,
+                robust,
+                expect_threshold,
+                language,
+                dedup,
+                paranoid_verify,
+            };
+            let manifest = manifest.as_deref().map(read_manifest).transpose()?;
+            if interactive {
+                #[cfg(feature = "tui")]
+                return shameless::tui::combine_interactive(&options);
+                #[cfg(not(feature = "tui"))]
+                anyhow::bail!(
+                    "--interactive requires shameless to be built with the `tui` feature"
+                );
+            }
+            let expected = assert_entropy_hex
+                .as_deref()
+                .map(|hex| decode_hex_arg("--assert-entropy-hex", hex))
+                .transpose()?;
+
+            // Read shares securely from stdin; lenient, robust and raw combining need the
+            // rejects too
+            let shares = if json {
+                read_shares_json()?
+            } else if lenient || robust || raw || expected.is_some() {
+                read_shares()?
+            } else {
+                read_shares_guided(language)?
+            };
+
+            if raw {
+                return combine_raw(options, &shares, output_file.as_deref(), force);
+            }
+            if let Some(expected) = expected {
+                return combine_assert_entropy(options, &shares, &expected);
+            }
+            combine(
+                options,
+                &shares,
+                check,
+                output_file.as_deref(),
+                force,
+                manifest.as_ref(),
+                require_all_shares,
+            )?;
+        }
+        Commands::Health { format } => health(format, language)?,
+        Commands::Words => list_words(language)?,
+        Commands::Fingerprint => fingerprint(language)?,
+        Commands::Completion { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "shameless", &mut io::stdout());
         }
     }
 
@@ -0,0 +1,102 @@
+//! Interactive terminal UI for combining shares
+//!
+//! Aimed at trustees who are not comfortable with the line-by-line stdin prompt: each
+//! pasted share is validated immediately, bad shares are rejected inline so they can be
+//! re-entered, and the recovered secret is only shown on a final screen that is cleared
+//! on the next key press.
+
+use anyhow::{Context, Result, bail};
+use console::Term;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Password, Select};
+use zeroize::Zeroizing;
+
+use crate::codec::CodecOptions;
+pub use crate::commands::ShareCollector;
+use crate::commands::{CombineOptions, combine_shares_with_options};
+
+/// Runs the interactive combine flow on the terminal attached to stderr
+///
+/// # Errors
+/// Returns an error if the terminal cannot be used or the shares fail to combine
+pub fn combine_interactive(options: &CombineOptions) -> Result<()> {
+    let term = Term::stderr();
+    if !term.is_term() {
+        bail!("--interactive requires a terminal");
+    }
+
+    let theme = ColorfulTheme::default();
+    let mut collector = ShareCollector::with_options(CodecOptions {
+        language: options.language,
+        ..CodecOptions::default()
+    });
+
+    term.write_line("Paste each share and press Enter. Input is hidden.")?;
+    loop {
+        let status = match collector.remaining() {
+            None => "need the first share".to_string(),
+            Some(0) => "threshold met".to_string(),
+            Some(remaining) => format!("need {remaining} more"),
+        };
+
+        if collector.remaining() == Some(0) {
+            let choice = Select::with_theme(&theme)
+                .with_prompt(format!(
+                    "{} share(s) entered, {status}",
+                    collector.shares().len()
+                ))
+                .items(&["Reconstruct", "Add another share"])
+                .default(0)
+                .interact_on(&term)
+                .context("Failed to read menu selection")?;
+            if choice == 0 {
+                break;
+            }
+        }
+
+        let share = Zeroizing::new(
+            Password::with_theme(&theme)
+                .with_prompt(format!(
+                    "Share #{} ({status})",
+                    collector.shares().len() + 1
+                ))
+                .allow_empty_password(true)
+                .interact_on(&term)
+                .context("Failed to read share")?,
+        );
+        if share.trim().is_empty() {
+            continue;
+        }
+
+        match collector.add(&share) {
+            Ok(index) => term.write_line(&format!(
+                "✓ Share accepted (index {index}, threshold {})",
+                collector.threshold().map_or(0, |threshold| *threshold)
+            ))?,
+            Err(e) => term.write_line(&format!("✗ Share rejected: {e:#}"))?,
+        }
+    }
+
+    let recovered = combine_shares_with_options(collector.shares(), options)?;
+
+    term.clear_screen()?;
+    for warning in &recovered.warnings {
+        term.write_line(&format!("Warning: {warning}"))?;
+    }
+    if recovered.passphrase_reminder {
+        term.write_line("Reminder: this wallet also uses a BIP39 passphrase.")?;
+    }
+    if let Some(message) = recovered.missing_shares_message() {
+        term.write_line(&format!("Note: {message}."))?;
+    }
+    term.write_line(&format!(
+        "Successfully reconstructed {}-word mnemonic:\n",
+        recovered.word_count
+    ))?;
+    term.write_line(&recovered.mnemonic)?;
+    term.write_line("\nPress any key to clear the screen.")?;
+    term.read_key().context("Failed to read key")?;
+    term.clear_screen()?;
+
+    Ok(())
+}
@@ -0,0 +1,1200 @@
+//! WASM bindings for shameless
+//!
+//! This module provides JavaScript-friendly bindings for the core split/combine functionality.
+//! The JSON documents they return are defined in [`types`], and [`wasm_schema`] describes
+//! them as a JSON Schema.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bip39::{Language, Mnemonic};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+use crate::codec;
+use crate::commands;
+use crate::domain::{ShareCount, ShareIndex, SplitConfig, Threshold};
+
+pub mod types;
+
+pub use types::{
+    CollectorStatus, CombineReadiness, InvalidShare, NextShareHint, QrShare, ShareMetadata,
+    SplitResult,
+};
+
+/// Initialize panic hook for better error messages in the browser console
+#[wasm_bindgen(start)]
+pub fn init() {
+    console_error_panic_hook::set_once();
+}
+
+/// Use wee_alloc as the global allocator for smaller WASM binary size
+#[cfg(target_arch = "wasm32")]
+#[global_allocator]
+static ALLOC: wee_alloc::WeeAlloc = wee_alloc::WeeAlloc::INIT;
+
+/// Split a BIP39 mnemonic into Shamir Secret Shares
+///
+/// # Arguments
+/// * `mnemonic` - The BIP39 mnemonic to split (12 or 24 words)
+/// * `shares` - Total number of shares to create (2-255)
+/// * `threshold` - Minimum number of shares needed to reconstruct (2-shares)
+///
+/// # Returns
+/// JSON string containing the shares and metadata, or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const result = wasm_split(
+///     "army van defense carry jealous true garbage claim echo media make crunch",
+///     5,
+///     3
+/// );
+/// const data = JSON.parse(result);
+/// console.log(`Created ${data.share_count} shares with threshold ${data.threshold}`);
+/// for (let i = 0; i < data.shares.length; i++) {
+///     console.log(`Share ${i+1}: ${data.shares[i]}`);
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn wasm_split(mnemonic: &str, shares: u8, threshold: u8) -> Result<String, JsValue> {
+    let share_mnemonics = split_checked(mnemonic, shares, threshold)?;
+
+    // Build result
+    let result = SplitResult {
+        shares: share_mnemonics,
+        share_count: shares,
+        threshold,
+    };
+
+    // Serialize to JSON
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// Validates split parameters and performs the split, with JavaScript-friendly errors
+fn split_checked(mnemonic: &str, shares: u8, threshold: u8) -> Result<Vec<String>, JsValue> {
+    let config = split_config(shares, threshold)?;
+
+    // Perform the split
+    commands::split_mnemonic(mnemonic, config)
+        .map_err(|e| JsValue::from_str(&format!("Split failed: {}", e)))
+}
+
+/// Validates split parameters, with JavaScript-friendly errors
+fn split_config(shares: u8, threshold: u8) -> Result<SplitConfig, JsValue> {
+    // Validate inputs
+    let threshold_obj = Threshold::new(threshold)
+        .map_err(|e| JsValue::from_str(&format!("Invalid threshold: {}", e)))?;
+
+    let share_count = ShareCount::new(shares)
+        .map_err(|e| JsValue::from_str(&format!("Invalid share count: {}", e)))?;
+
+    SplitConfig::new(threshold_obj, share_count)
+        .map_err(|e| JsValue::from_str(&format!("Invalid configuration: {}", e)))
+}
+
+/// Renders `text` as an inline SVG QR code, see [`commands::qr_svg`]
+fn qr_svg(text: &str) -> Result<String, JsValue> {
+    commands::qr_svg(text).map_err(|e| JsValue::from_str(&e.to_string()))
+}
+
+/// Split a BIP39 mnemonic and render each share as a QR code
+///
+/// The QR payload is exactly the share text returned alongside it, so a scanned code
+/// can be passed straight to `wasm_combine`.
+///
+/// # Arguments
+/// * `mnemonic` - The BIP39 mnemonic to split (12 or 24 words)
+/// * `shares` - Total number of shares to create (2-255)
+/// * `threshold` - Minimum number of shares needed to reconstruct (2-shares)
+///
+/// # Returns
+/// JSON array of `{index, share, qr_svg}` objects, or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const result = JSON.parse(wasm_split_qr(mnemonic, 5, 3));
+/// for (const { index, qr_svg } of result) {
+///     document.getElementById(`share-${index}`).innerHTML = qr_svg;
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn wasm_split_qr(mnemonic: &str, shares: u8, threshold: u8) -> Result<String, JsValue> {
+    let share_mnemonics = split_checked(mnemonic, shares, threshold)?;
+
+    let result = share_mnemonics
+        .into_iter()
+        .enumerate()
+        .map(|(idx, share)| {
+            Ok(QrShare {
+                // Safe: idx < shares, which is a u8
+                index: idx as u8,
+                qr_svg: qr_svg(&share)?,
+                share,
+            })
+        })
+        .collect::<Result<Vec<_>, JsValue>>()?;
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// Combine Shamir Secret Shares to reconstruct the original mnemonic
+///
+/// # Arguments
+/// * `shares` - Array of shamir39-encoded share mnemonics
+///
+/// # Returns
+/// The reconstructed BIP39 mnemonic, or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const shares = [
+///     "shameless word1 word2 ...",
+///     "shameless word1 word2 ...",
+///     "shameless word1 word2 ..."
+/// ];
+/// const mnemonic = wasm_combine(shares);
+/// console.log(`Recovered mnemonic: ${mnemonic}`);
+/// ```
+#[wasm_bindgen]
+pub fn wasm_combine(shares: Vec<String>) -> Result<String, JsValue> {
+    // Perform the combine
+    // The mnemonic has to leave WASM memory as a plain string for JavaScript
+    commands::combine_shares(&shares)
+        .map(|mnemonic| mnemonic.to_string())
+        .map_err(|e| JsValue::from_str(&format!("Combine failed: {}", e)))
+}
+
+/// Re-split the secret behind existing shares with new parameters
+///
+/// The shares are combined and re-split entirely inside WASM, so the recovered
+/// mnemonic never crosses into JavaScript. The old shares remain valid.
+///
+/// # Arguments
+/// * `shares` - Array of at least threshold-many existing share mnemonics
+/// * `new_shares` - Total number of new shares to create (2-255)
+/// * `new_threshold` - Minimum number of new shares needed to reconstruct (2-new_shares)
+///
+/// # Returns
+/// JSON string containing the new shares and metadata (as `wasm_split`), or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// // Turn a 2-of-3 backup into a 3-of-5 one
+/// const data = JSON.parse(wasm_rotate([share1, share3], 5, 3));
+/// console.log(`Created ${data.share_count} new shares with threshold ${data.threshold}`);
+/// ```
+#[wasm_bindgen]
+pub fn wasm_rotate(
+    shares: Vec<String>,
+    new_shares: u8,
+    new_threshold: u8,
+) -> Result<String, JsValue> {
+    let config = split_config(new_shares, new_threshold)?;
+
+    let share_mnemonics = commands::rotate_shares(&shares, config)
+        .map_err(|e| JsValue::from_str(&format!("Rotate failed: {}", e)))?;
+
+    let result = SplitResult {
+        shares: share_mnemonics,
+        share_count: new_shares,
+        threshold: new_threshold,
+    };
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// Check whether a set of shares is sufficient to reconstruct, without combining
+///
+/// Parses each share and validates threshold consistency like `wasm_combine`, but
+/// never recovers the secret, so it is safe to call on every keystroke.
+///
+/// # Arguments
+/// * `shares` - Array of shamir39-encoded share mnemonics
+///
+/// # Returns
+/// JSON string `{ready, have, need, duplicates, invalid}`, or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const status = JSON.parse(wasm_can_combine(shares));
+/// reconstructButton.disabled = !status.ready;
+/// console.log(`Have ${status.have} of ${status.need} shares`);
+/// ```
+#[wasm_bindgen]
+pub fn wasm_can_combine(shares: Vec<String>) -> Result<String, JsValue> {
+    let survey = survey_shares(&shares);
+    let have = survey.indices.len();
+    let readiness = CombineReadiness {
+        ready: survey.ready(),
+        have,
+        need: survey.threshold,
+        duplicates: survey.duplicates.into_iter().collect(),
+        invalid: survey.invalid,
+    };
+
+    serde_json::to_string(&readiness)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// What a set of shares holds, as found by [`survey_shares`]
+struct ShareSurvey {
+    /// Threshold of the first valid share
+    threshold: Option<u8>,
+    /// Share count recorded by the first valid share, if it records one
+    share_count: Option<u8>,
+    /// Distinct indices of the valid shares
+    indices: BTreeSet<u8>,
+    /// Indices that appear more than once
+    duplicates: BTreeSet<u8>,
+    /// Shares that failed to parse or disagree on the threshold
+    invalid: Vec<InvalidShare>,
+}
+
+impl ShareSurvey {
+    /// Whether enough distinct valid shares are present to reconstruct
+    fn ready(&self) -> bool {
+        self.threshold
+            .is_some_and(|t| self.indices.len() >= usize::from(t))
+    }
+}
+
+/// Parses each share and checks threshold consistency like `wasm_combine`, without
+/// recovering the secret
+fn survey_shares(shares: &[String]) -> ShareSurvey {
+    let mut survey = ShareSurvey {
+        threshold: None,
+        share_count: None,
+        indices: BTreeSet::new(),
+        duplicates: BTreeSet::new(),
+        invalid: Vec::new(),
+    };
+
+    for (idx, share) in shares.iter().enumerate() {
+        let position = idx + 1;
+
+        let (share_threshold, share_index, _data) = match codec::parse_share(share) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                survey.invalid.push(InvalidShare {
+                    position,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+
+        match survey.threshold {
+            None => {
+                survey.threshold = Some(*share_threshold);
+                survey.share_count =
+                    codec::parse_share_part(share, &codec::CodecOptions::default())
+                        .ok()
+                        .and_then(|decoded| decoded.share_count)
+                        .map(|count| *count);
+            }
+            Some(t) if t != *share_threshold => {
+                survey.invalid.push(InvalidShare {
+                    position,
+                    reason: format!(
+                        "inconsistent threshold: expected {}, got {}",
+                        t, *share_threshold
+                    ),
+                });
+                continue;
+            }
+            _ => {}
+        }
+
+        if !survey.indices.insert(*share_index) {
+            survey.duplicates.insert(*share_index);
+        }
+    }
+
+    survey
+}
+
+/// Describe what a guided recovery needs next, without combining
+///
+/// Stateless counterpart of [`WasmShareCollector`] for UI frameworks that keep the
+/// collected shares in immutable state: pass the whole collection on every call. Like
+/// `wasm_can_combine`, it never recovers the secret.
+///
+/// # Arguments
+/// * `collected` - Array of the shamir39-encoded share mnemonics entered so far
+///
+/// # Returns
+/// JSON string `{ready, threshold, have, remaining, indices, duplicates, missing,
+/// invalid, prompt}`, or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const hint = JSON.parse(wasm_next_share_hint(state.shares));
+/// promptLabel.textContent = hint.prompt;
+/// reconstructButton.disabled = !hint.ready;
+/// ```
+#[wasm_bindgen]
+pub fn wasm_next_share_hint(collected: Vec<String>) -> Result<String, JsValue> {
+    let survey = survey_shares(&collected);
+    let ready = survey.ready();
+    let have = survey.indices.len();
+    let remaining = survey
+        .threshold
+        .map(|t| usize::from(t).saturating_sub(have));
+    let missing: Option<Vec<u8>> = survey.share_count.map(|count| {
+        (0..count)
+            .filter(|index| !survey.indices.contains(index))
+            .collect()
+    });
+
+    let prompt = match (remaining, &missing) {
+        _ if ready => "Enough shares to reconstruct".to_string(),
+        (None, _) => "Enter any share to begin".to_string(),
+        (Some(remaining), Some(missing)) => format!(
+            "Enter {remaining} more share(s), from indices {}",
+            join_indices(missing)
+        ),
+        (Some(remaining), None) => format!(
+            "Enter {remaining} more share(s), other than indices {}",
+            join_indices(&survey.indices)
+        ),
+    };
+
+    let hint = NextShareHint {
+        ready,
+        threshold: survey.threshold,
+        have,
+        remaining,
+        indices: survey.indices.into_iter().collect(),
+        duplicates: survey.duplicates.into_iter().collect(),
+        missing,
+        invalid: survey.invalid,
+        prompt,
+    };
+
+    serde_json::to_string(&hint)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// Lists share indices for a prompt, e.g. `1, 3, 4`
+fn join_indices<'a>(indices: impl IntoIterator<Item = &'a u8>) -> String {
+    indices
+        .into_iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Collects shares across several calls, e.g. one QR scan at a time, and reconstructs
+/// once enough have been gathered
+///
+/// Each share is validated as it is added: it must parse, agree on the threshold with
+/// the shares collected so far, and not repeat an index. Re-adding a share that was
+/// already collected is harmless; it is reported and ignored.
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const collector = new WasmShareCollector();
+/// scanner.onScan = (text) => {
+///     const status = JSON.parse(collector.add_share(text));
+///     console.log(`Have ${status.have} of ${status.need} shares`);
+///     if (status.ready) {
+///         console.log(`Recovered mnemonic: ${collector.try_combine()}`);
+///     }
+/// };
+/// ```
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct WasmShareCollector {
+    threshold: Option<u8>,
+    shares: BTreeMap<u8, CollectedShare>,
+}
+
+/// A validated share held by a [`WasmShareCollector`]
+struct CollectedShare {
+    text: Zeroizing<String>,
+    data: Zeroizing<Vec<u8>>,
+}
+
+#[wasm_bindgen]
+impl WasmShareCollector {
+    /// Creates an empty collector
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate and collect a share
+    ///
+    /// # Returns
+    /// JSON string `{accepted, index, error, have, need, ready}`; a rejected share is
+    /// reported in `error` rather than thrown
+    pub fn add_share(&mut self, share: &str) -> JsValue {
+        let status = self.add(share);
+        match serde_json::to_string(&status) {
+            Ok(json) => JsValue::from_str(&json),
+            Err(e) => JsValue::from_str(&format!("Serialization failed: {}", e)),
+        }
+    }
+
+    /// Reconstruct the mnemonic from the collected shares
+    ///
+    /// # Returns
+    /// The reconstructed BIP39 mnemonic, or an error message if too few shares have
+    /// been collected or they fail to combine
+    pub fn try_combine(&self) -> Result<String, JsValue> {
+        let need = self.threshold.unwrap_or(2);
+        if self.shares.len() < usize::from(need) {
+            return Err(JsValue::from_str(&format!(
+                "Need {} more share(s) to reconstruct",
+                usize::from(need) - self.shares.len()
+            )));
+        }
+
+        let shares: Vec<&str> = self.shares.values().map(|s| s.text.as_str()).collect();
+
+        // The mnemonic has to leave WASM memory as a plain string for JavaScript
+        commands::combine_shares(&shares)
+            .map(|mnemonic| mnemonic.to_string())
+            .map_err(|e| JsValue::from_str(&format!("Combine failed: {}", e)))
+    }
+}
+
+impl WasmShareCollector {
+    /// Validates `share` against the shares collected so far and keeps it if it fits
+    fn add(&mut self, share: &str) -> CollectorStatus {
+        let share = share.trim();
+        let (index, error) = match codec::parse_share(share) {
+            Ok((threshold, index, data)) => {
+                (Some(*index), self.insert(share, threshold, index, data))
+            }
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let have = self.shares.len();
+        CollectorStatus {
+            accepted: error.is_none(),
+            index,
+            error,
+            have,
+            need: self.threshold,
+            ready: self.threshold.is_some_and(|t| have >= usize::from(t)),
+        }
+    }
+
+    /// Keeps a parsed share, returning why it was rejected if it cannot be combined
+    /// with the shares collected so far
+    fn insert(
+        &mut self,
+        share: &str,
+        threshold: Threshold,
+        index: ShareIndex,
+        data: Zeroizing<Vec<u8>>,
+    ) -> Option<String> {
+        if let Some(expected) = self.threshold
+            && expected != *threshold
+        {
+            return Some(format!(
+                "inconsistent threshold: expected {expected}, got {threshold}"
+            ));
+        }
+
+        if let Some(existing) = self.shares.get(&*index) {
+            return Some(if existing.data == data {
+                format!("share {index} was already added")
+            } else {
+                format!(
+                    "share {index} conflicts with a different share already added for that index"
+                )
+            });
+        }
+
+        self.threshold = Some(*threshold);
+        self.shares.insert(
+            *index,
+            CollectedShare {
+                text: Zeroizing::new(share.to_string()),
+                data,
+            },
+        );
+        None
+    }
+}
+
+/// Diagnose a set of shares without reconstructing the secret
+///
+/// Reports every problem found (bad version words, unknown words with suggestions,
+/// checksum failures, duplicates, threshold disagreements, missing quorum) rather
+/// than stopping at the first, like `wasm_combine` does.
+///
+/// # Arguments
+/// * `shares` - Array of shamir39-encoded share mnemonics
+///
+/// # Returns
+/// JSON string `{total, passed_checksum, distinct_indices, threshold, threshold_agreed,
+/// quorum, problems: [{position, kind, message}]}`, or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const report = JSON.parse(wasm_health_report(shares));
+/// for (const problem of report.problems) {
+///     console.log(`${problem.kind}: ${problem.message}`);
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn wasm_health_report(shares: Vec<String>) -> Result<String, JsValue> {
+    let report = commands::health_report(&shares);
+
+    serde_json::to_string(&report)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// Parse a shamir39 share to extract metadata without exposing its data
+///
+/// # Arguments
+/// * `share` - A shamir39-encoded share mnemonic
+///
+/// # Returns
+/// JSON string containing threshold, share_index, data_len, format_version,
+/// passphrase_reminder, share_count (`null` if the share does not record it) and
+/// fingerprint (4 hex digits, see `shameless fingerprint`), or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const metadata = wasm_parse_share("shameless word1 word2 ...");
+/// const data = JSON.parse(metadata);
+/// console.log(`Threshold: ${data.threshold}, Index: ${data.share_index}`);
+/// console.log(`${data.data_len} bytes, format v${data.format_version}`);
+/// ```
+#[wasm_bindgen]
+pub fn wasm_parse_share(share: &str) -> Result<String, JsValue> {
+    let meta = codec::share_metadata(share)
+        .map_err(|e| JsValue::from_str(&format!("Parse failed: {}", e)))?;
+
+    let metadata = ShareMetadata {
+        threshold: *meta.threshold,
+        share_index: *meta.index,
+        data_len: meta.data_len,
+        format_version: meta.format_version,
+        passphrase_reminder: meta.flags.passphrase_reminder,
+        share_count: meta.share_count.map(|share_count| *share_count),
+        fingerprint: meta.fingerprint_hex(),
+    };
+
+    serde_json::to_string(&metadata)
+        .map_err(|e| JsValue::from_str(&format!("Serialization failed: {}", e)))
+}
+
+/// Suggest BIP39 words the user may have meant by a mistyped word
+///
+/// # Arguments
+/// * `word` - A single word, as typed
+///
+/// # Returns
+/// JSON array of up to three English BIP39 words, closest first. A valid word is
+/// returned as the sole suggestion, in its wordlist spelling; the array is empty when
+/// no word is within two edits.
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const suggestions = JSON.parse(wasm_suggest_word("abandno"));
+/// // ["abandon"]
+/// ```
+#[wasm_bindgen]
+pub fn wasm_suggest_word(word: &str) -> String {
+    let word = word.trim();
+    let suggestions = match codec::word_to_index(word, Language::English) {
+        Ok(index) => vec![Language::English.word_list()[usize::from(index)]],
+        Err(_) => codec::suggest_words(word, Language::English),
+    };
+
+    serde_json::Value::from(suggestions).to_string()
+}
+
+/// Generate a random BIP39 mnemonic
+///
+/// # Arguments
+/// * `word_count` - Number of words (12 or 24)
+///
+/// # Returns
+/// A randomly generated BIP39 mnemonic string, or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const mnemonic12 = wasm_generate_mnemonic(12);
+/// const mnemonic24 = wasm_generate_mnemonic(24);
+/// console.log(`Random 12-word: ${mnemonic12}`);
+/// ```
+#[wasm_bindgen]
+pub fn wasm_generate_mnemonic(word_count: u8) -> Result<String, JsValue> {
+    // Validate word count
+    if word_count != 12 && word_count != 24 {
+        return Err(JsValue::from_str("Invalid word count: must be 12 or 24"));
+    }
+
+    // Generate random entropy
+    // The getrandom crate (with "js" feature) will use browser's crypto.getRandomValues()
+    let entropy_size = if word_count == 12 { 16 } else { 32 }; // 128 or 256 bits
+    let mut entropy = vec![0u8; entropy_size];
+
+    getrandom::getrandom(&mut entropy)
+        .map_err(|e| JsValue::from_str(&format!("Failed to generate random entropy: {}", e)))?;
+
+    // Create mnemonic from entropy
+    let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy)
+        .map_err(|e| JsValue::from_str(&format!("Failed to create mnemonic: {}", e)))?;
+
+    Ok(mnemonic.to_string())
+}
+
+/// Create a BIP39 mnemonic from caller-supplied entropy
+///
+/// For advanced users who bring their own randomness (e.g. hashed dice rolls). Input
+/// that is obviously not random, such as all zero bytes, is rejected.
+///
+/// # Arguments
+/// * `entropy` - 16, 20, 24, 28 or 32 bytes of entropy
+///
+/// # Returns
+/// The BIP39 mnemonic for `entropy`, or an error message
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const entropy = new Uint8Array(await crypto.subtle.digest("SHA-256", diceRollBytes));
+/// const mnemonic24 = wasm_generate_from_entropy(entropy);
+/// ```
+#[wasm_bindgen]
+pub fn wasm_generate_from_entropy(entropy: &[u8]) -> Result<String, JsValue> {
+    // The mnemonic has to leave WASM memory as a plain string for JavaScript
+    commands::generate_mnemonic_from_entropy(entropy)
+        .map(|mnemonic| mnemonic.to_string())
+        .map_err(|e| JsValue::from_str(&format!("Failed to create mnemonic: {}", e)))
+}
+
+/// Describe every JSON output of these bindings as a JSON Schema
+///
+/// The schema (draft 2020-12) names each output shape under `$defs`: `SplitResult`
+/// (`wasm_split`, `wasm_rotate`), `QrShareList` (`wasm_split_qr`), `ShareMetadata`
+/// (`wasm_parse_share`), `CombineReadiness` (`wasm_can_combine`), `NextShareHint`
+/// (`wasm_next_share_hint`), `CollectorStatus` (`WasmShareCollector.add_share`),
+/// `HealthReport` (`wasm_health_report`) and `WordSuggestions` (`wasm_suggest_word`).
+///
+/// # Returns
+/// The JSON Schema document as a string
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const schema = JSON.parse(wasm_schema());
+/// const validate = new Ajv2020().compile({ ...schema, $ref: "#/$defs/SplitResult" });
+/// validate(JSON.parse(wasm_split(mnemonic, 5, 3)));
+/// ```
+#[wasm_bindgen]
+pub fn wasm_schema() -> String {
+    types::schema().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_wasm_split_basic() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let result = wasm_split(mnemonic, 5, 3);
+        assert!(result.is_ok());
+
+        let json = result.unwrap();
+        let data: SplitResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(data.shares.len(), 5);
+        assert_eq!(data.share_count, 5);
+        assert_eq!(data.threshold, 3);
+    }
+
+    #[test]
+    fn test_wasm_split_invalid_threshold() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let result = wasm_split(mnemonic, 5, 1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_wasm_split_max_share_count() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let data: SplitResult =
+            serde_json::from_str(&wasm_split(mnemonic, 254, 2).unwrap()).unwrap();
+        assert_eq!(data.shares.len(), 254);
+        assert_eq!(data.share_count, 254);
+
+        // Shares keep consecutive indices up to the last one, which still combines
+        let last = codec::share_metadata(&data.shares[253]).unwrap();
+        assert_eq!(*last.index, 253);
+        let pair = vec![data.shares[0].clone(), data.shares[253].clone()];
+        assert_eq!(wasm_combine(pair).unwrap(), mnemonic);
+    }
+
+    #[test]
+    fn test_wasm_split_rejects_share_count_out_of_range() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+
+        let err = wasm_split(mnemonic, 255, 2)
+            .unwrap_err()
+            .as_string()
+            .unwrap();
+        assert!(err.starts_with("Invalid share count: "));
+        assert!(err.contains("Share count maximum is 254 due to GF256 limitations"));
+
+        let err = wasm_split(mnemonic, 0, 2).unwrap_err().as_string().unwrap();
+        assert!(err.starts_with("Invalid share count: "));
+    }
+
+    #[test]
+    fn test_wasm_split_invalid_mnemonic() {
+        let result = wasm_split("invalid mnemonic words", 5, 3);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_wasm_combine_basic() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+
+        // First split
+        let split_result = wasm_split(mnemonic, 5, 3).unwrap();
+        let data: SplitResult = serde_json::from_str(&split_result).unwrap();
+
+        // Take 3 shares (threshold)
+        let selected_shares = data.shares[0..3].to_vec();
+
+        // Combine
+        let recovered = wasm_combine(selected_shares);
+        assert!(recovered.is_ok());
+        assert_eq!(recovered.unwrap(), mnemonic);
+    }
+
+    #[test]
+    fn test_wasm_combine_insufficient_shares() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+
+        // Split with threshold 3
+        let split_result = wasm_split(mnemonic, 5, 3).unwrap();
+        let data: SplitResult = serde_json::from_str(&split_result).unwrap();
+
+        // Take only 2 shares (insufficient)
+        let selected_shares = data.shares[0..2].to_vec();
+
+        // Should fail
+        let result = wasm_combine(selected_shares);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_wasm_rotate_2_of_3_to_3_of_5() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+
+        let split_result = wasm_split(mnemonic, 3, 2).unwrap();
+        let old: SplitResult = serde_json::from_str(&split_result).unwrap();
+
+        let rotate_result = wasm_rotate(vec![old.shares[0].clone(), old.shares[2].clone()], 5, 3);
+        let new: SplitResult = serde_json::from_str(&rotate_result.unwrap()).unwrap();
+        assert_eq!(new.shares.len(), 5);
+        assert_eq!(new.share_count, 5);
+        assert_eq!(new.threshold, 3);
+
+        let recovered = wasm_combine(new.shares[1..4].to_vec()).unwrap();
+        assert_eq!(recovered, mnemonic);
+    }
+
+    #[test]
+    fn test_wasm_parse_share() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let split_result = wasm_split(mnemonic, 5, 3).unwrap();
+        let data: SplitResult = serde_json::from_str(&split_result).unwrap();
+
+        // Parse first share
+        let parse_result = wasm_parse_share(&data.shares[0]);
+        assert!(parse_result.is_ok());
+
+        let metadata: ShareMetadata = serde_json::from_str(&parse_result.unwrap()).unwrap();
+        assert_eq!(metadata.threshold, 3);
+        assert_eq!(metadata.share_index, 0);
+        // 16 bytes of entropy plus the x coordinate
+        assert_eq!(metadata.data_len, 17);
+        assert_eq!(metadata.format_version, 7);
+        assert!(!metadata.passphrase_reminder);
+        assert_eq!(metadata.share_count, Some(5));
+        assert_eq!(
+            metadata.fingerprint,
+            codec::share_metadata(&data.shares[0])
+                .unwrap()
+                .fingerprint_hex()
+        );
+    }
+
+    #[test]
+    fn test_wasm_suggest_word() {
+        let suggest = |word| serde_json::from_str::<Vec<String>>(&wasm_suggest_word(word)).unwrap();
+
+        assert_eq!(suggest("abandno"), ["abandon"]);
+        assert_eq!(suggest("zooo"), ["zoo", "book", "cook"]);
+        // Valid words come back as the only suggestion, as listed
+        assert_eq!(suggest("Abandon"), ["abandon"]);
+        assert!(suggest("qqqqqqqqq").is_empty());
+    }
+
+    #[test]
+    fn test_wasm_split_qr() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let result: Vec<QrShare> =
+            serde_json::from_str(&wasm_split_qr(mnemonic, 3, 2).unwrap()).unwrap();
+
+        assert_eq!(result.len(), 3);
+        for (idx, qr) in result.iter().enumerate() {
+            assert_eq!(usize::from(qr.index), idx);
+            assert!(qr.share.starts_with("shameless "));
+            assert!(qr.qr_svg.starts_with("<?xml"));
+            assert!(qr.qr_svg.contains("<svg"));
+        }
+
+        let shares = vec![result[0].share.clone(), result[2].share.clone()];
+        assert_eq!(wasm_combine(shares).unwrap(), mnemonic);
+    }
+
+    #[test]
+    fn test_qr_svg_handles_long_text() {
+        // Far longer than any share, but still within QR capacity
+        let svg = qr_svg(&"shameless ".repeat(200)).unwrap();
+        assert!(svg.contains("<svg"));
+    }
+
+    #[test]
+    fn test_wasm_can_combine_ready() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let split_result = wasm_split(mnemonic, 5, 3).unwrap();
+        let data: SplitResult = serde_json::from_str(&split_result).unwrap();
+
+        let status: CombineReadiness =
+            serde_json::from_str(&wasm_can_combine(data.shares[0..3].to_vec()).unwrap()).unwrap();
+        assert!(status.ready);
+        assert_eq!(status.have, 3);
+        assert_eq!(status.need, Some(3));
+        assert!(status.duplicates.is_empty());
+        assert!(status.invalid.is_empty());
+    }
+
+    #[test]
+    fn test_wasm_can_combine_reports_duplicates_and_invalid() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let split_result = wasm_split(mnemonic, 5, 3).unwrap();
+        let data: SplitResult = serde_json::from_str(&split_result).unwrap();
+
+        let shares = vec![
+            data.shares[0].clone(),
+            data.shares[0].clone(),
+            "not a share".to_string(),
+            data.shares[1].clone(),
+        ];
+
+        let status: CombineReadiness =
+            serde_json::from_str(&wasm_can_combine(shares).unwrap()).unwrap();
+        assert!(!status.ready);
+        assert_eq!(status.have, 2);
+        assert_eq!(status.need, Some(3));
+        assert_eq!(status.duplicates, vec![0]);
+        assert_eq!(status.invalid.len(), 1);
+        assert_eq!(status.invalid[0].position, 3);
+    }
+
+    #[test]
+    fn test_wasm_can_combine_empty() {
+        let status: CombineReadiness =
+            serde_json::from_str(&wasm_can_combine(Vec::new()).unwrap()).unwrap();
+        assert!(!status.ready);
+        assert_eq!(status.have, 0);
+        assert_eq!(status.need, None);
+    }
+
+    #[test]
+    fn test_wasm_next_share_hint_without_shares() {
+        let hint: NextShareHint =
+            serde_json::from_str(&wasm_next_share_hint(Vec::new()).unwrap()).unwrap();
+        assert!(!hint.ready);
+        assert_eq!((hint.threshold, hint.have, hint.remaining), (None, 0, None));
+        assert_eq!(hint.missing, None);
+        assert_eq!(hint.prompt, "Enter any share to begin");
+    }
+
+    #[test]
+    fn test_wasm_next_share_hint_one_short_of_threshold() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let data: SplitResult = serde_json::from_str(&wasm_split(mnemonic, 5, 3).unwrap()).unwrap();
+
+        let collected = vec![
+            data.shares[3].clone(),
+            data.shares[1].clone(),
+            data.shares[1].clone(),
+            "not a share".to_string(),
+        ];
+        let hint: NextShareHint =
+            serde_json::from_str(&wasm_next_share_hint(collected).unwrap()).unwrap();
+        assert!(!hint.ready);
+        assert_eq!(
+            (hint.threshold, hint.have, hint.remaining),
+            (Some(3), 2, Some(1))
+        );
+        assert_eq!(hint.indices, vec![1, 3]);
+        assert_eq!(hint.duplicates, vec![1]);
+        assert_eq!(hint.missing, Some(vec![0, 2, 4]));
+        assert_eq!(hint.invalid.len(), 1);
+        assert_eq!(hint.invalid[0].position, 4);
+        assert_eq!(hint.prompt, "Enter 1 more share(s), from indices 0, 2, 4");
+    }
+
+    #[test]
+    fn test_wasm_next_share_hint_threshold_met() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let data: SplitResult = serde_json::from_str(&wasm_split(mnemonic, 5, 3).unwrap()).unwrap();
+
+        let hint: NextShareHint =
+            serde_json::from_str(&wasm_next_share_hint(data.shares[2..].to_vec()).unwrap())
+                .unwrap();
+        assert!(hint.ready);
+        assert_eq!(hint.remaining, Some(0));
+        assert_eq!(hint.missing, Some(vec![0, 1]));
+        assert_eq!(hint.prompt, "Enough shares to reconstruct");
+    }
+
+    #[test]
+    fn test_wasm_share_collector_combines_across_batches() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let split_result = wasm_split(mnemonic, 5, 3).unwrap();
+        let data: SplitResult = serde_json::from_str(&split_result).unwrap();
+
+        let mut collector = WasmShareCollector::new();
+
+        let status = collector.add(&data.shares[4]);
+        assert!(status.accepted);
+        assert_eq!(status.index, Some(4));
+        assert_eq!(
+            (status.have, status.need, status.ready),
+            (1, Some(3), false)
+        );
+
+        // Scanning the same share again does not count twice
+        let status = collector.add(&data.shares[4]);
+        assert!(!status.accepted);
+        assert!(status.error.unwrap().contains("already added"));
+        assert_eq!(status.have, 1);
+
+        let status = collector.add("not a share");
+        assert!(!status.accepted);
+        assert_eq!(status.index, None);
+        assert_eq!(status.have, 1);
+
+        assert!(collector.add(&data.shares[0]).accepted);
+        let status = collector.add(&data.shares[2]);
+        assert!(status.ready);
+        assert_eq!(status.have, 3);
+
+        assert_eq!(collector.try_combine().unwrap(), mnemonic);
+    }
+
+    #[test]
+    fn test_wasm_share_collector_rejects_mismatched_shares() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let first: SplitResult =
+            serde_json::from_str(&wasm_split(mnemonic, 5, 3).unwrap()).unwrap();
+        let other: SplitResult =
+            serde_json::from_str(&wasm_split(mnemonic, 3, 2).unwrap()).unwrap();
+        let resplit: SplitResult =
+            serde_json::from_str(&wasm_split(mnemonic, 5, 3).unwrap()).unwrap();
+
+        let mut collector = WasmShareCollector::new();
+        assert!(collector.add(&first.shares[0]).accepted);
+
+        let status = collector.add(&other.shares[1]);
+        assert!(status.error.unwrap().contains("inconsistent threshold"));
+
+        // Same index from a different split of the same secret
+        let status = collector.add(&resplit.shares[0]);
+        assert!(status.error.unwrap().contains("conflicts"));
+        assert_eq!(status.have, 1);
+    }
+
+    #[test]
+    fn test_wasm_health_report() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let data: SplitResult = serde_json::from_str(&wasm_split(mnemonic, 5, 3).unwrap()).unwrap();
+
+        let shares = vec![data.shares[0].clone(), "shameless abandno".to_string()];
+        let report: serde_json::Value =
+            serde_json::from_str(&wasm_health_report(shares).unwrap()).unwrap();
+        assert_eq!(report["total"], 2);
+        assert_eq!(report["threshold"], 3);
+        assert_eq!(report["quorum"], false);
+        assert_eq!(report["problems"][0]["kind"], "unknown_word");
+        assert_eq!(report["problems"][0]["position"], 2);
+    }
+
+    /// Checks `value` against `schema`, supporting the keywords [`wasm_schema`] uses
+    fn validate(root: &Value, schema: &Value, value: &Value) -> Result<(), String> {
+        if let Some(reference) = schema["$ref"].as_str() {
+            let name = reference.trim_start_matches("#/$defs/");
+            return validate(root, &root["$defs"][name], value);
+        }
+        if let Some(options) = schema["anyOf"].as_array() {
+            return if options.iter().any(|o| validate(root, o, value).is_ok()) {
+                Ok(())
+            } else {
+                Err(format!("{value} matches no option of {schema}"))
+            };
+        }
+        if let Some(allowed) = schema["enum"].as_array()
+            && !allowed.contains(value)
+        {
+            return Err(format!("{value} is not one of {allowed:?}"));
+        }
+        let type_ok = match schema["type"].as_str() {
+            None => true,
+            Some("object") => value.is_object(),
+            Some("array") => value.is_array(),
+            Some("string") => value.is_string(),
+            Some("boolean") => value.is_boolean(),
+            Some("integer") => value.is_u64() || value.is_i64(),
+            Some("null") => value.is_null(),
+            Some(other) => return Err(format!("unsupported type {other}")),
+        };
+        if !type_ok {
+            return Err(format!("{value} is not of type {}", schema["type"]));
+        }
+        if let Some(n) = value.as_i64()
+            && (schema["minimum"].as_i64().is_some_and(|min| n < min)
+                || schema["maximum"].as_i64().is_some_and(|max| n > max))
+        {
+            return Err(format!("{n} is out of range for {schema}"));
+        }
+        if let Some(items) = value.as_array() {
+            for item in items {
+                validate(root, &schema["items"], item)?;
+            }
+        }
+        if let Some(object) = value.as_object() {
+            let properties = schema["properties"].as_object().unwrap();
+            for required in schema["required"].as_array().unwrap() {
+                if !object.contains_key(required.as_str().unwrap()) {
+                    return Err(format!("missing field {required}"));
+                }
+            }
+            for (name, field) in object {
+                let field_schema = properties
+                    .get(name)
+                    .ok_or_else(|| format!("unexpected field {name}"))?;
+                validate(root, field_schema, field).map_err(|e| format!("{name}: {e}"))?;
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_wasm_outputs_validate_against_schema() {
+        let schema: Value = serde_json::from_str(&wasm_schema()).unwrap();
+        let check = |def: &str, json: &str| {
+            let value: Value = serde_json::from_str(json).unwrap();
+            validate(&schema, &schema["$defs"][def], &value).unwrap();
+            // The root schema accepts every output too
+            validate(&schema, &schema, &value).unwrap();
+        };
+
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let split_result = wasm_split(mnemonic, 5, 3).unwrap();
+        check("SplitResult", &split_result);
+        let data: SplitResult = serde_json::from_str(&split_result).unwrap();
+        let shares = vec![
+            data.shares[0].clone(),
+            data.shares[0].clone(),
+            "shameless abandno".to_string(),
+        ];
+
+        check("QrShareList", &wasm_split_qr(mnemonic, 3, 2).unwrap());
+        check("ShareMetadata", &wasm_parse_share(&data.shares[1]).unwrap());
+        check(
+            "CombineReadiness",
+            &wasm_can_combine(shares.clone()).unwrap(),
+        );
+        check(
+            "NextShareHint",
+            &wasm_next_share_hint(shares.clone()).unwrap(),
+        );
+        check("NextShareHint", &wasm_next_share_hint(Vec::new()).unwrap());
+        check("HealthReport", &wasm_health_report(shares).unwrap());
+        check("WordSuggestions", &wasm_suggest_word("abandno"));
+        let mut collector = WasmShareCollector::new();
+        for share in [data.shares[2].as_str(), "not a share"] {
+            check(
+                "CollectorStatus",
+                &serde_json::to_string(&collector.add(share)).unwrap(),
+            );
+        }
+
+        // The schema is strict enough to catch a renamed or out-of-range field
+        let split_result: Value = serde_json::from_str(&split_result).unwrap();
+        let mut renamed = split_result.clone();
+        renamed["count"] = renamed["share_count"].take();
+        renamed.as_object_mut().unwrap().remove("share_count");
+        assert!(validate(&schema, &schema["$defs"]["SplitResult"], &renamed).is_err());
+        let mut out_of_range = split_result;
+        out_of_range["threshold"] = Value::from(256);
+        assert!(validate(&schema, &schema["$defs"]["SplitResult"], &out_of_range).is_err());
+    }
+
+    #[test]
+    fn test_wasm_generate_mnemonic_12_words() {
+        let result = wasm_generate_mnemonic(12);
+        assert!(result.is_ok());
+        let mnemonic = result.unwrap();
+        assert_eq!(mnemonic.split_whitespace().count(), 12);
+    }
+
+    #[test]
+    fn test_wasm_generate_mnemonic_24_words() {
+        let result = wasm_generate_mnemonic(24);
+        assert!(result.is_ok());
+        let mnemonic = result.unwrap();
+        assert_eq!(mnemonic.split_whitespace().count(), 24);
+    }
+
+    #[test]
+    fn test_wasm_generate_mnemonic_invalid_count() {
+        let result = wasm_generate_mnemonic(15);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_wasm_generate_from_entropy() {
+        let mnemonic = "army van defense carry jealous true garbage claim echo media make crunch";
+        let entropy = Mnemonic::parse(mnemonic).unwrap().to_entropy();
+
+        assert_eq!(wasm_generate_from_entropy(&entropy).unwrap(), mnemonic);
+    }
+
+    #[test]
+    fn test_wasm_generate_and_split() {
+        // Generate a random 12-word mnemonic
+        let mnemonic = wasm_generate_mnemonic(12).unwrap();
+
+        // Split it
+        let split_result = wasm_split(&mnemonic, 3, 2).unwrap();
+        let data: SplitResult = serde_json::from_str(&split_result).unwrap();
+
+        // Should produce 3 shares
+        assert_eq!(data.shares.len(), 3);
+
+        // Combine them back
+        let recovered = wasm_combine(data.shares[0..2].to_vec()).unwrap();
+
+        // Should match original
+        assert_eq!(mnemonic, recovered);
+    }
+}
@@ -0,0 +1,267 @@
+//! JSON output shapes of the WASM bindings
+//!
+//! Every JSON document a `wasm_*` function returns is one of the types here, so field
+//! names are defined and documented in one place. [`schema`] describes their shape as a
+//! JSON Schema (draft 2020-12), for consumers that want to validate outputs; what each
+//! field means is documented on the types only.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+pub use crate::commands::{HealthProblem, HealthReport, ProblemKind, SplitResult};
+
+/// A share together with a QR code of its exact text, as returned by `wasm_split_qr`
+#[derive(Serialize, Deserialize)]
+pub struct QrShare {
+    /// Share index (0-based, as encoded in the share)
+    pub index: u8,
+    /// The shamir39 share mnemonic
+    pub share: String,
+    /// Inline SVG document rendering `share` as a QR code
+    pub qr_svg: String,
+}
+
+/// Metadata of a single share, as returned by `wasm_parse_share`
+#[derive(Serialize, Deserialize)]
+pub struct ShareMetadata {
+    /// Minimum number of shares needed to reconstruct
+    pub threshold: u8,
+    /// Share index (0-based, as encoded in the share)
+    pub share_index: u8,
+    /// Length of the share data in bytes, including the x coordinate
+    pub data_len: usize,
+    /// Format version recorded by the share
+    pub format_version: u8,
+    /// Whether the secret was protected with a BIP39 passphrase
+    pub passphrase_reminder: bool,
+    /// Number of shares originally dealt (`null` if the share does not record it)
+    pub share_count: Option<u8>,
+    /// 4 hex digit share fingerprint, see `shameless fingerprint`
+    pub fingerprint: String,
+}
+
+/// An unusable share reported by `wasm_can_combine` and `wasm_next_share_hint`
+#[derive(Serialize, Deserialize)]
+pub struct InvalidShare {
+    /// 1-based position of the share in the input array
+    pub position: usize,
+    /// Why the share was rejected
+    pub reason: String,
+}
+
+/// Readiness of a set of shares for reconstruction, as returned by `wasm_can_combine`
+#[derive(Serialize, Deserialize)]
+pub struct CombineReadiness {
+    /// Whether enough distinct valid shares are present to reconstruct
+    pub ready: bool,
+    /// Number of distinct valid share indices
+    pub have: usize,
+    /// Threshold derived from the first valid share (`null` if none are valid)
+    pub need: Option<u8>,
+    /// Share indices that appear more than once
+    pub duplicates: Vec<u8>,
+    /// Shares that failed to parse or disagree on the threshold
+    pub invalid: Vec<InvalidShare>,
+}
+
+/// What a guided recovery still needs, as returned by `wasm_next_share_hint`
+#[derive(Serialize, Deserialize)]
+pub struct NextShareHint {
+    /// Whether enough distinct valid shares are present to reconstruct
+    pub ready: bool,
+    /// Threshold derived from the first valid share (`null` if none are valid)
+    pub threshold: Option<u8>,
+    /// Number of distinct valid share indices
+    pub have: usize,
+    /// Number of further distinct shares needed (`null` until the threshold is known)
+    pub remaining: Option<usize>,
+    /// Indices of the distinct valid shares, in ascending order
+    pub indices: Vec<u8>,
+    /// Share indices that appear more than once
+    pub duplicates: Vec<u8>,
+    /// Indices dealt but not yet present, if the shares record how many were dealt
+    pub missing: Option<Vec<u8>>,
+    /// Shares that failed to parse or disagree on the threshold
+    pub invalid: Vec<InvalidShare>,
+    /// What to ask the user for next
+    pub prompt: String,
+}
+
+/// Progress of a `WasmShareCollector` after adding a share, as returned by `add_share`
+#[derive(Serialize, Deserialize)]
+pub struct CollectorStatus {
+    /// Whether the share just added was kept
+    pub accepted: bool,
+    /// Share index of the share just added (`null` if it failed to parse)
+    pub index: Option<u8>,
+    /// Why the share was not kept (`null` if accepted)
+    pub error: Option<String>,
+    /// Number of distinct shares collected so far
+    pub have: usize,
+    /// Threshold of the collected shares (`null` until one is accepted)
+    pub need: Option<u8>,
+    /// Whether enough shares are collected to reconstruct
+    pub ready: bool,
+}
+
+/// JSON Schema describing every JSON output of the WASM bindings
+///
+/// Each output shape is a definition under `$defs`, named after its type here
+/// (`SplitResult`, `ShareMetadata`, `HealthReport`, ...), plus `QrShareList` and
+/// `WordSuggestions` for the array outputs. The root schema accepts any of them.
+/// Every listed field is always present; optional values are `null` rather than
+/// omitted, and unknown fields are rejected.
+#[must_use]
+pub fn schema() -> Value {
+    let defs: Map<String, Value> = [
+        (
+            "SplitResult",
+            object([
+                ("shares", array(string())),
+                ("share_count", byte()),
+                ("threshold", byte()),
+            ]),
+        ),
+        (
+            "QrShare",
+            object([("index", byte()), ("share", string()), ("qr_svg", string())]),
+        ),
+        ("QrShareList", array(reference("QrShare"))),
+        (
+            "ShareMetadata",
+            object([
+                ("threshold", byte()),
+                ("share_index", byte()),
+                ("data_len", count()),
+                ("format_version", byte()),
+                ("passphrase_reminder", boolean()),
+                ("share_count", nullable(byte())),
+                ("fingerprint", string()),
+            ]),
+        ),
+        (
+            "InvalidShare",
+            object([("position", count()), ("reason", string())]),
+        ),
+        (
+            "CombineReadiness",
+            object([
+                ("ready", boolean()),
+                ("have", count()),
+                ("need", nullable(byte())),
+                ("duplicates", indices()),
+                ("invalid", array(reference("InvalidShare"))),
+            ]),
+        ),
+        (
+            "NextShareHint",
+            object([
+                ("ready", boolean()),
+                ("threshold", nullable(byte())),
+                ("have", count()),
+                ("remaining", nullable(count())),
+                ("indices", indices()),
+                ("duplicates", indices()),
+                ("missing", nullable(indices())),
+                ("invalid", array(reference("InvalidShare"))),
+                ("prompt", string()),
+            ]),
+        ),
+        (
+            "CollectorStatus",
+            object([
+                ("accepted", boolean()),
+                ("index", nullable(byte())),
+                ("error", nullable(string())),
+                ("have", count()),
+                ("need", nullable(byte())),
+                ("ready", boolean()),
+            ]),
+        ),
+        (
+            "HealthProblem",
+            object([
+                ("position", nullable(count())),
+                ("kind", json!({ "enum": ProblemKind::ALL })),
+                ("message", string()),
+            ]),
+        ),
+        (
+            "HealthReport",
+            object([
+                ("total", count()),
+                ("passed_checksum", count()),
+                ("distinct_indices", indices()),
+                ("threshold", nullable(byte())),
+                ("threshold_agreed", boolean()),
+                ("quorum", boolean()),
+                ("share_count", nullable(byte())),
+                ("problems", array(reference("HealthProblem"))),
+            ]),
+        ),
+        ("WordSuggestions", array(string())),
+    ]
+    .into_iter()
+    .map(|(name, def)| (name.to_string(), def))
+    .collect();
+    let any_of: Vec<Value> = defs.keys().map(|name| reference(name)).collect();
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "shameless WASM outputs",
+        "description": "JSON documents returned by the shameless WASM bindings",
+        "anyOf": any_of,
+        "$defs": defs,
+    })
+}
+
+/// An object with exactly the given properties, all required
+fn object<const N: usize>(properties: [(&str, Value); N]) -> Value {
+    let required: Vec<&str> = properties.iter().map(|(name, _)| *name).collect();
+    let properties: Map<String, Value> = properties
+        .into_iter()
+        .map(|(name, schema)| (name.to_string(), schema))
+        .collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn string() -> Value {
+    json!({"type": "string"})
+}
+
+fn boolean() -> Value {
+    json!({"type": "boolean"})
+}
+
+/// An integer that fits in a `u8`
+fn byte() -> Value {
+    json!({"type": "integer", "minimum": 0, "maximum": 255})
+}
+
+/// A non-negative integer
+fn count() -> Value {
+    json!({"type": "integer", "minimum": 0})
+}
+
+/// A list of share indices
+fn indices() -> Value {
+    array(byte())
+}
+
+fn array(items: Value) -> Value {
+    json!({"type": "array", "items": items})
+}
+
+/// `schema`, or `null`
+fn nullable(schema: Value) -> Value {
+    json!({"anyOf": [schema, {"type": "null"}]})
+}
+
+fn reference(name: &str) -> Value {
+    json!({"$ref": format!("#/$defs/{name}")})
+}
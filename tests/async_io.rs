@@ -0,0 +1,81 @@
+//! Round trips through the async split and combine of the `tokio` feature
+
+#![cfg(feature = "tokio")]
+
+use shameless::commands::{
+    CombineOptions, SplitOptions, combine_shares_async, split_mnemonic_async,
+};
+use shameless::domain::{ShareCount, SplitConfig, Threshold};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, duplex};
+
+const MNEMONIC_12: &str =
+    "army van defense carry jealous true garbage claim echo media make crunch";
+
+#[tokio::test]
+async fn test_split_then_combine_over_duplex_streams() {
+    let config = SplitConfig::new(Threshold::new(2).unwrap(), ShareCount::new(3).unwrap()).unwrap();
+    let options = SplitOptions::default();
+
+    // The mnemonic arrives over one stream and the shares leave over another, whose
+    // small buffer makes them stream back while they are read
+    let (mut client, server_input) = duplex(1024);
+    client.write_all(MNEMONIC_12.as_bytes()).await.unwrap();
+    drop(client);
+    let (server_output, mut reader) = duplex(64);
+    let (split, shares) = tokio::join!(
+        split_mnemonic_async(server_input, server_output, config, &options),
+        async {
+            let mut shares = String::new();
+            reader.read_to_string(&mut shares).await.unwrap();
+            shares
+        }
+    );
+    split.unwrap();
+
+    let shares: Vec<&str> = shares.lines().collect();
+    assert_eq!(shares.len(), 3);
+    assert!(shares.iter().all(|share| share.starts_with("shameless ")));
+
+    // Any two shares recover the mnemonic
+    let (mut client, server_input) = duplex(64);
+    let input = format!("{}\n\n{}\n", shares[2], shares[0]);
+    let (written, ()) = tokio::join!(
+        async {
+            let mut output = Vec::new();
+            let recovered =
+                combine_shares_async(server_input, &mut output, &CombineOptions::default())
+                    .await
+                    .unwrap();
+            (output, recovered)
+        },
+        async {
+            client.write_all(input.as_bytes()).await.unwrap();
+            drop(client);
+        }
+    );
+    let (output, recovered) = written;
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        format!("{MNEMONIC_12}\n")
+    );
+    assert_eq!(recovered.missing_shares(), Some(1));
+}
+
+#[tokio::test]
+async fn test_combine_async_reports_bad_input() {
+    let mut output = Vec::new();
+    let err = combine_shares_async(
+        &b"shameless not a share\n"[..],
+        &mut output,
+        &CombineOptions::default(),
+    )
+    .await
+    .unwrap_err();
+    assert!(format!("{err:#}").contains("Failed to parse share #1"));
+    assert!(output.is_empty());
+
+    let err = combine_shares_async(&[0xff, 0xfe][..], &mut output, &CombineOptions::default())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not valid UTF-8"));
+}
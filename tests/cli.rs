@@ -0,0 +1,987 @@
+//! End-to-end tests for the `shameless` binary
+//!
+//! These tests drive the CLI through its non-interactive (piped stdin) mode.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const MNEMONIC_12: &str =
+    "army van defense carry jealous true garbage claim echo media make crunch";
+
+/// `MNEMONIC_12`'s entropy in the Spanish wordlist, with accents decomposed (NFKD) as
+/// BIP39 prints them
+const MNEMONIC_12_ES: &str = "amistad tu\u{301}nica costa broma juicio toalla furgo\u{301}n \
+                              can\u{303}a domingo masivo maldad co\u{301}digo";
+
+/// Runs the binary with the given arguments, feeding `stdin` as piped input
+fn run(args: &[&str], stdin: &str) -> Output {
+    run_with_env(args, stdin, &[])
+}
+
+/// Like [`run`], with extra environment variables set for the child
+fn run_with_env(args: &[&str], stdin: &str, env: &[(&str, &str)]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_shameless"))
+        .args(args)
+        .env_remove("SHAMELESS_MNEMONIC")
+        .envs(env.iter().copied())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to spawn shameless binary");
+
+    // The binary may exit (e.g. on an argument error) before reading its input
+    let written = child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(stdin.as_bytes());
+    if let Err(e) = written {
+        assert_eq!(
+            e.kind(),
+            std::io::ErrorKind::BrokenPipe,
+            "failed to write to stdin"
+        );
+    }
+
+    child.wait_with_output().expect("failed to wait for binary")
+}
+
+#[test]
+fn test_split_min_threshold_rejects_lower_threshold() {
+    let output = run(
+        &["split", "-s", "5", "-t", "2", "--min-threshold", "3"],
+        MNEMONIC_12,
+    );
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("below the required minimum of 3"));
+}
+
+#[test]
+fn test_split_min_threshold_accepts_equal_threshold() {
+    let output = run(
+        &["split", "-s", "5", "-t", "3", "--min-threshold", "3"],
+        MNEMONIC_12,
+    );
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("shameless ").count(), 5);
+}
+
+#[test]
+fn test_split_yes_flag_is_accepted() {
+    let output = run(&["split", "-s", "3", "-t", "2", "--yes"], MNEMONIC_12);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("shameless ").count(), 3);
+}
+
+/// Splits `MNEMONIC_12` into 3 shares with threshold 2 and returns them
+fn split_shares() -> Vec<String> {
+    split_shares_of(MNEMONIC_12)
+}
+
+fn split_shares_of(mnemonic: &str) -> Vec<String> {
+    let output = run(&["split", "-s", "3", "-t", "2"], mnemonic);
+    assert!(output.status.success());
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .map(str::to_string)
+        .collect()
+}
+
+#[test]
+fn test_combine_check_does_not_print_secret() {
+    let shares = split_shares();
+    let input = format!("{}\n{}\n\n", shares[0], shares[2]);
+
+    let output = run(&["combine", "--check"], &input);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("OK: shares reconstruct a valid 12-word mnemonic (fingerprint: "));
+    assert!(!stdout.contains(MNEMONIC_12));
+
+    // The fingerprint is stable across runs
+    let rerun = run(
+        &["combine", "--check"],
+        &format!("{}\n{}\n\n", shares[1], shares[2]),
+    );
+    assert_eq!(
+        stdout.lines().last(),
+        String::from_utf8_lossy(&rerun.stdout).lines().last()
+    );
+}
+
+#[test]
+fn test_combine_check_fails_with_insufficient_shares() {
+    let shares = split_shares();
+    let output = run(&["combine", "--check"], &format!("{}\n\n", shares[0]));
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Insufficient shares"));
+}
+
+#[test]
+fn test_split_base64_encoding_round_trips_through_combine() {
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--encoding", "base64"],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless:"))
+        .map(str::to_string)
+        .collect();
+    assert_eq!(shares.len(), 3);
+
+    let output = run(&["combine"], &format!("{}\n{}\n\n", shares[0], shares[2]));
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+}
+
+#[test]
+fn test_split_strict_spec_warns_and_round_trips() {
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--strict-spec"],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("carry no length prefix or checksum"));
+
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shamir39-p1 "))
+        .map(str::to_string)
+        .collect();
+    assert_eq!(shares.len(), 3);
+
+    let output = run(&["combine"], &format!("{}\n{}\n\n", shares[0], shares[1]));
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("strict shamir39 layout"));
+}
+
+#[test]
+fn test_combine_interactive_requires_terminal_ui() {
+    let output = run(&["combine", "--interactive"], "");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--interactive requires"));
+}
+
+#[test]
+fn test_rotate_reshares_without_printing_secret() {
+    let shares = split_shares();
+    let output = run(
+        &["rotate", "-s", "5", "-t", "3"],
+        &format!("{}\n{}\n\n", shares[0], shares[1]),
+    );
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains(MNEMONIC_12));
+
+    let new_shares: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .collect();
+    assert_eq!(new_shares.len(), 5);
+
+    let output = run(
+        &["combine"],
+        &format!(
+            "{}\n{}\n{}\n\n",
+            new_shares[4], new_shares[0], new_shares[2]
+        ),
+    );
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+}
+
+#[test]
+fn test_split_reads_mnemonic_from_env_with_warning() {
+    let output = run_with_env(
+        &["split", "-s", "3", "-t", "2"],
+        "",
+        &[("SHAMELESS_MNEMONIC", MNEMONIC_12)],
+    );
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout)
+            .matches("shameless ")
+            .count(),
+        3
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("may be visible to other processes"));
+}
+
+#[test]
+fn test_split_mnemonic_file_takes_precedence_over_env() {
+    let path = std::env::temp_dir().join(format!("shameless-test-{}.txt", std::process::id()));
+    std::fs::write(&path, format!("{MNEMONIC_12}\n")).unwrap();
+
+    let output = run_with_env(
+        &[
+            "split",
+            "-s",
+            "3",
+            "-t",
+            "2",
+            "--mnemonic-file",
+            path.to_str().unwrap(),
+        ],
+        "",
+        &[("SHAMELESS_MNEMONIC", "not a valid mnemonic")],
+    );
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("SHAMELESS_MNEMONIC"));
+}
+
+#[test]
+fn test_completion_scripts_cover_subcommands() {
+    for shell in ["bash", "zsh", "fish", "powershell"] {
+        let output = run(&["completion", shell], "");
+        assert!(output.status.success(), "{shell} completion failed");
+
+        let script = String::from_utf8_lossy(&output.stdout);
+        assert!(!script.is_empty(), "{shell} completion is empty");
+        for expected in ["split", "combine", "threshold", "mnemonic-file"] {
+            assert!(
+                script.contains(expected),
+                "{shell} completion lacks {expected}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_split_output_template_names_share_files() {
+    let dir = std::env::temp_dir().join(format!("shameless-out-{}", std::process::id()));
+
+    let output = run(
+        &[
+            "split",
+            "-s",
+            "3",
+            "-t",
+            "2",
+            "--output-dir",
+            dir.to_str().unwrap(),
+            "--output-template",
+            "custodian-{index}-of-{count}.txt",
+        ],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("shameless "));
+
+    let shares: Vec<String> = (0..3)
+        .map(|index| std::fs::read_to_string(dir.join(format!("custodian-{index}-of-3.txt"))))
+        .collect::<Result<_, _>>()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let output = run(&["combine"], &format!("{}{}\n", shares[0], shares[2]));
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+}
+
+#[test]
+fn test_split_output_template_is_validated() {
+    let dir = std::env::temp_dir();
+    for (template, expected) in [
+        ("share.txt", "must contain {index}"),
+        ("../share-{index}.txt", "no '..' or absolute paths"),
+        ("/tmp/share-{index}.txt", "no '..' or absolute paths"),
+    ] {
+        let output = run(
+            &[
+                "split",
+                "-s",
+                "3",
+                "-t",
+                "2",
+                "--output-dir",
+                dir.to_str().unwrap(),
+                "--output-template",
+                template,
+            ],
+            MNEMONIC_12,
+        );
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains(expected));
+    }
+}
+
+#[test]
+fn test_split_and_combine_spanish_mnemonic() {
+    let output = run(
+        &["--language", "spanish", "split", "-s", "3", "-t", "2"],
+        MNEMONIC_12_ES,
+    );
+    assert!(output.status.success());
+
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .map(str::to_string)
+        .collect();
+    assert_eq!(shares.len(), 3);
+
+    // The global flag may also follow the subcommand
+    let input = format!("{}\n{}\n\n", shares[0], shares[2]);
+    let output = run(&["combine", "--language", "spanish"], &input);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12_ES));
+
+    // Spanish share words are not in the default English wordlist
+    let output = run(&["combine"], &input);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("English wordlist"));
+}
+
+#[test]
+fn test_combine_output_file_keeps_secret_off_stdout() {
+    let shares = split_shares();
+    let input = format!("{}\n{}\n\n", shares[0], shares[1]);
+    let path = std::env::temp_dir().join(format!("shameless-recovered-{}.txt", std::process::id()));
+    let path_str = path.to_str().unwrap();
+
+    let output = run(&["combine", "--output-file", path_str], &input);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        format!("{MNEMONIC_12}\n")
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    // An existing file is only replaced with --force
+    let output = run(&["combine", "--output-file", path_str], &input);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("use --force"));
+
+    let output = run(&["combine", "--output-file", path_str, "--force"], &input);
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn test_verbose_logging_never_reveals_secrets() {
+    let output = run(&["-vv", "split", "-s", "3", "-t", "2"], MNEMONIC_12);
+    assert!(output.status.success());
+    let split_log = String::from_utf8_lossy(&output.stderr).into_owned();
+    assert!(split_log.contains("dealing 3 shares with threshold 2"));
+
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .map(str::to_string)
+        .collect();
+    let output = run(
+        &["combine", "--verbose", "--verbose"],
+        &format!("{}\n{}\n\n", shares[0], shares[2]),
+    );
+    assert!(output.status.success());
+    let combine_log = String::from_utf8_lossy(&output.stderr).into_owned();
+    assert!(combine_log.contains("parsed share #2 index=2 threshold=2 len=17 bytes"));
+    assert!(combine_log.contains("recovering with 2 shares"));
+
+    for log in [split_log, combine_log] {
+        let tokens: Vec<&str> = log
+            .split(|c: char| !c.is_ascii_alphabetic())
+            .filter(|token| !token.is_empty())
+            .collect();
+        for word in MNEMONIC_12.split(' ') {
+            assert!(!tokens.contains(&word), "log reveals mnemonic word {word}");
+        }
+
+        // Any two consecutive share data words would be a leak
+        for share in &shares {
+            let words: Vec<&str> = share.split(' ').skip(2).collect();
+            for pair in words.windows(2) {
+                assert!(!log.contains(&pair.join(" ")), "log reveals share words");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_health_reports_problems_without_recovering() {
+    let shares = split_shares();
+    let typo = shares[1].replacen("shameless", "shamelss", 1);
+
+    let output = run(&["health"], &format!("{}\n{typo}\n\n", shares[0]));
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains(MNEMONIC_12));
+    assert!(stdout.contains("Shares: 2 given, 1 passed their checksum"));
+    assert!(stdout.contains("Quorum: no"));
+    assert!(stdout.contains("share #2: the share starts with 'shamelss'"));
+
+    let output = run(
+        &["health", "--format", "json"],
+        &format!("{}\n{}\n\n", shares[0], shares[2]),
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"quorum\": true"));
+    assert!(stdout.contains("\"distinct_indices\": [\n    0,\n    2\n  ]"));
+    assert!(stdout.contains("\"problems\": []"));
+}
+
+#[test]
+fn test_split_scheme_sets_threshold_and_share_count() {
+    let output = run(&["split", "--scheme", "3-of-5"], MNEMONIC_12);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Created 5 shares (threshold: 3)"));
+    assert_eq!(stdout.matches("shameless ").count(), 5);
+
+    for (scheme, expected) in [
+        ("3of5", "is not of the form M-of-N"),
+        ("5-of-3", "Threshold 5 cannot exceed share count 3"),
+        ("1-of-3", "Threshold must be at least 2"),
+    ] {
+        let output = run(&["split", "--scheme", scheme], MNEMONIC_12);
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).contains(expected));
+    }
+
+    let output = run(&["split", "--scheme", "3-of-5", "-t", "2"], MNEMONIC_12);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+}
+
+#[test]
+fn test_combine_manifest_flags_shares_of_another_secret() {
+    let path = std::env::temp_dir().join(format!("shameless-manifest-{}.json", std::process::id()));
+    let path_str = path.to_str().unwrap();
+
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--manifest", path_str],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+    let manifest = std::fs::read_to_string(&path).unwrap();
+    assert!(manifest.contains("\"scheme\": \"2-of-3\""));
+    assert!(!manifest.contains("army"));
+
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .map(str::to_string)
+        .collect();
+    let output = run(
+        &["combine", "--manifest", path_str],
+        &format!("{}\n{}\n\n", shares[0], shares[2]),
+    );
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+
+    // Shares of the same scheme protecting a different secret
+    let other = split_shares_of(
+        "legal winner thank year wave sausage worth useful legal winner thank yellow",
+    );
+    let output = run(
+        &["combine", "--manifest", path_str],
+        &format!("{}\n{}\n\n", other[0], other[1]),
+    );
+    std::fs::remove_file(&path).unwrap();
+    assert!(!output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("legal winner"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("do not belong to the share set"));
+}
+
+#[test]
+fn test_combine_reminds_of_passphrase() {
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--with-passphrase-reminder"],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .map(str::to_string)
+        .collect();
+
+    let output = run(&["combine"], &format!("{}\n{}\n\n", shares[0], shares[1]));
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("BIP39 passphrase"));
+
+    // Shares split without the flag carry no reminder
+    let plain = split_shares();
+    let output = run(&["combine"], &format!("{}\n{}\n\n", plain[0], plain[1]));
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("passphrase"));
+}
+
+#[test]
+fn test_combine_dedup_keeps_one_copy() {
+    let shares = split_shares();
+    let input = format!("{}\n{}\n{}\n\n", shares[0], shares[0], shares[1]);
+
+    let output = run(&["combine"], &input);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("exact duplicate"));
+
+    let output = run(&["combine", "--dedup", "last"], &input);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+}
+
+#[test]
+fn test_split_warns_about_scheme_without_spare_shares() {
+    let output = run(&["split", "-s", "3", "-t", "3"], MNEMONIC_12);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("threshold equals share count"));
+
+    let output = run(&["split", "-s", "3", "-t", "2"], MNEMONIC_12);
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("threshold equals share count"));
+
+    let output = run(&["--quiet", "split", "-s", "3", "-t", "3"], MNEMONIC_12);
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("threshold equals share count"));
+}
+
+#[test]
+fn test_combine_raw_prints_entropy_hex() {
+    let shares = split_shares();
+    let output = run(
+        &["combine", "--raw"],
+        &format!("{}\n{}\n\n", shares[0], shares[2]),
+    );
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Recovered 16 byte secret (hex):"));
+    let hex = stdout.lines().last().unwrap();
+    assert_eq!(hex.len(), 32);
+    assert!(hex.chars().all(|c| c.is_ascii_hexdigit()));
+    assert!(!stdout.contains(MNEMONIC_12));
+}
+
+#[test]
+fn test_restore_drill_with_fixed_entropy() {
+    let entropy = "0c1e24e5917779d297e14d45f14e1a1a";
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--entropy-hex", entropy],
+        "",
+    );
+    assert!(output.status.success());
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .map(str::to_string)
+        .collect();
+    let input = format!("{}\n{}\n\n", shares[0], shares[2]);
+
+    let output = run(&["combine", "--assert-entropy-hex", entropy], &input);
+    assert!(output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stdout)
+            .contains("OK: shares reconstruct the expected secret")
+    );
+
+    let output = run(
+        &[
+            "combine",
+            "--assert-entropy-hex",
+            "00000000000000000000000000000000",
+        ],
+        &input,
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("does not match the expected secret"));
+    assert!(!String::from_utf8_lossy(&output.stdout).contains(entropy));
+    assert!(!String::from_utf8_lossy(&output.stderr).contains(entropy));
+
+    // Only BIP39 entropy lengths can be split this way
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--entropy-hex", "0102030405"],
+        "",
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("got 5 bytes"));
+
+    // A 64-byte BIP39 seed is called out as such
+    let seed = "ab".repeat(64);
+    let output = run(&["split", "-s", "3", "-t", "2", "--entropy-hex", &seed], "");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("looks like a BIP39 seed"));
+}
+
+#[test]
+fn test_entropy_hex_refuses_weak_entropy_unless_forced() {
+    let zeros = "00".repeat(16);
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--entropy-hex", &zeros],
+        "",
+    );
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("every byte is 0x00"), "{stderr}");
+    assert!(stderr.contains("--force-weak"));
+
+    let output = run(
+        &[
+            "split",
+            "-s",
+            "3",
+            "-t",
+            "2",
+            "--entropy-hex",
+            &zeros,
+            "--force-weak",
+        ],
+        "",
+    );
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_combine_paranoid_verify_reports_regenerated_shares() {
+    let shares = split_shares();
+    let input = format!("{}\n{}\n{}\n\n", shares[0], shares[1], shares[2]);
+
+    let output = run(&["combine", "--paranoid-verify"], &input);
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("verified: recovered secret regenerates the provided shares"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn test_windows_line_endings_and_bom_are_accepted() {
+    let output = run(
+        &["split", "-s", "3", "-t", "2"],
+        &format!("\u{FEFF}{MNEMONIC_12}\r\n"),
+    );
+    assert!(output.status.success());
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .map(str::to_string)
+        .collect();
+
+    let input = format!("\u{FEFF}{}\r\n{}\r\n\r\n", shares[0], shares[1]);
+    let output = run(&["combine"], &input);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+}
+
+#[test]
+fn test_words_lists_indices_and_flags_unknown_words() {
+    let shares = split_shares();
+    let mut words: Vec<&str> = shares[0].split(' ').collect();
+    words[3] = "abandonn";
+    let input = format!("{}\n\n", words.join(" "));
+
+    let output = run(&["words"], &input);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("shameless        -  version"));
+    assert!(stdout.contains("abandonn         -  data (not in the wordlist)"));
+}
+
+#[test]
+fn test_split_bundle_combines_on_its_own() {
+    let path = std::env::temp_dir().join(format!("shameless-bundle-{}.txt", std::process::id()));
+    let path_str = path.to_str().unwrap();
+
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--bundle", path_str],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("holds every share"));
+    let bundle = std::fs::read_to_string(&path).unwrap();
+    assert!(bundle.starts_with("SHAMELESS-BUNDLE:"));
+    std::fs::remove_file(&path).unwrap();
+
+    let output = run(&["combine"], &format!("{}\n\n", bundle.trim()));
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+}
+
+#[test]
+fn test_split_paper_writes_one_page_per_share() {
+    let dir = std::env::temp_dir().join(format!("shameless-paper-{}", std::process::id()));
+    let dir_str = dir.to_str().unwrap();
+
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--paper", dir_str],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+    let page = std::fs::read_to_string(dir.join("share-1.html")).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(page.contains("Share 2 of 3, threshold 2"));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let shares: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .collect();
+    assert!(page.contains(shares[1]));
+    assert!(!page.contains(shares[0]));
+}
+
+#[test]
+fn test_kit_writes_pages_manifest_and_readme() {
+    let dir = std::env::temp_dir().join(format!("shameless-kit-{}", std::process::id()));
+    let dir_str = dir.to_str().unwrap();
+
+    let output = run(
+        &["kit", "-s", "3", "-t", "2", "--output-dir", dir_str],
+        MNEMONIC_12,
+    );
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!String::from_utf8_lossy(&output.stdout).contains("shameless "));
+
+    let mut names: Vec<String> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            "README.txt",
+            "manifest.json",
+            "share-0.html",
+            "share-1.html",
+            "share-2.html"
+        ]
+    );
+
+    // Each page holds exactly one share, its own, readable only by the owner
+    let shares: Vec<String> = (0..3)
+        .map(|index| {
+            let path = dir.join(format!("share-{index}.html"));
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+                assert_eq!(mode & 0o777, 0o600);
+            }
+            let page = std::fs::read_to_string(path).unwrap();
+            let start = page.find("<p class=\"text\">").unwrap() + "<p class=\"text\">".len();
+            let share = &page[start..start + page[start..].find("</p>").unwrap()];
+            assert_eq!(page.matches(share).count(), 1);
+            share.to_string()
+        })
+        .collect();
+    assert_ne!(shares[0], shares[1]);
+
+    let manifest = dir.join("manifest.json");
+    let readme = std::fs::read_to_string(dir.join("README.txt")).unwrap();
+    assert!(readme.contains("shameless combine --manifest manifest.json"));
+    let output = run(
+        &["combine", "--manifest", manifest.to_str().unwrap()],
+        &format!("{}\n{}\n\n", shares[0], shares[2]),
+    );
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+
+    // A kit is never written over existing files
+    let output = run(
+        &["kit", "-s", "3", "-t", "2", "--output-dir", dir_str],
+        MNEMONIC_12,
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("it is not empty"));
+}
+
+#[test]
+fn test_combine_reports_missing_shares() {
+    let shares = split_shares();
+    let input = format!("{}\n{}\n\n", shares[0], shares[2]);
+
+    let output = run(&["combine"], &input);
+    assert!(output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr)
+            .contains("recovered using 2 of 3 original shares; 1 share is missing")
+    );
+
+    let output = run(&["combine", "--require-all-shares"], &input);
+    assert!(!output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+
+    let input = format!("{}\n{}\n{}\n\n", shares[0], shares[1], shares[2]);
+    let output = run(&["combine", "--require-all-shares"], &input);
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+
+    let output = run(&["health"], &format!("{}\n\n", shares[1]));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Share count: 3 dealt, 2 missing"));
+}
+
+#[test]
+fn test_split_without_share_count() {
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--no-share-count"],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+    let shares: Vec<String> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .map(str::to_string)
+        .collect();
+    let input = format!("{}\n{}\n\n", shares[0], shares[1]);
+
+    let output = run(&["combine"], &input);
+    assert!(output.status.success());
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("missing"));
+
+    let output = run(&["combine", "--require-all-shares"], &input);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("record how many were dealt"));
+}
+
+#[test]
+fn test_split_fingerprints_match_fingerprint_command() {
+    let output = run(
+        &["split", "-s", "3", "-t", "2", "--show-fingerprints"],
+        MNEMONIC_12,
+    );
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let heading = stdout
+        .lines()
+        .find(|line| line.starts_with("Share #2 (fingerprint "))
+        .unwrap();
+    let fingerprint = &heading["Share #2 (fingerprint ".len()..heading.len() - 2];
+    assert_eq!(fingerprint.len(), 4);
+    let share = stdout
+        .lines()
+        .filter(|line| line.starts_with("shameless "))
+        .nth(1)
+        .unwrap();
+
+    let output = run(&["fingerprint"], &format!("{share}\n\n"));
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        format!("Share #1 (index 1): fingerprint {fingerprint}")
+    );
+}
+
+#[test]
+fn test_combine_and_fingerprint_skip_comment_lines() {
+    let shares = split_shares();
+    let notes = format!(
+        "# Alice's share, stored in safe\n{}\n#\n  # Bob's share\n{}\n",
+        shares[0], shares[2]
+    );
+
+    let output = run(&["combine"], &notes);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+
+    let output = run(&["fingerprint"], &notes);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("Share #1 (index 0): fingerprint "));
+    assert!(lines[0].ends_with("  # Alice's share, stored in safe"));
+    assert!(lines[1].ends_with("  # Bob's share"));
+}
+
+#[test]
+fn test_combine_reads_piped_shares_past_blank_lines() {
+    let shares = split_shares();
+    let notes = format!(
+        "\n# Alice's share\n{}\n\n\n# Bob's share\n\n{}\n\n",
+        shares[0], shares[2]
+    );
+
+    let output = run(&["combine"], &notes);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+
+    let output = run(&["fingerprint"], &notes);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert!(lines[1].ends_with("  # Bob's share"));
+}
+
+#[test]
+fn test_json_error_format() {
+    let output = run(&["--error-format", "json", "combine"], "not a share\n\n");
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let report: serde_json::Value = serde_json::from_str(stderr.lines().last().unwrap()).unwrap();
+    let error = report["error"].as_str().unwrap();
+    let chain = report["chain"].as_array().unwrap();
+    assert!(!error.is_empty());
+    assert_eq!(chain[0].as_str(), Some(error));
+    assert!(chain.iter().all(serde_json::Value::is_string));
+
+    // Text stays the default
+    let output = run(&["combine"], "not a share\n\n");
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("Error: "));
+}
+
+#[test]
+fn test_combine_json_split_result() {
+    let shares = split_shares();
+    let json = serde_json::json!({
+        "shares": [shares[0], shares[2]],
+        "share_count": 3,
+        "threshold": 2,
+    });
+
+    let output = run(&["combine", "--json"], &json.to_string());
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains(MNEMONIC_12));
+
+    let output = run(
+        &["combine", "--json"],
+        r#"{"share_count": 3, "threshold": 2}"#,
+    );
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("missing field `shares`"));
+}
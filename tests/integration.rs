@@ -2,7 +2,7 @@ use bip39::{Language, Mnemonic};
 use blahaj::Sharks;
 
 use shameless::shamir39;
-use shameless::shamir39::{ShareIndex, Threshold};
+use shameless::shamir39::{ShareCount, ShareIndex, Threshold};
 
 #[test]
 fn test_split_and_combine_12_word_mnemonic() {
@@ -77,21 +77,15 @@ fn test_full_shamir39_encoding_round_trip() {
     let num_shares = 3;
     let sharks = Sharks(threshold);
     let dealer = sharks.dealer(&original_entropy);
-    let share_vec: Vec<_> = dealer.take(num_shares).collect();
+    let share_vec: Vec<_> = dealer.take(num_shares.into()).collect();
 
     // Encode each share using shamir39 encoding
     let encoded_shares = share_vec
         .iter()
-        .enumerate()
-        .map(|(idx, share)| {
+        .zip(ShareCount::new(num_shares).unwrap().iter_indices())
+        .map(|(share, index)| {
             let share_bytes = Vec::from(share);
-            let idx_u8 = u8::try_from(idx).unwrap_or_else(|_| unreachable!("idx fits in u8"));
-            shamir39::create_share(
-                &share_bytes,
-                Threshold::new(threshold).unwrap(),
-                ShareIndex::new(idx_u8).unwrap(),
-            )
-            .unwrap()
+            shamir39::create_share(&share_bytes, Threshold::new(threshold).unwrap(), index).unwrap()
         })
         .collect::<Vec<_>>();
 
@@ -193,21 +187,15 @@ fn test_12_word_complete_workflow() {
     let num_shares = 5;
     let sharks = Sharks(threshold);
     let dealer = sharks.dealer(&entropy);
-    let share_vec: Vec<_> = dealer.take(num_shares).collect();
+    let share_vec: Vec<_> = dealer.take(num_shares.into()).collect();
 
     // Encode as shamir39
     let share_mnemonics = share_vec
         .iter()
-        .enumerate()
-        .map(|(idx, share)| {
+        .zip(ShareCount::new(num_shares).unwrap().iter_indices())
+        .map(|(share, index)| {
             let share_bytes = Vec::from(share);
-            let idx_u8 = u8::try_from(idx).unwrap_or_else(|_| unreachable!("idx fits in u8"));
-            shamir39::create_share(
-                &share_bytes,
-                Threshold::new(threshold).unwrap(),
-                ShareIndex::new(idx_u8).unwrap(),
-            )
-            .unwrap()
+            shamir39::create_share(&share_bytes, Threshold::new(threshold).unwrap(), index).unwrap()
         })
         .collect::<Vec<_>>();
 
@@ -249,21 +237,15 @@ fn test_24_word_complete_workflow() {
     let num_shares = 3;
     let sharks = Sharks(threshold);
     let dealer = sharks.dealer(&entropy);
-    let share_vec: Vec<_> = dealer.take(num_shares).collect();
+    let share_vec: Vec<_> = dealer.take(num_shares.into()).collect();
 
     // Encode as shamir39
     let share_mnemonics = share_vec
         .iter()
-        .enumerate()
-        .map(|(idx, share)| {
+        .zip(ShareCount::new(num_shares).unwrap().iter_indices())
+        .map(|(share, index)| {
             let share_bytes = Vec::from(share);
-            let idx_u8 = u8::try_from(idx).unwrap_or_else(|_| unreachable!("idx fits in u8"));
-            shamir39::create_share(
-                &share_bytes,
-                Threshold::new(threshold).unwrap(),
-                ShareIndex::new(idx_u8).unwrap(),
-            )
-            .unwrap()
+            shamir39::create_share(&share_bytes, Threshold::new(threshold).unwrap(), index).unwrap()
         })
         .collect::<Vec<_>>();
 
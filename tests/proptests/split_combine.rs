@@ -5,7 +5,7 @@ use blahaj::Sharks;
 use quickcheck::{Arbitrary, Gen};
 use quickcheck_macros::quickcheck;
 use shameless::shamir39;
-use shameless::shamir39::{ShareIndex, Threshold};
+use shameless::shamir39::{ShareCount, ShareIndex, Threshold};
 
 /// Wrapper for valid BIP39 mnemonics (12 or 24 words)
 #[derive(Clone, Debug)]
@@ -72,15 +72,10 @@ fn prop_split_combine_round_trip(mnemonic: ValidMnemonic, params: ValidShamirPar
     // Encode as shamir39
     let share_mnemonics: Result<Vec<_>, _> = share_vec
         .iter()
-        .enumerate()
-        .map(|(idx, share)| {
+        .zip(ShareCount::new(num_shares).unwrap().iter_indices())
+        .map(|(share, index)| {
             let share_bytes = Vec::from(share);
-            let idx_u8 = u8::try_from(idx).unwrap_or_else(|_| unreachable!("idx fits in u8"));
-            shamir39::create_share(
-                &share_bytes,
-                Threshold::new(threshold).unwrap(),
-                ShareIndex::new(idx_u8).unwrap(),
-            )
+            shamir39::create_share(&share_bytes, Threshold::new(threshold).unwrap(), index)
         })
         .collect();
 
@@ -206,16 +201,12 @@ fn prop_shamir39_metadata_preserved(mnemonic: ValidMnemonic, params: ValidShamir
     let share_vec: Vec<_> = dealer.take(num_shares.into()).collect();
 
     // Encode each share and verify metadata
-    for (idx, share) in share_vec.iter().enumerate() {
+    let indices = ShareCount::new(num_shares).unwrap().iter_indices();
+    for (share, index) in share_vec.iter().zip(indices) {
         let share_bytes = Vec::from(share);
-        let Ok(idx_u8) = u8::try_from(idx) else {
-            return false; // idx doesn't fit in u8
-        };
-        let Ok(mnemonic) = shamir39::create_share(
-            &share_bytes,
-            Threshold::new(threshold).unwrap(),
-            ShareIndex::new(idx_u8).unwrap(),
-        ) else {
+        let Ok(mnemonic) =
+            shamir39::create_share(&share_bytes, Threshold::new(threshold).unwrap(), index)
+        else {
             return false;
         };
 
@@ -224,7 +215,7 @@ fn prop_shamir39_metadata_preserved(mnemonic: ValidMnemonic, params: ValidShamir
             return false;
         };
 
-        if *parsed_threshold != threshold || *parsed_index != idx_u8 {
+        if *parsed_threshold != threshold || parsed_index != index {
             return false;
         }
     }
@@ -0,0 +1,113 @@
+//! Golden test vectors pinning the shameless word format
+//!
+//! Each vector pairs fixed `(threshold, index, data)` inputs with the exact mnemonic
+//! `create_share` writes for them. If one of these fails, the wire format changed and
+//! shares written by earlier versions may no longer parse: update a vector only for a
+//! deliberate, versioned format change.
+
+use shameless::codec::{create_share, parse_share};
+use shameless::domain::{ShareIndex, Threshold};
+
+/// A fixed share and the inputs it encodes
+struct Vector {
+    threshold: u8,
+    index: u8,
+    data: &'static str,
+    mnemonic: &'static str,
+}
+
+const VECTORS: &[Vector] = &[
+    // Single-word parameters, 1 byte of data
+    Vector {
+        threshold: 2,
+        index: 0,
+        data: "01",
+        mnemonic: "shameless amount aerobic abandon abandon avoid wage glory basic",
+    },
+    // 7 bytes, one short of a whole 11-bit word boundary
+    Vector {
+        threshold: 3,
+        index: 1,
+        data: "01020304050607",
+        mnemonic: "shameless around dragon abandon abstract absurd avoid scissors anxiety gather \
+                   planet since cat",
+    },
+    // 8 bytes, all bits set so the padding bits show
+    Vector {
+        threshold: 2,
+        index: 4,
+        data: "ffffffffffffffff",
+        mnemonic: "shameless ancient analyst abandon ability cable zoo zoo zoo zoo zoo video \
+                   potato beauty",
+    },
+    // 11 bytes (88 bits, a whole number of words), largest single-word index
+    Vector {
+        threshold: 5,
+        index: 31,
+        data: "00172e455c738aa1b8cfe6",
+        mnemonic: "shameless blood canal abandon absent length frequent silly fragile ordinary \
+                   position immense wrist barrel angle motor",
+    },
+    // Two-word threshold
+    Vector {
+        threshold: 32,
+        index: 0,
+        data: "deadbeef",
+        mnemonic: "shameless lottery abandon awesome abandon ability dash fork upon minimum \
+                   comfort supreme",
+    },
+    // Two-word index
+    Vector {
+        threshold: 2,
+        index: 32,
+        data: "00000000000000",
+        mnemonic: "shameless lens amount dragon abandon abstract abandon abandon abandon abandon \
+                   abandon farm typical tackle",
+    },
+    // Two-word threshold and index, 16 bytes (a 12-word mnemonic's entropy)
+    Vector {
+        threshold: 200,
+        index: 254,
+        data: "000102030405060708090a0b0c0d0e0f",
+        mnemonic: "shameless ocean cat acoustic length abandon length able advice core action \
+                   blossom three animal expect seek borrow thought morning reduce arch",
+    },
+];
+
+#[test]
+fn test_create_share_matches_vectors() {
+    for vector in VECTORS {
+        let data = hex::decode(vector.data).unwrap();
+        let mnemonic = create_share(
+            &data,
+            Threshold::new(vector.threshold).unwrap(),
+            ShareIndex::new(vector.index).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            mnemonic.as_str(),
+            vector.mnemonic,
+            "threshold {}, index {}, data {}",
+            vector.threshold,
+            vector.index,
+            vector.data
+        );
+    }
+}
+
+#[test]
+fn test_parse_share_recovers_vectors() {
+    for vector in VECTORS {
+        let (threshold, index, data) = parse_share(vector.mnemonic).unwrap();
+        assert_eq!(
+            (threshold, index, hex::encode(&*data).as_str()),
+            (
+                Threshold::new(vector.threshold).unwrap(),
+                ShareIndex::new(vector.index).unwrap(),
+                vector.data
+            ),
+            "{}",
+            vector.mnemonic
+        );
+    }
+}